@@ -3,18 +3,26 @@ pub mod time_series;
 
 //1.1 Smoothing
 pub use time_series::smoothing::smoothing::Smoothing;
+pub use time_series::smoothing::ets::ets::Ets;
+pub use time_series::smoothing::ets::ets_model::*;
+pub use time_series::smoothing::ets::ets_est::*;
 
 //1.2 Decomposition
 pub use time_series::decomposition::decomposition::Decomposition;
+pub use time_series::decomposition::stl::stl_fit::*;
 
 //1.3 Difference
 pub use time_series::difference::difference::*;
 
 //1.4 Evaluation
 pub use time_series::evaluation::basic_evaluation;
+pub use time_series::evaluation::cross_validation::*;
+pub use time_series::evaluation::forecast_accuracy::*;
 
 //1.5 Autocorrelation
 pub use time_series::autocorrelation::autocorrelation::Autocorrelation;
+pub use time_series::autocorrelation::cross_correlation::CrossCorrelation;
+pub use time_series::autocorrelation::ccf::*;
 
 //1.6 Unit Root Test
 pub use time_series::unit_root_test::calculate_critical_values::*;
@@ -25,14 +33,23 @@ pub use time_series::unit_root_test::read_critical_values::*;
 pub use time_series::unit_root_test::read_pvalue::*;
 pub use time_series::unit_root_test::dickey_fuller::dickey_fuller::DickeyFuller;
 pub use time_series::unit_root_test::augmented_dickey_fuller::augmented_dickey_fuller::AugmentedDickeyFuller;
+pub use time_series::unit_root_test::kpss::kpss::Kpss;
+pub use time_series::unit_root_test::phillips_perron::phillips_perron::PhillipsPerron;
+pub use time_series::unit_root_test::zivot_andrews::zivot_andrews::ZivotAndrews;
 
 //1.7 ARIMA
 pub use time_series::arima::arima::Arima;
 pub use time_series::arima::est_coef_process::autocov::*;
 pub use time_series::arima::est_coef_process::durb_lev_alg::durb_lev_alg;
 pub use time_series::arima::est_coef_process::innov_alg::innov_alg;
-pub use time_series::arima::est_coef_process::css::css;
-pub use time_series::arima::est_coef_process::est_coef::est_coef;
+pub use time_series::arima::est_coef_process::css::*;
+pub use time_series::arima::est_coef_process::est_coef::*;
+pub use time_series::arima::est_coef_process::sarima_order::*;
+pub use time_series::arima::est_coef_process::kalman_filter::*;
+pub use time_series::arima::est_coef_process::param_transform::*;
+pub use time_series::arima::est_coef_process::est_coef_ml::*;
+pub use time_series::arima::auto_arima::{AutoArima, ArimaCandidate};
+pub use time_series::arima::arima_outliers::*;
 
 //2. Regression Analysis
 pub mod regression;
@@ -43,12 +60,61 @@ pub use regression::no_intercept_linear_regression::no_intercept_linear_regressi
 pub use regression::simple_exponential_regression::simple_exponential_regression::SimpleExponentialRegression;
 pub use regression::multiple_linear_regression::multiple_linear_regression::MultipleLinearRegression;
 pub use regression::multiple_linear_regression::calculate_matrix::*;
+pub use regression::curve_estimation::curve_estimation::CurveEstimation;
+
+//2.2 Structural Break Tests
+pub use regression::structural_break::recursive_residuals::*;
+pub use regression::structural_break::chow_test::*;
+pub use regression::structural_break::cusum_test::*;
+pub use regression::structural_break::bai_perron::*;
 
 //1.8 GARCH Models
 pub use time_series::garch::garch::GARCH;
+pub use time_series::garch::garch_filter::*;
+pub use time_series::garch::innovation::*;
 
 //1.9 ECM - Error Correction Model
 pub use time_series::ecm::ecm::ECM;
 
 //1.10 ARDL - Autoregressive Distributed Lag
 pub use time_series::ardl::ardl::ARDL;
+pub use time_series::ardl::pss_critical_values::*;
+
+//1.11 ARIMAX - Regression with ARIMA errors and transfer functions
+pub use time_series::arimax::arimax::Arimax;
+pub use time_series::arimax::transfer_function::*;
+pub use time_series::arimax::intervention::*;
+
+//1.12 VECM - Johansen cointegration and vector error correction
+pub use time_series::vecm::vecm::Vecm;
+pub use time_series::vecm::johansen::*;
+pub use time_series::vecm::osterwald_lenum::*;
+
+//1.13 VAR - Vector autoregression
+pub use time_series::var::var::Var;
+pub use time_series::var::var_model::*;
+
+//1.14 Spectral analysis
+pub use time_series::spectral::spectral::Spectral;
+pub use time_series::spectral::fft::fft;
+pub use time_series::spectral::spectral_density::*;
+pub use time_series::spectral::fisher_test::*;
+
+//1.15 Imputation - Missing values and irregular dates
+pub use time_series::imputation::imputation::Imputation;
+pub use time_series::imputation::fill_methods::*;
+pub use time_series::imputation::local_level::*;
+pub use time_series::imputation::regularize::*;
+
+//1.16 Box-Cox - Variance-stabilizing transformation
+pub use time_series::box_cox::box_cox::*;
+pub use time_series::box_cox::lambda_estimation::*;
+
+//1.17 State Space - Structural time-series models
+pub use time_series::state_space::structural_model::StructuralModel;
+pub use time_series::state_space::state_space_kalman::*;
+
+//1.18 Filters - Trend-cycle extraction
+pub use time_series::filters::cycle_filter::*;
+pub use time_series::filters::hp_filter::*;
+pub use time_series::filters::band_pass::*;
\ No newline at end of file
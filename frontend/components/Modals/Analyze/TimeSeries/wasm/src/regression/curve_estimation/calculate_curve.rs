@@ -0,0 +1,187 @@
+use wasm_bindgen::prelude::*;
+use crate::CurveEstimation;
+use crate::time_series::ecm::ols_helper::ols;
+use statrs::distribution::{ContinuousCDF, FisherSnedecor, StudentsT};
+
+// Internal helpers (not exposed to WASM)
+impl CurveEstimation {
+    /// Predictor values: x, or the time index 1..n
+    pub fn predictor(&self) -> Vec<f64> {
+        if self.time_index {
+            (1..=self.y.len()).map(|t| t as f64).collect()
+        } else {
+            self.x.clone()
+        }
+    }
+
+    /// Regressors (after the intercept) and response of the linearized model
+    fn linearize(&self, x: &[f64]) -> Result<(Vec<Vec<f64>>, Vec<f64>), String> {
+        let positive_x = || x.iter().all(|&v| v > 0.0);
+        let nonzero_x = || x.iter().all(|&v| v != 0.0);
+        let positive_y = || self.y.iter().all(|&v| v > 0.0);
+        let ln_y = || self.y.iter().map(|v| v.ln()).collect::<Vec<f64>>();
+        let power = |k: i32| x.iter().map(|v| v.powi(k)).collect::<Vec<f64>>();
+        let inverse = || x.iter().map(|v| 1.0 / v).collect::<Vec<f64>>();
+        let (columns, response) = match self.model.as_str() {
+            "linear" => (vec![x.to_vec()], self.y.clone()),
+            "logarithmic" => {
+                if !positive_x() {
+                    return Err("The logarithmic model needs positive x".to_string());
+                }
+                (vec![x.iter().map(|v| v.ln()).collect()], self.y.clone())
+            },
+            "inverse" => {
+                if !nonzero_x() {
+                    return Err("The inverse model needs nonzero x".to_string());
+                }
+                (vec![inverse()], self.y.clone())
+            },
+            "quadratic" => (vec![power(1), power(2)], self.y.clone()),
+            "cubic" => (vec![power(1), power(2), power(3)], self.y.clone()),
+            "compound" | "growth" | "exponential" => {
+                if !positive_y() {
+                    return Err(format!("The {} model needs positive y", self.model));
+                }
+                (vec![x.to_vec()], ln_y())
+            },
+            "power" => {
+                if !positive_x() || !positive_y() {
+                    return Err("The power model needs positive x and y".to_string());
+                }
+                (vec![x.iter().map(|v| v.ln()).collect()], ln_y())
+            },
+            "s" => {
+                if !nonzero_x() || !positive_y() {
+                    return Err("The S model needs nonzero x and positive y".to_string());
+                }
+                (vec![inverse()], ln_y())
+            },
+            "logistic" => {
+                let u = self.upper_bound;
+                if !positive_y() || self.y.iter().any(|&v| v >= u) {
+                    return Err("The logistic model needs 0 < y < upper bound".to_string());
+                }
+                (vec![x.to_vec()], self.y.iter().map(|v| (1.0 / v - 1.0 / u).ln()).collect())
+            },
+            _ => panic!("Unknown model: {}", self.model),
+        };
+        Ok((columns, response))
+    }
+
+    /// Linearized coefficient i mapped to the model's own form with its delta-method
+    /// standard error: b0 = exp(a) for compound, power, exponential and logistic, and
+    /// b1 = exp(b) for compound and logistic
+    fn model_coefficient(&self, i: usize, value: f64, se: f64) -> (f64, f64) {
+        let exponentiated = match self.model.as_str() {
+            "compound" | "logistic" => true,
+            "power" | "exponential" => i == 0,
+            _ => false,
+        };
+        if exponentiated {
+            (value.exp(), value.exp() * se)
+        } else {
+            (value, se)
+        }
+    }
+
+    /// Number of coefficients b0, b1, .. of the model
+    fn n_coefficients(&self) -> usize {
+        match self.model.as_str() {
+            "quadratic" => 3,
+            "cubic" => 4,
+            _ => 2,
+        }
+    }
+
+    /// Prediction on the original scale from the linearized fit value
+    fn back_transform(&self, fitted: f64) -> f64 {
+        match self.model.as_str() {
+            "compound" | "growth" | "exponential" | "power" | "s" => fitted.exp(),
+            "logistic" => 1.0 / (1.0 / self.upper_bound + fitted.exp()),
+            _ => fitted,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl CurveEstimation {
+    /// Fit the model. The ANOVA table, R², standard error of the estimate, t statistics
+    /// and p-values refer to the linearized model (ln y for compound, power, S, growth and
+    /// exponential; ln(1/y - 1/u) for logistic), as in SPSS; coefficients, their standard
+    /// errors and the predicted values are on the model's own scale.
+    pub fn calculate_curve(&mut self) -> Result<Vec<f64>, JsValue> {
+        let x = self.predictor();
+        let n = self.y.len();
+        if x.len() != n {
+            return Err(JsValue::from_str("x and y must have the same length"));
+        }
+        let (regressors, response) = self.linearize(&x).map_err(|e| JsValue::from_str(&e))?;
+        let k = regressors.len();
+        if n <= k + 1 {
+            return Err(JsValue::from_str("Not enough observations for the model"));
+        }
+        let mut columns = vec![vec![1.0; n]];
+        columns.extend(regressors);
+        let fit = ols(&columns, &response).ok_or_else(|| JsValue::from_str("Singular design matrix"))?;
+
+        let mean = response.iter().sum::<f64>() / n as f64;
+        let sst = response.iter().map(|v| (v - mean).powi(2)).sum::<f64>();
+        let ss_reg = sst - fit.ssr;
+        let (df_reg, df_res) = (k as f64, (n - k - 1) as f64);
+        let (ms_reg, ms_res) = (ss_reg / df_reg, fit.ssr / df_res);
+        let f_stat = ms_reg / ms_res;
+        let f_p = match FisherSnedecor::new(df_reg, df_res) {
+            Ok(dist) if f_stat.is_finite() => 1.0 - dist.cdf(f_stat),
+            _ => f64::NAN,
+        };
+        self.anova = vec![ss_reg, df_reg, ms_reg, fit.ssr, df_res, ms_res, f_stat, f_p];
+        self.r_squared = ss_reg / sst;
+        self.adj_r_squared = 1.0 - (1.0 - self.r_squared) * (n as f64 - 1.0) / df_res;
+        self.std_error_estimate = ms_res.sqrt();
+
+        let t_dist = StudentsT::new(0.0, 1.0, df_res).unwrap();
+        self.t_stats = fit.beta.iter().zip(fit.se.iter()).map(|(b, se)| b / se).collect();
+        self.p_values = self.t_stats.iter().map(|t| 2.0 * (1.0 - t_dist.cdf(t.abs()))).collect();
+        let mapped: Vec<(f64, f64)> = fit
+            .beta
+            .iter()
+            .zip(fit.se.iter())
+            .enumerate()
+            .map(|(i, (&b, &se))| self.model_coefficient(i, b, se))
+            .collect();
+        self.coefficients = mapped.iter().map(|(b, _)| *b).collect();
+        self.std_errors = mapped.iter().map(|(_, se)| *se).collect();
+        self.predicted = response
+            .iter()
+            .zip(fit.residuals.iter())
+            .map(|(v, e)| self.back_transform(v - e))
+            .collect();
+        Ok(self.coefficients.clone())
+    }
+
+    /// Predictions at new predictor values (time indices n+1, n+2, ... for forecasts);
+    /// empty until calculate_curve has fitted the current model, bound and time index
+    pub fn predict(&self, x_new: Vec<f64>) -> Vec<f64> {
+        let b = &self.coefficients;
+        if b.len() != self.n_coefficients() {
+            return Vec::new();
+        }
+        x_new
+            .iter()
+            .map(|&x| match self.model.as_str() {
+                "linear" => b[0] + b[1] * x,
+                "logarithmic" => b[0] + b[1] * x.ln(),
+                "inverse" => b[0] + b[1] / x,
+                "quadratic" => b[0] + b[1] * x + b[2] * x * x,
+                "cubic" => b[0] + b[1] * x + b[2] * x * x + b[3] * x.powi(3),
+                "compound" => b[0] * b[1].powf(x),
+                "power" => b[0] * x.powf(b[1]),
+                "s" => (b[0] + b[1] / x).exp(),
+                "growth" => (b[0] + b[1] * x).exp(),
+                "exponential" => b[0] * (b[1] * x).exp(),
+                "logistic" => 1.0 / (1.0 / self.upper_bound + b[0] * b[1].powf(x)),
+                _ => panic!("Unknown model: {}", self.model),
+            })
+            .collect()
+    }
+}
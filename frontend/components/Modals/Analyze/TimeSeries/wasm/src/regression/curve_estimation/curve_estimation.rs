@@ -0,0 +1,112 @@
+use wasm_bindgen::prelude::*;
+
+/// Curve estimation (SPSS CURVEFIT) of y on x or on a time index 1..n. Models:
+/// "linear" y = b0 + b1·x, "logarithmic" y = b0 + b1·ln x, "inverse" y = b0 + b1/x,
+/// "quadratic" y = b0 + b1·x + b2·x², "cubic" y = b0 + b1·x + b2·x² + b3·x³,
+/// "compound" y = b0·b1^x, "power" y = b0·x^b1, "s" y = exp(b0 + b1/x),
+/// "growth" y = exp(b0 + b1·x), "exponential" y = b0·exp(b1·x) and
+/// "logistic" y = 1/(1/u + b0·b1^x) with upper bound u (infinite by default).
+/// The nonlinear models are fitted by least squares on their linearized form.
+#[wasm_bindgen]
+pub struct CurveEstimation {
+    pub(crate) x: Vec<f64>,
+    pub(crate) y: Vec<f64>,
+    pub(crate) model: String,
+    pub(crate) upper_bound: f64,   // Logistic model only
+    pub(crate) time_index: bool,   // Use t = 1..n as the predictor instead of x
+    pub(crate) coefficients: Vec<f64>, // [b0, b1, (b2, b3)] in the model's own form
+    pub(crate) std_errors: Vec<f64>,
+    pub(crate) t_stats: Vec<f64>,
+    pub(crate) p_values: Vec<f64>,
+    pub(crate) predicted: Vec<f64>,
+    pub(crate) anova: Vec<f64>,    // [SS reg, df reg, MS reg, SS res, df res, MS res, F, Sig.]
+    pub(crate) r_squared: f64,
+    pub(crate) adj_r_squared: f64,
+    pub(crate) std_error_estimate: f64,
+}
+
+#[wasm_bindgen]
+impl CurveEstimation {
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: Vec<f64>, y: Vec<f64>, model: String) -> CurveEstimation {
+        CurveEstimation {
+            x,
+            y,
+            model,
+            upper_bound: f64::INFINITY,
+            time_index: false,
+            coefficients: Vec::new(),
+            std_errors: Vec::new(),
+            t_stats: Vec::new(),
+            p_values: Vec::new(),
+            predicted: Vec::new(),
+            anova: Vec::new(),
+            r_squared: f64::NAN,
+            adj_r_squared: f64::NAN,
+            std_error_estimate: f64::NAN,
+        }
+    }
+
+    // Getters
+    pub fn get_x(&self) -> Vec<f64> {
+        self.x.clone()
+    }
+    pub fn get_y(&self) -> Vec<f64> {
+        self.y.clone()
+    }
+    pub fn get_model(&self) -> String {
+        self.model.clone()
+    }
+    pub fn get_upper_bound(&self) -> f64 {
+        self.upper_bound
+    }
+    pub fn get_time_index(&self) -> bool {
+        self.time_index
+    }
+    pub fn get_coefficients(&self) -> Vec<f64> {
+        self.coefficients.clone()
+    }
+    pub fn get_std_errors(&self) -> Vec<f64> {
+        self.std_errors.clone()
+    }
+    pub fn get_t_stats(&self) -> Vec<f64> {
+        self.t_stats.clone()
+    }
+    pub fn get_p_values(&self) -> Vec<f64> {
+        self.p_values.clone()
+    }
+    pub fn get_predicted(&self) -> Vec<f64> {
+        self.predicted.clone()
+    }
+    pub fn get_anova(&self) -> Vec<f64> {
+        self.anova.clone()
+    }
+    pub fn get_r_squared(&self) -> f64 {
+        self.r_squared
+    }
+    pub fn get_r(&self) -> f64 {
+        self.r_squared.sqrt()
+    }
+    pub fn get_adj_r_squared(&self) -> f64 {
+        self.adj_r_squared
+    }
+    pub fn get_std_error_estimate(&self) -> f64 {
+        self.std_error_estimate
+    }
+
+    // Setters
+    /// Changing the model discards the fitted coefficients
+    pub fn set_model(&mut self, model: String) {
+        self.model = model;
+        self.coefficients.clear();
+    }
+    /// Upper bound u of the logistic model; it must exceed every y
+    pub fn set_upper_bound(&mut self, upper_bound: f64) {
+        self.upper_bound = upper_bound;
+        self.coefficients.clear();
+    }
+    pub fn set_time_index(&mut self, time_index: bool) {
+        self.time_index = time_index;
+        self.coefficients.clear();
+    }
+}
@@ -0,0 +1,2 @@
+pub mod curve_estimation;
+pub mod calculate_curve;
\ No newline at end of file
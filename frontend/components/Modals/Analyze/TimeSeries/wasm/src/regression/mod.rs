@@ -1,4 +1,6 @@
 pub mod simple_linear_regression;
 pub mod no_intercept_linear_regression;
 pub mod multiple_linear_regression;
-pub mod simple_exponential_regression;
\ No newline at end of file
+pub mod simple_exponential_regression;
+pub mod structural_break;
+pub mod curve_estimation;
\ No newline at end of file
@@ -0,0 +1,93 @@
+use wasm_bindgen::prelude::*;
+use crate::{least_squares, ALIAS_TOLERANCE};
+use crate::MultipleLinearRegression;
+use nalgebra::DMatrix;
+
+// Internal helpers (not exposed to WASM)
+impl MultipleLinearRegression {
+    /// Singular values and right singular vectors (as columns of V) of the design
+    /// matrix with every column scaled to unit length, as in Belsley, Kuh and Welsch.
+    /// Zero rows pad the matrix to at least as many rows as columns, so there is one
+    /// singular value (possibly zero) per coefficient.
+    fn scaled_svd(&self) -> (Vec<f64>, DMatrix<f64>) {
+        let design_matrix = self.design_columns();
+        let p = design_matrix.len();
+        if p == 0 {
+            return (Vec::new(), DMatrix::zeros(0, 0));
+        }
+        let n = self.get_y().len();
+        let norms: Vec<f64> = design_matrix
+            .iter()
+            .map(|x| x.iter().map(|v| v * v).sum::<f64>().sqrt())
+            .collect();
+        let x = DMatrix::from_fn(n.max(p), p, |t, j| {
+            if t < n && norms[j] > 0.0 { design_matrix[j][t] / norms[j] } else { 0.0 }
+        });
+        let svd = x.svd(false, true);
+        let v = svd.v_t.unwrap().transpose();
+        (svd.singular_values.iter().cloned().collect(), v)
+    }
+}
+
+#[wasm_bindgen]
+impl MultipleLinearRegression {
+    /// Variance inflation factors 1/(1 - R²_j) of the regressors (constant excluded),
+    /// with R²_j from regressing x_j on the other regressors and a constant. A regressor
+    /// that is an exact combination of the others (an aliased one) gets infinity.
+    pub fn calculate_vif(&self) -> Vec<f64> {
+        let skip = if self.get_constant() { 1 } else { 0 };
+        let design_matrix = self.design_columns();
+        let regressors = &design_matrix[skip..];
+        let n = self.get_y().len();
+        (0..regressors.len())
+            .map(|j| {
+                let target = &regressors[j];
+                let mean = target.iter().sum::<f64>() / n as f64;
+                let sst = target.iter().map(|v| (v - mean).powi(2)).sum::<f64>();
+                let mut columns = vec![vec![1.0; n]];
+                columns.extend(regressors.iter().enumerate().filter(|(i, _)| *i != j).map(|(_, x)| x.clone()));
+                match least_squares(&columns, target) {
+                    Some(fit) => {
+                        let sse = fit.residuals.iter().map(|e| e * e).sum::<f64>();
+                        // Aliased by the same criterion as least_squares
+                        if sse <= ALIAS_TOLERANCE.powi(2) * sst { f64::INFINITY } else { sst / sse }
+                    },
+                    None => f64::NAN,
+                }
+            })
+            .collect()
+    }
+
+    /// Condition indices √(λ_max/λ_k) of the column-scaled design matrix (constant
+    /// included), largest first; values above 30 signal strong dependencies
+    pub fn calculate_condition_indices(&self) -> Vec<f64> {
+        let (mut singular_values, _) = self.scaled_svd();
+        if singular_values.is_empty() {
+            return Vec::new();
+        }
+        singular_values.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+        let max = singular_values[0];
+        singular_values.iter().map(|d| if *d > 0.0 { max / d } else { f64::INFINITY }).collect()
+    }
+
+    /// Variance-decomposition proportions: the share of Var(b_j) associated with each
+    /// dimension k, v_jk²/d_k² / Σ_k v_jk²/d_k². Flattened by dimension (ordered as
+    /// calculate_condition_indices), then coefficient (constant first): [k·p + j].
+    /// Two or more coefficients with large proportions on a high condition index
+    /// are involved in the same near dependency. An exact dependency (a zero singular
+    /// value, e.g. fewer observations than coefficients) leaves the proportions undefined
+    /// (NaN on that dimension, 0 elsewhere).
+    pub fn calculate_variance_proportions(&self) -> Vec<f64> {
+        let (singular_values, v) = self.scaled_svd();
+        let p = v.nrows(); // One row of V per coefficient
+        let mut order: Vec<usize> = (0..p).collect();
+        order.sort_by(|&a, &b| singular_values[b].partial_cmp(&singular_values[a]).unwrap_or(std::cmp::Ordering::Equal));
+        let phi = |j: usize, k: usize| v[(j, k)].powi(2) / singular_values[k].powi(2);
+        let totals: Vec<f64> = (0..p).map(|j| (0..p).map(|k| phi(j, k)).sum::<f64>()).collect();
+        order
+            .iter()
+            .flat_map(|&k| (0..p).map(move |j| (j, k)))
+            .map(|(j, k)| phi(j, k) / totals[j])
+            .collect()
+    }
+}
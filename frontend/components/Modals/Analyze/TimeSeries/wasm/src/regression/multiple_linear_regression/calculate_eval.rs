@@ -1,57 +1,19 @@
 use wasm_bindgen::prelude::*;
 use crate::MultipleLinearRegression;
-use crate::{transpose, multiply_matrix_vector};
 use std::f64::consts::PI;
 use statrs::distribution::{ContinuousCDF, FisherSnedecor};
 
 #[wasm_bindgen]
 impl MultipleLinearRegression{
     pub fn calculate_sse(&self)-> f64 {
-        // prepare the transpose matrix x
-        let x_values: Vec<Vec<f64>> = serde_wasm_bindgen::from_value(self.get_x()).unwrap();
-        let m: usize = x_values.len();
-        let n: usize = x_values[0].len();
-        let mut design_matrix: Vec<Vec<f64>> = Vec::new();
-        let mut first_column = Vec::new();
-        if self.get_constant() {
-            for _ in 0..n{
-                first_column.push(1.0);
-            }
-            design_matrix.push(first_column);
-        }
-        for i in 0..m{
-            design_matrix.push(x_values[i].clone());
-        }
-        let x_transpose: Vec<Vec<f64>> = transpose(&design_matrix);
-
-        // prepare b vector
-        let b_vector: Vec<f64> = self.get_beta().clone();
-
-        // prepare y scalar
-        let y_vector: Vec<f64> = self.get_y();
-        let mut y_scalar = 0.0;
-        for i in 0..y_vector.len(){
-            y_scalar += y_vector[i] * y_vector[i];
-        }
-
-        // Multiply x_transpose and y_vector
-        let x_transpose_y: Vec<f64> = multiply_matrix_vector(&x_transpose, &y_vector);
-
-        // Multiply x_transpose_y and b_vector
-        let mut b_vector_x_transpose_y: f64 = 0.0;
-        for i in 0..b_vector.len(){
-            b_vector_x_transpose_y += b_vector[i] * x_transpose_y[i];
-        }
-
-        // Calculate sse
-        let sse = y_scalar - b_vector_x_transpose_y;
-        sse
+        // Sum of squared residuals (stable, unlike y'y - b'X'y)
+        self.residuals().iter().map(|e| e * e).sum::<f64>()
     }
 
     pub fn calculate_mse(&self)-> f64 {
         let sse = self.calculate_sse();
         let n = self.get_y().len() as f64;
-        let mse = sse / (n - self.get_beta().len() as f64);
+        let mse = sse / (n - self.get_rank() as f64);
         mse
     }
 
@@ -75,7 +37,7 @@ impl MultipleLinearRegression{
     pub fn calculate_r2_adj(&self)-> f64 {
         let r2 = self.calculate_r2();
         let n = self.get_y().len() as f64;
-        let k = self.get_beta().len() as f64;
+        let k = self.get_rank() as f64;
         let r2_adj = 1.0 - (1.0 - r2) * (n - 1.0) / (n - k);
         r2_adj
     }
@@ -99,7 +61,7 @@ impl MultipleLinearRegression{
         let sse = self.calculate_sse();
         let n = self.get_y().len() as f64;
         let ssr = sst - sse;
-        let k = self.get_beta().len() as f64 - 1.0;
+        let k = self.get_rank() as f64 - 1.0;
         let f_stat = (ssr / k ) / (sse / (n - k - 1.0));
         f_stat
     }
@@ -107,7 +69,7 @@ impl MultipleLinearRegression{
     pub fn calculate_f_prob(&self)-> f64 {
         let f_stat = self.calculate_f_stat();
         let n = self.get_y().len() as f64;
-        let k = self.get_beta().len() as f64 - 1.0;
+        let k = self.get_rank() as f64 - 1.0;
         let f = FisherSnedecor::new(k, n - k -1.0).unwrap();
         let f_prob = 1.0 - f.cdf(f_stat);
         f_prob
@@ -134,7 +96,7 @@ impl MultipleLinearRegression{
 
     pub fn calculate_aic(&self)-> f64 {
         let n = self.get_y().len() as f64;
-        let p = self.get_beta().len() as f64;
+        let p = self.get_rank() as f64;
         let likelihood = self.calculate_log_likelihood();
         let aic = -2.0 * likelihood + 2.0 * p;
         aic / n
@@ -142,7 +104,7 @@ impl MultipleLinearRegression{
 
     pub fn calculate_sbc(&self)-> f64 {
         let n = self.get_y().len() as f64;
-        let p = self.get_beta().len() as f64;
+        let p = self.get_rank() as f64;
         let likelihood = self.calculate_log_likelihood();
         let bic = -2.0 * likelihood + p * (n.ln());
         bic / n
@@ -166,7 +128,7 @@ impl MultipleLinearRegression{
     pub fn calculate_hqc(&self)-> f64 {
         let likelihood = self.calculate_log_likelihood();
         let n = self.get_y().len() as f64;
-        let k = self.get_beta().len() as f64;
+        let k = self.get_rank() as f64;
         let hc = -2.0 * likelihood + 2.0 * k * (n.ln()).ln();
         hc / n
     }
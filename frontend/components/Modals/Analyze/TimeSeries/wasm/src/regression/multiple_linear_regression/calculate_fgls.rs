@@ -0,0 +1,69 @@
+use wasm_bindgen::prelude::*;
+use crate::MultipleLinearRegression;
+use crate::time_series::ecm::ols_helper::ols;
+
+// Convergence tolerance on ρ between iterations
+const RHO_TOLERANCE: f64 = 1e-6;
+
+#[wasm_bindgen]
+impl MultipleLinearRegression {
+    /// Feasible GLS for AR(1) errors u_t = ρu_{t-1} + ε_t. ρ is estimated from the
+    /// residuals, y and X are quasi-differenced (y_t - ρy_{t-1}) and OLS is refitted,
+    /// iterating until ρ changes by less than 1e-6 or `max_iter` rounds.
+    /// "cochrane_orcutt" drops the first observation; "prais_winsten" keeps it scaled
+    /// by √(1 - ρ²). Stores ρ, the coefficients and their standard errors and returns
+    /// the coefficients (OLS estimates are left unchanged). Starts from the OLS fit, so it
+    /// errors before calculate_regression.
+    pub fn calculate_fgls(&mut self, method: String, max_iter: usize) -> Result<Vec<f64>, JsValue> {
+        if !self.is_estimated() {
+            return Err(JsValue::from_str("Regression not estimated, call calculate_regression first"));
+        }
+        let y = self.get_y();
+        let n = y.len();
+        let design_matrix = self.design_columns();
+        let keep_first = match method.as_str() {
+            "cochrane_orcutt" => false,
+            "prais_winsten" => true,
+            _ => return Err(JsValue::from_str(&format!("Unknown method: {}", method))),
+        };
+        let residuals_for = |beta: &[f64]| -> Vec<f64> {
+            (0..n)
+                .map(|t| y[t] - design_matrix.iter().zip(beta.iter()).map(|(x, b)| x[t] * b).sum::<f64>())
+                .collect()
+        };
+        let transform = |v: &[f64], rho: f64| -> Vec<f64> {
+            let mut out = Vec::with_capacity(n);
+            if keep_first {
+                out.push((1.0 - rho * rho).sqrt() * v[0]);
+            }
+            out.extend((1..n).map(|t| v[t] - rho * v[t - 1]));
+            out
+        };
+
+        let mut beta = self.get_beta();
+        let mut rho = 0.0;
+        let mut se = Vec::new();
+        for _ in 0..max_iter.max(1) {
+            let e = residuals_for(&beta);
+            let numerator: f64 = (1..n).map(|t| e[t] * e[t - 1]).sum();
+            let denominator: f64 = e[..n - 1].iter().map(|v| v * v).sum();
+            let new_rho = (numerator / denominator).clamp(-0.999, 0.999);
+            let columns: Vec<Vec<f64>> = design_matrix.iter().map(|x| transform(x, new_rho)).collect();
+            let fit = match ols(&columns, &transform(&y, new_rho)) {
+                Some(fit) => fit,
+                None => break,
+            };
+            beta = fit.beta;
+            se = fit.se;
+            let change = (new_rho - rho).abs();
+            rho = new_rho;
+            if change < RHO_TOLERANCE {
+                break;
+            }
+        }
+        self.set_rho(rho);
+        self.set_fgls_se(se);
+        self.set_fgls_beta(beta.clone());
+        Ok(beta)
+    }
+}
@@ -0,0 +1,134 @@
+use wasm_bindgen::prelude::*;
+use crate::{least_squares, multiply_matrix};
+use crate::MultipleLinearRegression;
+use statrs::distribution::{ContinuousCDF, StudentsT};
+use std::f64::consts::PI;
+
+/// HAC kernel weight at x = j / bandwidth: Bartlett for "newey_west",
+/// quadratic spectral for "andrews"
+pub fn hac_kernel(kernel: &str, x: f64) -> f64 {
+    match kernel {
+        "newey_west" => (1.0 - x.abs()).max(0.0),
+        "andrews" => {
+            if x == 0.0 {
+                return 1.0;
+            }
+            let z = 6.0 * PI * x / 5.0;
+            25.0 / (12.0 * PI * PI * x * x) * (z.sin() / z - z.cos())
+        },
+        _ => panic!("Unknown kernel: {}", kernel),
+    }
+}
+
+// Internal helpers (not exposed to WASM)
+impl MultipleLinearRegression {
+    /// Score series x_t·u_t, one vector per regressor
+    fn scores(&self) -> Vec<Vec<f64>> {
+        let residuals = self.residuals();
+        self.design_columns()
+            .iter()
+            .map(|x| x.iter().zip(residuals.iter()).map(|(a, u)| a * u).collect())
+            .collect()
+    }
+
+    /// HAC covariance (X'X)⁻¹·S·(X'X)⁻¹ with S = Γ_0 + Σ_j k(j/b)(Γ_j + Γ_j'); aliased
+    /// coefficients are left out of (X'X)⁻¹ and get a NaN variance
+    fn hac_covariance(&self, kernel: &str, bandwidth: f64) -> Result<Vec<Vec<f64>>, JsValue> {
+        if !self.is_estimated() {
+            return Err(JsValue::from_str("Regression not estimated, call calculate_regression first"));
+        }
+        let fit = least_squares(&self.design_columns(), &self.get_y())
+            .ok_or_else(|| JsValue::from_str("No regressor can be estimated in the design matrix"))?;
+        let xtx_inv: Vec<Vec<f64>> = fit
+            .xtx_inv
+            .iter()
+            .map(|row| row.iter().map(|v| if v.is_nan() { 0.0 } else { *v }).collect())
+            .collect();
+        let scores = self.scores();
+        let k = scores.len();
+        let n = scores[0].len();
+        let gamma = |j: usize| -> Vec<Vec<f64>> {
+            (0..k)
+                .map(|a| (0..k).map(|b| (j..n).map(|t| scores[a][t] * scores[b][t - j]).sum()).collect())
+                .collect()
+        };
+        let mut s = gamma(0);
+        for j in 1..n {
+            let w = hac_kernel(kernel, j as f64 / bandwidth);
+            // Bartlett weights vanish past the bandwidth; the QS kernel uses every lag
+            if kernel == "newey_west" && w == 0.0 {
+                break;
+            }
+            let g = gamma(j);
+            for a in 0..k {
+                for b in 0..k {
+                    s[a][b] += w * (g[a][b] + g[b][a]);
+                }
+            }
+        }
+        let mut covariance = multiply_matrix(&multiply_matrix(&xtx_inv, &s), &xtx_inv);
+        for &i in fit.aliased.iter() {
+            covariance[i][i] = f64::NAN;
+        }
+        Ok(covariance)
+    }
+}
+
+#[wasm_bindgen]
+impl MultipleLinearRegression {
+    /// Automatic HAC bandwidth: 4(n/100)^{2/9} + 1 (the Bartlett weights then reach lag
+    /// ⌊4(n/100)^{2/9}⌋) for "newey_west", and Andrews' (1991) AR(1) plug-in
+    /// 1.3221(n·α(2))^{1/5} for "andrews", fitted to the scores of the non-constant regressors
+    /// (so "andrews" errors before calculate_regression)
+    pub fn hac_bandwidth(&self, kernel: String) -> Result<f64, JsValue> {
+        let n = self.get_y().len() as f64;
+        let bandwidth = match kernel.as_str() {
+            "newey_west" => (4.0 * (n / 100.0).powf(2.0 / 9.0)).floor() + 1.0,
+            "andrews" => {
+                if !self.is_estimated() {
+                    return Err(JsValue::from_str("Regression not estimated, call calculate_regression first"));
+                }
+                let skip = if self.get_constant() { 1 } else { 0 };
+                let (mut numerator, mut denominator) = (0.0, 0.0);
+                for v in self.scores().iter().skip(skip) {
+                    let sxx: f64 = v[..v.len() - 1].iter().map(|a| a * a).sum();
+                    let sxy: f64 = (1..v.len()).map(|t| v[t] * v[t - 1]).sum();
+                    let rho = if sxx > 0.0 { (sxy / sxx).clamp(-0.97, 0.97) } else { 0.0 };
+                    let sigma2 = (1..v.len()).map(|t| (v[t] - rho * v[t - 1]).powi(2)).sum::<f64>() / (v.len() - 1) as f64;
+                    numerator += 4.0 * rho * rho * sigma2 * sigma2 / (1.0 - rho).powi(8);
+                    denominator += sigma2 * sigma2 / (1.0 - rho).powi(4);
+                }
+                let alpha = if denominator > 0.0 { numerator / denominator } else { 0.0 };
+                (1.3221 * (n * alpha).powf(0.2)).max(1.0)
+            },
+            _ => panic!("Unknown kernel: {}", kernel),
+        };
+        Ok(bandwidth)
+    }
+
+    /// Heteroskedasticity and autocorrelation consistent standard errors with the
+    /// "newey_west" (Bartlett) or "andrews" (quadratic spectral) kernel;
+    /// bandwidth <= 0 selects hac_bandwidth; errors before calculate_regression
+    pub fn calculate_hac_standard_error(&self, kernel: String, bandwidth: f64) -> Result<Vec<f64>, JsValue> {
+        let bandwidth = if bandwidth > 0.0 { bandwidth } else { self.hac_bandwidth(kernel.clone())? };
+        let covariance = self.hac_covariance(&kernel, bandwidth)?;
+        Ok((0..covariance.len()).map(|i| if covariance[i][i].is_nan() { f64::NAN } else { covariance[i][i].max(0.0).sqrt() }).collect())
+    }
+
+    /// Two-sided p-values of the coefficients with HAC standard errors, t(n - k);
+    /// NaN when there are no residual degrees of freedom
+    pub fn calculate_hac_pvalue(&self, kernel: String, bandwidth: f64) -> Result<Vec<f64>, JsValue> {
+        let beta = self.get_beta();
+        let se = self.calculate_hac_standard_error(kernel, bandwidth)?;
+        let df = self.get_y().len() as f64 - self.get_rank() as f64;
+        let t_dist = match StudentsT::new(0.0, 1.0, df) {
+            Ok(t_dist) => t_dist,
+            Err(_) => return Ok(vec![f64::NAN; beta.len()]),
+        };
+        Ok(beta
+            .iter()
+            .zip(se.iter())
+            .map(|(b, s)| if s.is_nan() { f64::NAN } else { 2.0 * (1.0 - t_dist.cdf((b / s).abs())) })
+            .collect())
+    }
+}
@@ -1,4 +1,4 @@
-use nalgebra::DMatrix;
+use nalgebra::{DMatrix, DVector};
 // Fungsi untuk transpose matriks
 pub fn transpose(matrix: &Vec<Vec<f64>>) -> Vec<Vec<f64>> {
     let cols = matrix.len();
@@ -61,4 +61,72 @@ pub fn invert_matrix(matrix: &Vec<Vec<f64>>) -> Option<Vec<Vec<f64>>> {
     let mat = DMatrix::from_row_slice(n, n, &data);
     // Coba inversi menggunakan `try_inverse()`
     mat.try_inverse().map(|inv_mat| inv_mat.data.as_vec().chunks(n).map(|r| r.to_vec()).collect())
-}
\ No newline at end of file
+}
+
+// Relative norm below which a column is treated as a linear combination of earlier ones
+pub const ALIAS_TOLERANCE: f64 = 1e-7;
+
+// Least-squares solution of y = Xb from the QR decomposition of X
+pub struct LeastSquares {
+    pub beta: Vec<f64>,          // Aliased coefficients are 0
+    pub residuals: Vec<f64>,
+    pub rank: usize,
+    pub aliased: Vec<usize>,     // Positions of the columns dropped as collinear
+    pub xtx_inv: Vec<Vec<f64>>,  // (X'X)^-1 of the retained columns, NaN rows/columns for aliased ones
+}
+
+/// Least squares for X given as a list of columns, solved by QR rather than the normal
+/// equations. Columns are taken in order and one whose residual after projection on the
+/// retained columns is below 1e-7 of its norm is aliased (as lm() does in R); the
+/// remaining columns give R, b = R⁻¹Q'y and (X'X)⁻¹ = R⁻¹R⁻ᵀ. Returns None when no
+/// column can be retained.
+pub fn least_squares(columns: &[Vec<f64>], y: &[f64]) -> Option<LeastSquares> {
+    let k = columns.len();
+    let n = y.len();
+    // Modified Gram-Schmidt, applied twice, to find the columns to keep
+    let mut basis: Vec<Vec<f64>> = Vec::new();
+    let mut kept = Vec::new();
+    let mut aliased = Vec::new();
+    for (j, column) in columns.iter().enumerate() {
+        let norm = column.iter().map(|v| v * v).sum::<f64>().sqrt();
+        let mut r = column.clone();
+        for _ in 0..2 {
+            for q in basis.iter() {
+                let dot: f64 = q.iter().zip(r.iter()).map(|(a, b)| a * b).sum();
+                r.iter_mut().zip(q.iter()).for_each(|(v, a)| *v -= dot * a);
+            }
+        }
+        let residual_norm = r.iter().map(|v| v * v).sum::<f64>().sqrt();
+        if norm == 0.0 || !residual_norm.is_finite() || residual_norm <= ALIAS_TOLERANCE * norm {
+            aliased.push(j);
+        } else {
+            basis.push(r.iter().map(|v| v / residual_norm).collect());
+            kept.push(j);
+        }
+    }
+    let rank = kept.len();
+    if rank == 0 {
+        return None;
+    }
+
+    let x = DMatrix::from_fn(n, rank, |t, j| columns[kept[j]][t]);
+    let qr = x.qr();
+    let r = qr.r();
+    let qty = qr.q().transpose() * DVector::from_column_slice(y);
+    let b = r.solve_upper_triangular(&qty)?;
+    let r_inv = r.solve_upper_triangular(&DMatrix::identity(rank, rank))?;
+    let cov = &r_inv * r_inv.transpose();
+
+    let mut beta = vec![0.0; k];
+    let mut xtx_inv = vec![vec![f64::NAN; k]; k];
+    for (a, &i) in kept.iter().enumerate() {
+        beta[i] = b[a];
+        for (c, &j) in kept.iter().enumerate() {
+            xtx_inv[i][j] = cov[(a, c)];
+        }
+    }
+    let residuals = (0..n)
+        .map(|t| y[t] - kept.iter().map(|&j| columns[j][t] * beta[j]).sum::<f64>())
+        .collect();
+    Some(LeastSquares { beta, residuals, rank, aliased, xtx_inv })
+}
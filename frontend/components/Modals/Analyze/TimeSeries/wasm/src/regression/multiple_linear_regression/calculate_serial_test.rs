@@ -0,0 +1,50 @@
+use wasm_bindgen::prelude::*;
+use crate::{MultipleLinearRegression, GARCH};
+use crate::time_series::ecm::ols_helper::ols;
+use crate::time_series::helper_structs::ArchLMResult;
+use statrs::distribution::{ChiSquared, ContinuousCDF, FisherSnedecor};
+
+#[wasm_bindgen]
+impl MultipleLinearRegression {
+    /// Breusch-Godfrey LM test for serial correlation up to `order`: the residuals are
+    /// regressed on X and e_{t-1}..e_{t-order} (presample residuals set to zero).
+    /// Returns [LM = n·R², df, p-value (χ²), F, F p-value]; errors before calculate_regression.
+    pub fn breusch_godfrey_test(&self, order: usize) -> Result<Vec<f64>, JsValue> {
+        if !self.is_estimated() {
+            return Err(JsValue::from_str("Regression not estimated, call calculate_regression first"));
+        }
+        let residuals = self.residuals();
+        let n = residuals.len();
+        let mut columns = self.design_columns();
+        let k = columns.len();
+        // The auxiliary regression needs residual degrees of freedom
+        if order == 0 || n <= k + order {
+            return Ok(vec![f64::NAN, order as f64, f64::NAN, f64::NAN, f64::NAN]);
+        }
+        for lag in 1..=order {
+            columns.push((0..n).map(|t| if t >= lag { residuals[t - lag] } else { 0.0 }).collect());
+        }
+        let mean = residuals.iter().sum::<f64>() / n as f64;
+        let sst: f64 = residuals.iter().map(|e| (e - mean).powi(2)).sum();
+        let fit = match ols(&columns, &residuals) {
+            Some(fit) => fit,
+            None => return Ok(vec![f64::NAN, order as f64, f64::NAN, f64::NAN, f64::NAN]),
+        };
+        let r_squared = 1.0 - fit.ssr / sst;
+        let lm = n as f64 * r_squared;
+        let p_value = 1.0 - ChiSquared::new(order as f64).unwrap().cdf(lm);
+        let df2 = (n - k - order) as f64;
+        let f_stat = (r_squared / order as f64) / ((1.0 - r_squared) / df2);
+        let f_p_value = 1.0 - FisherSnedecor::new(order as f64, df2).unwrap().cdf(f_stat);
+        Ok(vec![lm, order as f64, p_value, f_stat, f_p_value])
+    }
+
+    /// ARCH-LM test on the regression residuals (see GARCH::arch_lm_test); errors before
+    /// calculate_regression
+    pub fn arch_lm_test(&self, lags: usize) -> Result<ArchLMResult, JsValue> {
+        if !self.is_estimated() {
+            return Err(JsValue::from_str("Regression not estimated, call calculate_regression first"));
+        }
+        Ok(GARCH::arch_lm_test(self.residuals(), lags))
+    }
+}
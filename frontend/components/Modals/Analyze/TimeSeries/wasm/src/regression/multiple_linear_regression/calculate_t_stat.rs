@@ -16,11 +16,16 @@ impl MultipleLinearRegression{
 
     pub fn calculate_pvalue(&self)-> Vec<f64>{
         let t_stat = self.calculate_t_stat();
-        let df = self.get_y().len() as f64 - self.get_beta().len() as f64;
+        let df = self.get_y().len() as f64 - self.get_rank() as f64;
         let t_dist = StudentsT::new(0.0, 1.0, df).unwrap();
         let mut p_value = Vec::new();
         for t in t_stat.iter(){
-            p_value.push(2.0 * (1.0 - t_dist.cdf(t.abs())));
+            // Aliased coefficients have no t statistic
+            if t.is_nan() {
+                p_value.push(f64::NAN);
+            } else {
+                p_value.push(2.0 * (1.0 - t_dist.cdf(t.abs())));
+            }
         }
         p_value
     }
@@ -3,4 +3,9 @@ pub mod calculate_matrix;
 pub mod calculate_regression;
 pub mod calculate_standard_error;
 pub mod calculate_t_stat;
-pub mod calculate_eval;
\ No newline at end of file
+pub mod calculate_eval;
+pub mod calculate_hac;
+pub mod calculate_serial_test;
+pub mod calculate_fgls;
+pub mod calculate_structural_break;
+pub mod calculate_collinearity;
\ No newline at end of file
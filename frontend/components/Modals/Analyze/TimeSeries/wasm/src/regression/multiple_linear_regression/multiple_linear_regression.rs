@@ -11,6 +11,11 @@ pub struct MultipleLinearRegression {
     y_prediction: Vec<f64>,
     beta: Vec<f64>,
     constant: bool,
+    fgls_beta: Vec<f64>,
+    fgls_se: Vec<f64>,
+    rho: f64,
+    rank: usize,
+    aliased: Vec<usize>,
 }
 
 #[wasm_bindgen]
@@ -24,6 +29,11 @@ impl MultipleLinearRegression {
             y_prediction: Vec::new(),
             beta: Vec::new(),
             constant: true,
+            fgls_beta: Vec::new(),
+            fgls_se: Vec::new(),
+            rho: f64::NAN,
+            rank: 0,
+            aliased: Vec::new(),
         }
     }
 
@@ -43,6 +53,23 @@ impl MultipleLinearRegression {
     pub fn get_constant(&self) -> bool {
         self.constant.clone()
     }
+    pub fn get_fgls_beta(&self) -> Vec<f64> {
+        self.fgls_beta.clone()
+    }
+    pub fn get_fgls_se(&self) -> Vec<f64> {
+        self.fgls_se.clone()
+    }
+    pub fn get_rho(&self) -> f64 {
+        self.rho
+    }
+    /// Number of linearly independent columns of the design matrix
+    pub fn get_rank(&self) -> usize {
+        self.rank
+    }
+    /// Positions in beta (constant first) of the coefficients aliased by collinearity
+    pub fn get_aliased(&self) -> Vec<usize> {
+        self.aliased.clone()
+    }
 
     // Setters
     pub fn set_y_prediction(&mut self, y_prediction: Vec<f64>) {
@@ -54,4 +81,45 @@ impl MultipleLinearRegression {
     pub fn set_constant(&mut self, constant: bool) {
         self.constant = constant;
     }
+    pub fn set_fgls_beta(&mut self, fgls_beta: Vec<f64>) {
+        self.fgls_beta = fgls_beta;
+    }
+    pub fn set_fgls_se(&mut self, fgls_se: Vec<f64>) {
+        self.fgls_se = fgls_se;
+    }
+    pub fn set_rho(&mut self, rho: f64) {
+        self.rho = rho;
+    }
+    pub fn set_rank(&mut self, rank: usize) {
+        self.rank = rank;
+    }
+    pub fn set_aliased(&mut self, aliased: Vec<usize>) {
+        self.aliased = aliased;
+    }
+}
+
+// Internal helpers (not exposed to WASM)
+impl MultipleLinearRegression {
+    /// Design matrix as columns, led by a column of ones when the model has a constant
+    pub fn design_columns(&self) -> Vec<Vec<f64>> {
+        let mut design_matrix: Vec<Vec<f64>> = Vec::new();
+        if self.constant {
+            design_matrix.push(vec![1.0; self.y.len()]);
+        }
+        design_matrix.extend(self.x.iter().cloned());
+        design_matrix
+    }
+
+    /// Whether calculate_regression has stored a coefficient for every design column
+    pub fn is_estimated(&self) -> bool {
+        !self.beta.is_empty() && self.beta.len() == self.x.len() + usize::from(self.constant)
+    }
+
+    /// Residuals y - Xb of the current coefficients
+    pub fn residuals(&self) -> Vec<f64> {
+        let design_matrix = self.design_columns();
+        (0..self.y.len())
+            .map(|t| self.y[t] - design_matrix.iter().zip(self.beta.iter()).map(|(x, b)| x[t] * b).sum::<f64>())
+            .collect()
+    }
 }
\ No newline at end of file
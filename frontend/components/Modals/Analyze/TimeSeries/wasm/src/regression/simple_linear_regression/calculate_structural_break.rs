@@ -0,0 +1,36 @@
+use wasm_bindgen::prelude::*;
+use crate::SimpleLinearRegression;
+use crate::regression::structural_break::bai_perron::{bai_perron, BaiPerronResult};
+use crate::regression::structural_break::chow_test::chow_test;
+use crate::regression::structural_break::cusum_test::{cusum, cusum_of_squares, CusumResult};
+
+#[wasm_bindgen]
+impl SimpleLinearRegression {
+    // Chow test at a known break: [F, df1, df2, p-value]
+    pub fn chow_test(&self, break_index: usize) -> Result<Vec<f64>, JsValue> {
+        chow_test(&self.design_columns(), &self.get_y(), break_index)
+            .ok_or_else(|| JsValue::from_str("Each regime needs more observations than coefficients"))
+    }
+
+    // CUSUM of recursive residuals with 5% boundaries
+    pub fn cusum_test(&self) -> Result<CusumResult, JsValue> {
+        cusum(&self.design_columns(), &self.get_y()).ok_or_else(|| JsValue::from_str("Too few observations for recursive residuals"))
+    }
+
+    // CUSUM of squares with 5% boundaries
+    pub fn cusum_of_squares_test(&self) -> Result<CusumResult, JsValue> {
+        cusum_of_squares(&self.design_columns(), &self.get_y()).ok_or_else(|| JsValue::from_str("Too few observations for recursive residuals"))
+    }
+
+    // Bai-Perron multiple breakpoints with trimming ε (e.g. 0.15)
+    pub fn bai_perron_test(&self, max_breaks: usize, trimming: f64) -> Result<BaiPerronResult, JsValue> {
+        bai_perron(&self.design_columns(), &self.get_y(), max_breaks, trimming).map_err(|e| JsValue::from_str(&e))
+    }
+}
+
+// Internal helpers (not exposed to WASM)
+impl SimpleLinearRegression {
+    pub fn design_columns(&self) -> Vec<Vec<f64>> {
+        vec![vec![1.0; self.get_x().len()], self.get_x()]
+    }
+}
@@ -0,0 +1,204 @@
+use wasm_bindgen::prelude::*;
+use crate::regression::structural_break::recursive_residuals::recursive_residuals;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand_distr::{Distribution, StandardNormal};
+
+// Null distribution of sup-F: replications of a pure mean-shift problem with q series on a
+// grid of BP_GRID points (fixed seed for reproducible output)
+const BP_REPS: usize = 1000;
+const BP_GRID: usize = 200;
+const BP_SEED: u64 = 42;
+
+// Bai-Perron multiple structural change estimates
+// NOTE: Vec<f64> fields can't be public in WASM structs - use getters
+#[wasm_bindgen]
+pub struct BaiPerronResult {
+    breaks: Vec<usize>,      // First row (0-based) of each new regime at the BIC-selected count
+    all_breaks: Vec<usize>,  // Optimal breaks for m = 1..M, concatenated (m entries each)
+    ssr: Vec<f64>,           // Global minimum SSR for m = 0..M
+    bic: Vec<f64>,           // For m = 0..M
+    sup_f: Vec<f64>,         // sup-F(m) against no break, m = 1..M
+    critical_values: Vec<f64>, // 5% critical values of sup-F(m)
+    p_values: Vec<f64>,
+    pub n_breaks: usize,     // BIC-selected count
+    pub trimming: f64,
+    pub min_segment: usize,
+}
+
+#[wasm_bindgen]
+impl BaiPerronResult {
+    pub fn get_breaks(&self) -> Vec<usize> { self.breaks.clone() }
+    pub fn get_ssr(&self) -> Vec<f64> { self.ssr.clone() }
+    pub fn get_bic(&self) -> Vec<f64> { self.bic.clone() }
+    pub fn get_sup_f(&self) -> Vec<f64> { self.sup_f.clone() }
+    pub fn get_critical_values(&self) -> Vec<f64> { self.critical_values.clone() }
+    pub fn get_p_values(&self) -> Vec<f64> { self.p_values.clone() }
+
+    /// Optimal break rows with exactly m breaks (1 ≤ m ≤ M); empty for m outside that range
+    pub fn get_breaks_for(&self, m: usize) -> Vec<usize> {
+        // ssr holds m = 0..M
+        if m == 0 || m >= self.ssr.len() {
+            return Vec::new();
+        }
+        let start = m * (m - 1) / 2;
+        self.all_breaks[start..start + m].to_vec()
+    }
+}
+
+/// Minimum SSR of every partition into m + 1 segments of at least h rows, m = 0..max_breaks,
+/// by dynamic programming over the segment SSRs ssr[i][j] (rows i..=j). Returns the SSRs and
+/// the first row of each new regime for m = 1..max_breaks.
+pub fn optimal_partitions(ssr: &[Vec<f64>], h: usize, max_breaks: usize) -> (Vec<f64>, Vec<Vec<usize>>) {
+    let n = ssr.len();
+    // best[m][j]: minimum SSR of rows 0..=j in m + 1 segments; start[m][j]: first row of the last one
+    let mut best = vec![vec![f64::INFINITY; n]; max_breaks + 1];
+    let mut start = vec![vec![0usize; n]; max_breaks + 1];
+    for j in h - 1..n {
+        best[0][j] = ssr[0][j];
+    }
+    for m in 1..=max_breaks {
+        for j in (m + 1) * h - 1..n {
+            for b in m * h..=j + 1 - h {
+                let value = best[m - 1][b - 1] + ssr[b][j];
+                if value < best[m][j] {
+                    best[m][j] = value;
+                    start[m][j] = b;
+                }
+            }
+        }
+    }
+    let totals = (0..=max_breaks).map(|m| best[m][n - 1]).collect();
+    let breaks = (1..=max_breaks)
+        .map(|m| {
+            let mut dates = Vec::with_capacity(m);
+            let mut j = n - 1;
+            for level in (1..=m).rev() {
+                let b = start[level][j];
+                dates.push(b);
+                j = b - 1;
+            }
+            dates.reverse();
+            dates
+        })
+        .collect();
+    (totals, breaks)
+}
+
+/// sup-F(m) = ((SSR_0 - SSR_m)/(m·q)) / (SSR_m/(n - (m + 1)q)) for m = 1..M
+fn sup_f_statistics(totals: &[f64], n: usize, q: usize) -> Vec<f64> {
+    (1..totals.len())
+        .map(|m| {
+            let df = n as f64 - ((m + 1) * q) as f64;
+            ((totals[0] - totals[m]) / (m * q) as f64) / (totals[m] / df)
+        })
+        .collect()
+}
+
+/// Simulated null distribution of sup-F(m), m = 1..M, for q changing regressors and
+/// trimming ε, sorted ascending for each m
+pub fn sup_f_null_distribution(q: usize, trimming: f64, max_breaks: usize) -> Vec<Vec<f64>> {
+    let t = BP_GRID;
+    let h = ((trimming * t as f64).floor() as usize).max(2);
+    let mut rng = StdRng::seed_from_u64(BP_SEED);
+    let mut draws = vec![Vec::with_capacity(BP_REPS); max_breaks];
+    for _ in 0..BP_REPS {
+        // Cumulative sums of q independent N(0, 1) series; each segment SSR is that of
+        // fitting segment means
+        let mut sum = vec![vec![0.0; t + 1]; q];
+        let mut sum_sq = vec![vec![0.0; t + 1]; q];
+        for c in 0..q {
+            for i in 0..t {
+                let e: f64 = StandardNormal.sample(&mut rng);
+                sum[c][i + 1] = sum[c][i] + e;
+                sum_sq[c][i + 1] = sum_sq[c][i] + e * e;
+            }
+        }
+        let ssr: Vec<Vec<f64>> = (0..t)
+            .map(|i| {
+                (0..t)
+                    .map(|j| {
+                        if j < i {
+                            return f64::INFINITY;
+                        }
+                        let len = (j + 1 - i) as f64;
+                        (0..q)
+                            .map(|c| {
+                                let s = sum[c][j + 1] - sum[c][i];
+                                sum_sq[c][j + 1] - sum_sq[c][i] - s * s / len
+                            })
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect();
+        let (totals, _) = optimal_partitions(&ssr, h, max_breaks);
+        // q·t observations in all
+        for (m, f) in sup_f_statistics(&totals, q * t, q).into_iter().enumerate() {
+            draws[m].push(f);
+        }
+    }
+    for d in draws.iter_mut() {
+        d.sort_by(|a, b| a.total_cmp(b));
+    }
+    draws
+}
+
+/// Bai-Perron (1998, 2003) pure structural change in all coefficients with up to
+/// `max_breaks` breaks and segments of at least max(⌊ε·n⌋, k + 1) rows. Breaks are global
+/// SSR minimisers; the count minimises BIC = n·ln(SSR_m/n) + ((m + 1)k + m)·ln n.
+/// sup-F(m) tests m breaks against none with simulated asymptotic critical values.
+pub fn bai_perron(columns: &[Vec<f64>], y: &[f64], max_breaks: usize, trimming: f64) -> Result<BaiPerronResult, String> {
+    let n = y.len();
+    let k = columns.len();
+    let h = ((trimming * n as f64).floor() as usize).max(k + 1);
+    if max_breaks == 0 || (max_breaks + 1) * h > n {
+        return Err(format!(
+            "Cannot fit {} breaks with segments of at least {} rows in {} observations", max_breaks, h, n
+        ));
+    }
+
+    // SSR of every segment i..=j from its recursive residuals
+    let mut ssr = vec![vec![f64::INFINITY; n]; n];
+    for (i, row) in ssr.iter_mut().enumerate() {
+        if let Some((first, w, init_ssr)) = recursive_residuals(columns, y, i, n) {
+            row[first - 1] = init_ssr;
+            let mut total = init_ssr;
+            for (offset, v) in w.iter().enumerate() {
+                total += v * v;
+                row[first + offset] = total;
+            }
+        }
+    }
+
+    let (totals, breaks) = optimal_partitions(&ssr, h, max_breaks);
+    let n_f = n as f64;
+    let bic: Vec<f64> = totals
+        .iter()
+        .enumerate()
+        .map(|(m, s)| n_f * (s / n_f).ln() + ((m + 1) * k + m) as f64 * n_f.ln())
+        .collect();
+    let n_breaks = (0..bic.len()).fold(0, |best, m| if bic[m] < bic[best] { m } else { best });
+
+    let sup_f = sup_f_statistics(&totals, n, k);
+    let null = sup_f_null_distribution(k, h as f64 / n_f, max_breaks);
+    let critical_values = null.iter().map(|d| d[((0.95 * d.len() as f64).ceil() as usize).min(d.len()) - 1]).collect();
+    let p_values = sup_f
+        .iter()
+        .zip(null.iter())
+        .map(|(f, d)| (1 + d.iter().filter(|v| *v >= f).count()) as f64 / (d.len() + 1) as f64)
+        .collect();
+
+    Ok(BaiPerronResult {
+        breaks: if n_breaks > 0 { breaks[n_breaks - 1].clone() } else { Vec::new() },
+        all_breaks: breaks.into_iter().flatten().collect(),
+        ssr: totals,
+        bic,
+        sup_f,
+        critical_values,
+        p_values,
+        n_breaks,
+        trimming,
+        min_segment: h,
+    })
+}
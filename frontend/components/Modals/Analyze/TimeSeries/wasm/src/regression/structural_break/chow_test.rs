@@ -0,0 +1,22 @@
+use crate::time_series::ecm::ols_helper::ols;
+use statrs::distribution::{ContinuousCDF, FisherSnedecor};
+
+/// Chow test of equal coefficients before and after `break_index` (first row of the
+/// second regime): F = ((SSR - SSR_1 - SSR_2)/k) / ((SSR_1 + SSR_2)/(n - 2k)).
+/// Returns [F, k, n - 2k, p-value], or None when a regime has no more rows than regressors
+/// or a fit is singular.
+pub fn chow_test(columns: &[Vec<f64>], y: &[f64], break_index: usize) -> Option<Vec<f64>> {
+    let n = y.len();
+    let k = columns.len();
+    if break_index <= k || n - break_index.min(n) <= k {
+        return None;
+    }
+    let part = |a: usize, b: usize| -> Vec<Vec<f64>> { columns.iter().map(|c| c[a..b].to_vec()).collect() };
+    let pooled = ols(columns, y)?.ssr;
+    let first = ols(&part(0, break_index), &y[..break_index])?.ssr;
+    let second = ols(&part(break_index, n), &y[break_index..])?.ssr;
+    let df2 = (n - 2 * k) as f64;
+    let f_stat = ((pooled - first - second) / k as f64) / ((first + second) / df2);
+    let p_value = 1.0 - FisherSnedecor::new(k as f64, df2).unwrap().cdf(f_stat);
+    Some(vec![f_stat, k as f64, df2, p_value])
+}
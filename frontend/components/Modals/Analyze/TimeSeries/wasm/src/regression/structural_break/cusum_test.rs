@@ -0,0 +1,84 @@
+use wasm_bindgen::prelude::*;
+use crate::regression::structural_break::recursive_residuals::recursive_residuals;
+
+// 5% boundary constant of the CUSUM test (Brown, Durbin and Evans, 1975)
+const CUSUM_A_5: f64 = 0.948;
+// 5% two-sided Kolmogorov-Smirnov constant behind the CUSUM of squares band
+const CUSUMSQ_KS_5: f64 = 1.3581;
+
+// CUSUM or CUSUM-of-squares path with its 5% boundaries
+// NOTE: Vec<f64> fields can't be public in WASM structs - use getters
+#[wasm_bindgen]
+pub struct CusumResult {
+    index: Vec<usize>, // Observation (0-based) of each point of the path
+    statistic: Vec<f64>,
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+    pub crossed: bool, // Path leaves the band somewhere: parameter instability at 5%
+}
+
+#[wasm_bindgen]
+impl CusumResult {
+    pub fn get_index(&self) -> Vec<usize> { self.index.clone() }
+    pub fn get_statistic(&self) -> Vec<f64> { self.statistic.clone() }
+    pub fn get_lower(&self) -> Vec<f64> { self.lower.clone() }
+    pub fn get_upper(&self) -> Vec<f64> { self.upper.clone() }
+}
+
+// Internal constructor (not exposed to WASM)
+impl CusumResult {
+    pub fn new(index: Vec<usize>, statistic: Vec<f64>, lower: Vec<f64>, upper: Vec<f64>) -> CusumResult {
+        let crossed = statistic.iter().zip(lower.iter().zip(upper.iter())).any(|(s, (l, u))| s < l || s > u);
+        CusumResult { index, statistic, lower, upper, crossed }
+    }
+}
+
+/// CUSUM of recursive residuals W_r = Σ_{j≤r} w_j / σ̂_w with the 5% band
+/// ±a(√(n-k) + 2(r-k)/√(n-k)), a = 0.948
+pub fn cusum(columns: &[Vec<f64>], y: &[f64]) -> Option<CusumResult> {
+    let n = y.len();
+    let (first, w, _) = recursive_residuals(columns, y, 0, n)?;
+    let m = w.len();
+    if m < 2 {
+        return None;
+    }
+    let mean = w.iter().sum::<f64>() / m as f64;
+    let sigma = (w.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (m - 1) as f64).sqrt();
+    let root = (m as f64).sqrt();
+    let mut total = 0.0;
+    let mut statistic = Vec::with_capacity(m);
+    let mut upper = Vec::with_capacity(m);
+    for (i, v) in w.iter().enumerate() {
+        total += v / sigma;
+        statistic.push(total);
+        upper.push(CUSUM_A_5 * (root + 2.0 * (i + 1) as f64 / root));
+    }
+    let lower = upper.iter().map(|u| -u).collect();
+    Some(CusumResult::new((first..n).collect(), statistic, lower, upper))
+}
+
+/// CUSUM of squares S_r = Σ_{j≤r} w_j² / Σ w_j² around its mean (r - k)/(n - k), with the
+/// 5% band ±c_0 from the asymptotic approximation c_0 = 1.3581/√(m/2 - 1), m = n - k,
+/// to Durbin's (1969) table
+pub fn cusum_of_squares(columns: &[Vec<f64>], y: &[f64]) -> Option<CusumResult> {
+    let n = y.len();
+    let (first, w, _) = recursive_residuals(columns, y, 0, n)?;
+    let m = w.len();
+    if m < 4 {
+        return None;
+    }
+    let total: f64 = w.iter().map(|v| v * v).sum();
+    let c0 = CUSUMSQ_KS_5 / (m as f64 / 2.0 - 1.0).sqrt();
+    let mut running = 0.0;
+    let mut statistic = Vec::with_capacity(m);
+    let mut lower = Vec::with_capacity(m);
+    let mut upper = Vec::with_capacity(m);
+    for (i, v) in w.iter().enumerate() {
+        running += v * v;
+        statistic.push(running / total);
+        let expected = (i + 1) as f64 / m as f64;
+        lower.push(expected - c0);
+        upper.push(expected + c0);
+    }
+    Some(CusumResult::new((first..n).collect(), statistic, lower, upper))
+}
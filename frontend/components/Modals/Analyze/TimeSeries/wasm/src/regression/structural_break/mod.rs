@@ -0,0 +1,4 @@
+pub mod recursive_residuals;
+pub mod chow_test;
+pub mod cusum_test;
+pub mod bai_perron;
\ No newline at end of file
@@ -0,0 +1,46 @@
+use crate::{invert_matrix, multiply_matrix, transpose};
+
+/// Recursive residuals w_r = (y_r - x_r'b_{r-1}) / √(1 + x_r'(X'_{r-1}X_{r-1})⁻¹x_r) of the
+/// regression on rows start..end, by recursive least squares. The first rows up to the
+/// point where X'X is invertible (at least k) initialise the recursion.
+/// Returns the first row with a residual, the residuals from that row on, and the SSR of
+/// the initial rows.
+pub fn recursive_residuals(columns: &[Vec<f64>], y: &[f64], start: usize, end: usize) -> Option<(usize, Vec<f64>, f64)> {
+    let k = columns.len();
+    let row = |t: usize| -> Vec<f64> { columns.iter().map(|c| c[t]).collect() };
+
+    // Smallest initial sample with an invertible X'X
+    let mut init = start + k;
+    let (mut p, mut b, init_ssr) = loop {
+        if init > end {
+            return None;
+        }
+        let x: Vec<Vec<f64>> = columns.iter().map(|c| c[start..init].to_vec()).collect();
+        let xt = transpose(&x);
+        if let Some(p) = invert_matrix(&multiply_matrix(&xt, &x)) {
+            let xty: Vec<f64> = x.iter().map(|c| c.iter().zip(y[start..init].iter()).map(|(a, v)| a * v).sum()).collect();
+            let b: Vec<f64> = (0..k).map(|i| (0..k).map(|j| p[i][j] * xty[j]).sum()).collect();
+            let ssr = (start..init)
+                .map(|t| (y[t] - row(t).iter().zip(b.iter()).map(|(a, c)| a * c).sum::<f64>()).powi(2))
+                .sum();
+            break (p, b, ssr);
+        }
+        init += 1;
+    };
+
+    let mut residuals = Vec::with_capacity(end - init);
+    for (t, y_t) in y.iter().enumerate().take(end).skip(init) {
+        let x = row(t);
+        let px: Vec<f64> = (0..k).map(|i| (0..k).map(|j| p[i][j] * x[j]).sum()).collect();
+        let f = 1.0 + x.iter().zip(px.iter()).map(|(a, c)| a * c).sum::<f64>();
+        let error = y_t - x.iter().zip(b.iter()).map(|(a, c)| a * c).sum::<f64>();
+        residuals.push(error / f.sqrt());
+        for i in 0..k {
+            b[i] += px[i] / f * error;
+            for j in 0..k {
+                p[i][j] -= px[i] * px[j] / f;
+            }
+        }
+    }
+    Some((init, residuals, init_ssr))
+}
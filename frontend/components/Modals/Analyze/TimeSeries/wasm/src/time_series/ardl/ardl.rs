@@ -7,11 +7,20 @@ pub struct ARDL {
     pub(crate) n_obs: usize,      // Number of observations per variable
     pub(crate) p: usize,          // AR order for Y
     pub(crate) q: Vec<usize>,     // DL orders for each X (stored as Vec but passed as flat)
+    pub(crate) case: usize,       // PSS deterministic case 1-5 (default 3: unrestricted intercept)
     
     pub(crate) coefficients: Vec<f64>,
+    pub(crate) std_errors: Vec<f64>,
     pub(crate) long_run_coef: Vec<f64>,
+    pub(crate) long_run_se: Vec<f64>,
     pub(crate) bounds_f_stat: f64,
+    pub(crate) bounds_t_stat: f64,
     pub(crate) r_squared: f64,
+    pub(crate) ecm_coefficients: Vec<f64>,
+    pub(crate) ecm_std_errors: Vec<f64>,
+    pub(crate) speed_of_adjustment: f64,
+    pub(crate) speed_of_adjustment_se: f64,
+    pub(crate) lag_candidates: Vec<f64>, // Rows [p, q_1..q_k, AIC, BIC, adj. R²], best first
 }
 #[wasm_bindgen]
 impl ARDL {
@@ -47,10 +56,19 @@ impl ARDL {
             n_obs,
             p,
             q: q_flat,
+            case: 3,
             coefficients: Vec::new(),
+            std_errors: Vec::new(),
             long_run_coef: Vec::new(),
-            bounds_f_stat: 0.0,
+            long_run_se: Vec::new(),
+            bounds_f_stat: f64::NAN,
+            bounds_t_stat: f64::NAN,
             r_squared: 0.0,
+            ecm_coefficients: Vec::new(),
+            ecm_std_errors: Vec::new(),
+            speed_of_adjustment: 0.0,
+            speed_of_adjustment_se: 0.0,
+            lag_candidates: Vec::new(),
         })
     }
     
@@ -64,9 +82,31 @@ impl ARDL {
     
     // Getters
     pub fn get_coefficients(&self) -> Vec<f64> { self.coefficients.clone() }
+    pub fn get_std_errors(&self) -> Vec<f64> { self.std_errors.clone() }
     pub fn get_long_run_coef(&self) -> Vec<f64> { self.long_run_coef.clone() }
+    pub fn get_long_run_se(&self) -> Vec<f64> { self.long_run_se.clone() }
     pub fn get_bounds_f_stat(&self) -> f64 { self.bounds_f_stat }
+    pub fn get_bounds_t_stat(&self) -> f64 { self.bounds_t_stat }
     pub fn get_r_squared(&self) -> f64 { self.r_squared }
+    pub fn get_ecm_coefficients(&self) -> Vec<f64> { self.ecm_coefficients.clone() }
+    pub fn get_ecm_std_errors(&self) -> Vec<f64> { self.ecm_std_errors.clone() }
+    pub fn get_speed_of_adjustment(&self) -> f64 { self.speed_of_adjustment }
+    pub fn get_speed_of_adjustment_se(&self) -> f64 { self.speed_of_adjustment_se }
+    pub fn get_lag_candidates(&self) -> Vec<f64> { self.lag_candidates.clone() }
     pub fn get_n_vars(&self) -> usize { self.n_vars }
     pub fn get_n_obs(&self) -> usize { self.n_obs }
+    pub fn get_p(&self) -> usize { self.p }
+    pub fn get_q(&self) -> Vec<usize> { self.q.clone() }
+    pub fn get_case(&self) -> usize { self.case }
+
+    // Setters
+    /// PSS case: 1 no intercept, 2 restricted intercept, 3 unrestricted intercept,
+    /// 4 unrestricted intercept and restricted trend, 5 unrestricted intercept and trend
+    pub fn set_case(&mut self, case: usize) {
+        if !(1..=5).contains(&case) {
+            panic!("Unknown case: {}", case);
+        }
+        self.case = case;
+        self.ecm_coefficients = Vec::new();
+    }
 }
\ No newline at end of file
@@ -1,24 +1,31 @@
 use wasm_bindgen::prelude::*;
 use crate::ARDL;
 use crate::time_series::helper_structs::{BoundsTestResult, CriticalValues};
+use crate::time_series::ardl::pss_critical_values::pss_f_bounds;
 
 #[wasm_bindgen]
 impl ARDL {
     /// Pesaran ARDL Bounds Test for Cointegration
     /// F-statistic untuk test: H0: No long-run relationship
-    pub fn calculate_bounds_test(&self, unrestricted_ssr: f64, restricted_ssr: f64, n_obs: usize) -> BoundsTestResult {
+    /// Errors when the PSS table has no bounds for the case and k (k = 1..10)
+    pub fn calculate_bounds_test(&self, unrestricted_ssr: f64, restricted_ssr: f64, n_obs: usize) -> Result<BoundsTestResult, JsValue> {
         let k = self.n_vars; // Use struct field instead of x.len()
+        if n_obs <= k + 1 {
+            return Err(JsValue::from_str("Too few observations for the bounds test"));
+        }
         
         // F-statistic = ((SSR_r - SSR_u) / k) / (SSR_u / (n - k - 1))
         let numerator = (restricted_ssr - unrestricted_ssr) / k as f64;
         let denominator = unrestricted_ssr / (n_obs - k - 1) as f64;
         let f_stat = numerator / denominator;
         
-        // Critical values dari Pesaran et al (2001) Table CI(iii) Case III
-        // Simplified: untuk k=2, n=100, α=5%
-        // I(0) bound = 3.79, I(1) bound = 4.85
-        let i0_bound = 3.79;
-        let i1_bound = 4.85;
+        // Critical values dari Pesaran et al (2001) Table CI, α=5%
+        let CriticalValues { i0_bound, i1_bound } = self.get_critical_values(0.05);
+        if i0_bound.is_nan() || i1_bound.is_nan() {
+            return Err(JsValue::from_str(&format!(
+                "No PSS critical values for case {} with k = {} regressors (tabulated for k = 1..10)", self.case, k
+            )));
+        }
         
         // Decision
         let has_cointegration = if f_stat > i1_bound {
@@ -29,30 +36,26 @@ impl ARDL {
             false // Inconclusive
         };
         
-        BoundsTestResult {
+        Ok(BoundsTestResult {
             f_statistic: f_stat,
             has_cointegration,
-        }
+        })
     }
 }
 
 // Internal helper methods (not exposed to WASM)
 impl ARDL {
     /// Get critical values untuk bounds test (internal use only)
+    /// NaN bounds for alpha outside 0.10, 0.05, 0.025 and 0.01 or k outside the table
     pub fn get_critical_values(&self, alpha: f64) -> CriticalValues {
-        // Simplified critical values (untuk k=1-5, n=100)
-        // Seharusnya lookup table berdasarkan k dan n
-        let k = self.n_vars;
-        
-        let (i0, i1) = match (k, (alpha * 100.0) as usize) {
-            (1, 10) => (2.72, 3.77),  // k=1, α=10%
-            (1, 5) => (3.23, 4.35),   // k=1, α=5%
-            (2, 10) => (3.17, 4.14),  // k=2, α=10%
-            (2, 5) => (3.79, 4.85),   // k=2, α=5%
-            (3, 10) => (3.47, 4.45),  // k=3, α=10%
-            (3, 5) => (4.23, 5.23),   // k=3, α=5%
-            _ => (3.79, 4.85)         // Default
+        let level = match (alpha * 1000.0).round() as i64 {
+            100 => "10%",
+            50 => "5%",
+            25 => "2.5%",
+            10 => "1%",
+            _ => return CriticalValues { i0_bound: f64::NAN, i1_bound: f64::NAN },
         };
+        let [i0, i1] = pss_f_bounds(self.case, self.n_vars, level);
         
         CriticalValues {
             i0_bound: i0,
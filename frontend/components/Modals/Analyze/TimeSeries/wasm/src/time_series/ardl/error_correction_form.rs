@@ -0,0 +1,137 @@
+use wasm_bindgen::prelude::*;
+use crate::ARDL;
+use crate::time_series::ardl::pss_critical_values::{pss_f_bounds, pss_t_bounds};
+use crate::time_series::ecm::ols_helper::ols;
+
+#[wasm_bindgen]
+impl ARDL {
+    /// Conditional ECM of the current ARDL(p, q) order under the PSS case:
+    /// Δy_t = det + π_y·y_{t-1} + Σπ_j·x_{j,t-1} + Σ_{i=1}^{p-1} γ_i·Δy_{t-i} + Σ_j Σ_{i=0}^{q_j-1} δ_{j,i}·Δx_{j,t-i} + u_t
+    /// (Δx_{j,t} is kept when q_j = 0). Coefficients are ordered [constant (cases 2-5),
+    /// trend (cases 4-5), π_y, π_1..π_k, γ, δ]. π_y is the speed of adjustment; the bounds
+    /// F test restricts the level terms (and the restricted intercept or trend), the
+    /// bounds t test is π_y / se(π_y) for cases 1, 3 and 5.
+    pub fn estimate_ecm(&mut self) -> Result<(), JsValue> {
+        let n = self.y.len();
+        let k = self.n_vars;
+        let start = self.p.max(*self.q.iter().max().unwrap_or(&0)).max(1);
+        if n <= start {
+            return Err(JsValue::from_str("Too few observations for the ARDL orders"));
+        }
+        let dy = |t: usize| self.y[t] - self.y[t - 1];
+        let dx = |j: usize, t: usize| self.get_x(j, t) - self.get_x(j, t - 1);
+
+        let mut deterministic: Vec<Vec<f64>> = Vec::new();
+        if self.case >= 2 {
+            deterministic.push(vec![1.0; n - start]);
+        }
+        if self.case >= 4 {
+            deterministic.push((start..n).map(|t| (t + 1) as f64).collect());
+        }
+        let mut levels: Vec<Vec<f64>> = vec![(start..n).map(|t| self.y[t - 1]).collect()];
+        for j in 0..k {
+            levels.push((start..n).map(|t| self.get_x(j, t - 1)).collect());
+        }
+        let mut short_run: Vec<Vec<f64>> = Vec::new();
+        for i in 1..self.p {
+            short_run.push((start..n).map(|t| dy(t - i)).collect());
+        }
+        for (j, &q_j) in self.q.iter().enumerate() {
+            for i in 0..q_j.max(1) {
+                short_run.push((start..n).map(|t| dx(j, t - i)).collect());
+            }
+        }
+        let target: Vec<f64> = (start..n).map(dy).collect();
+
+        let n_det = deterministic.len();
+        let mut columns = deterministic.clone();
+        columns.extend(levels.iter().cloned());
+        columns.extend(short_run.iter().cloned());
+        let n_eff = target.len();
+        if n_eff <= columns.len() {
+            return Err(JsValue::from_str("Too few observations for the error-correction form"));
+        }
+        let fit = ols(&columns, &target)
+            .ok_or_else(|| JsValue::from_str("Singular regressor matrix in the error-correction form"))?;
+
+        // Restricted model: no level terms, and no restricted intercept (case 2) or trend (case 4)
+        let mut restricted: Vec<Vec<f64>> = match self.case {
+            2 => Vec::new(),
+            4 => deterministic[..1].to_vec(),
+            _ => deterministic.clone(),
+        };
+        let n_restrictions = (k + 1 + n_det - restricted.len()) as f64;
+        restricted.extend(short_run);
+        let restricted_ssr = if restricted.is_empty() {
+            target.iter().map(|v| v * v).sum()
+        } else {
+            ols(&restricted, &target)
+                .ok_or_else(|| JsValue::from_str("Singular regressor matrix in the restricted model"))?
+                .ssr
+        };
+        let df = (n_eff - columns.len()) as f64;
+        self.bounds_f_stat = ((restricted_ssr - fit.ssr) / n_restrictions) / (fit.ssr / df);
+
+        let pi_y = fit.beta[n_det];
+        self.speed_of_adjustment = pi_y;
+        self.speed_of_adjustment_se = fit.se[n_det];
+        self.bounds_t_stat = match self.case {
+            1 | 3 | 5 => pi_y / fit.se[n_det],
+            _ => f64::NAN,
+        };
+
+        // θ_j = -π_j / π_y with delta-method standard errors
+        self.long_run_coef = Vec::with_capacity(k);
+        self.long_run_se = Vec::with_capacity(k);
+        for j in 0..k {
+            let idx = n_det + 1 + j;
+            let pi_j = fit.beta[idx];
+            let grad = [pi_j / pi_y.powi(2), -1.0 / pi_y];
+            let cov = |a: usize, b: usize| fit.sigma2 * fit.xtx_inv[a][b];
+            let var = grad[0] * grad[0] * cov(n_det, n_det)
+                + 2.0 * grad[0] * grad[1] * cov(n_det, idx)
+                + grad[1] * grad[1] * cov(idx, idx);
+            self.long_run_coef.push(-pi_j / pi_y);
+            self.long_run_se.push(var.max(0.0).sqrt());
+        }
+        self.ecm_coefficients = fit.beta;
+        self.ecm_std_errors = fit.se;
+        Ok(())
+    }
+
+    /// PSS [I(0), I(1)] bounds of the F statistic at "10%", "5%", "2.5%" or "1%"
+    pub fn get_f_bounds(&self, level: String) -> Vec<f64> {
+        pss_f_bounds(self.case, self.n_vars, &level).to_vec()
+    }
+
+    /// PSS [I(0), I(1)] bounds of the t statistic (NaN for cases 2 and 4)
+    pub fn get_t_bounds(&self, level: String) -> Vec<f64> {
+        pss_t_bounds(self.case, self.n_vars, &level).to_vec()
+    }
+
+    /// Bounds-test conclusion after estimate_ecm: "cointegration" when F exceeds its I(1)
+    /// bound and (where available) t lies below its I(1) bound, "no cointegration" when
+    /// F is below its I(0) bound, "inconclusive" otherwise. Errors before estimate_ecm and
+    /// when the PSS table has no F bounds for the case, k and level.
+    pub fn bounds_conclusion(&self, level: String) -> Result<String, JsValue> {
+        if self.ecm_coefficients.is_empty() {
+            return Err(JsValue::from_str("Error-correction form not estimated, call estimate_ecm first"));
+        }
+        let f_bounds = pss_f_bounds(self.case, self.n_vars, &level);
+        if f_bounds.iter().any(|b| b.is_nan()) {
+            return Err(JsValue::from_str(&format!(
+                "No PSS critical values for case {} with k = {} regressors at level {}", self.case, self.n_vars, level
+            )));
+        }
+        let t_bounds = pss_t_bounds(self.case, self.n_vars, &level);
+        let t_rejects = t_bounds[1].is_nan() || self.bounds_t_stat < t_bounds[1];
+        let conclusion = if self.bounds_f_stat > f_bounds[1] && t_rejects {
+            "cointegration"
+        } else if self.bounds_f_stat < f_bounds[0] {
+            "no cointegration"
+        } else {
+            "inconclusive"
+        };
+        Ok(conclusion.to_string())
+    }
+}
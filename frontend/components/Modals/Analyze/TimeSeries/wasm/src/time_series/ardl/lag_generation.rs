@@ -4,45 +4,45 @@ use crate::ARDL;
 // Note: generate_lags is NOT #[wasm_bindgen] because it returns tuple (internal use only)
 impl ARDL {
     /// Generate lagged variables untuk ARDL regression (internal function)
-    pub fn generate_lags(&self) -> (Vec<Vec<f64>>, Vec<f64>) {
-        let n = self.y.len();
+    pub fn generate_lags(&self) -> Result<(Vec<Vec<f64>>, Vec<f64>), JsValue> {
         let max_lag = self.p.max(*self.q.iter().max().unwrap_or(&0));
-        let n_obs = n - max_lag;
-        
+        self.lag_columns(self.p, &self.q, max_lag)
+    }
+
+    /// Regressors of ARDL(p, q_1, .., q_k) on the sample t = start..n:
+    /// [constant (cases 2-5), Y_{t-1}..Y_{t-p}, X_{1,t}..X_{1,t-q_1}, .., trend (cases 4 and 5)]
+    pub fn lag_columns(&self, p: usize, q: &[usize], start: usize) -> Result<(Vec<Vec<f64>>, Vec<f64>), JsValue> {
+        let n = self.y.len();
+        if start >= n {
+            return Err(JsValue::from_str("Too few observations for the ARDL orders"));
+        }
         let mut x_matrix: Vec<Vec<f64>> = Vec::new();
-        
-        // Constant
-        x_matrix.push(vec![1.0; n_obs]);
-        
+
+        // Constant (none in PSS case 1)
+        if self.case >= 2 {
+            x_matrix.push(vec![1.0; n - start]);
+        }
+
         // Lagged Y: Y_{t-1}, ..., Y_{t-p}
-        for lag in 1..=self.p {
-            let mut lagged = Vec::new();
-            for t in max_lag..n {
-                if t >= lag {
-                    lagged.push(self.y[t - lag]);
-                }
-            }
-            x_matrix.push(lagged);
+        for lag in 1..=p {
+            x_matrix.push((start..n).map(|t| self.y[t - lag]).collect());
         }
-        
+
         // Lagged X variables (using flattened array)
-        for var_idx in 0..self.n_vars {
-            let q_j = self.q[var_idx];
-            
+        for (var_idx, &q_j) in q.iter().enumerate() {
             for lag in 0..=q_j {
-                let mut lagged = Vec::new();
-                for t in max_lag..n {
-                    if t >= lag {
-                        lagged.push(self.get_x(var_idx, t - lag));
-                    }
-                }
-                x_matrix.push(lagged);
+                x_matrix.push((start..n).map(|t| self.get_x(var_idx, t - lag)).collect());
             }
         }
-        
+
+        // Trend last, so the layout read by calculate_long_run_coefficients is unchanged
+        if self.case >= 4 {
+            x_matrix.push((start..n).map(|t| (t + 1) as f64).collect());
+        }
+
         // Y vector
-        let y_vec = self.y[max_lag..].to_vec();
-        
-        (x_matrix, y_vec)
+        let y_vec = self.y[start..].to_vec();
+
+        Ok((x_matrix, y_vec))
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,127 @@
+use wasm_bindgen::prelude::*;
+use crate::ARDL;
+use crate::time_series::ecm::ols_helper::ols;
+
+// Largest number of (p, q_1, .., q_k) combinations the grid search will fit
+const MAX_GRID_SIZE: usize = 100_000;
+
+// Information criteria of one candidate order
+struct LagCandidate {
+    orders: Vec<usize>, // [p, q_1, .., q_k]
+    aic: f64,
+    bic: f64,
+    adj_r_squared: f64,
+}
+
+#[wasm_bindgen]
+impl ARDL {
+    /// OLS estimates of the ARDL(p, q_1, .., q_k) levels equation, with R² and the
+    /// long-run coefficients θ_j = Σβ_{j,i} / (1 - Σα_i)
+    pub fn estimate(&mut self) -> Result<(), JsValue> {
+        let (columns, y) = self.generate_lags()?;
+        if y.len() <= columns.len() {
+            return Err(JsValue::from_str("Too few observations for the ARDL orders"));
+        }
+        let fit = ols(&columns, &y)
+            .ok_or_else(|| JsValue::from_str("Singular regressor matrix in ARDL estimation"))?;
+        let mean = y.iter().sum::<f64>() / y.len() as f64;
+        let tss: f64 = y.iter().map(|v| (v - mean).powi(2)).sum();
+        self.r_squared = 1.0 - fit.ssr / tss;
+        self.long_run_coef = self.calculate_long_run_coefficients(fit.beta.clone());
+        self.coefficients = fit.beta;
+        self.std_errors = fit.se;
+        Ok(())
+    }
+
+    /// Grid search over p = 1..max_p and q_j = 0..max_q on the common sample that starts
+    /// at max(max_p, max_q). The best order by "aic", "bic" (smallest) or "adj_r2"
+    /// (largest) replaces p and q and is returned as [p, q_1, .., q_k]; every candidate is
+    /// kept in get_lag_candidates, best first.
+    pub fn select_orders(&mut self, max_p: usize, max_q: usize, criterion: String) -> Result<Vec<usize>, JsValue> {
+        if max_p == 0 {
+            return Err(JsValue::from_str("max_p must be at least 1"));
+        }
+        let grid_size = (max_q + 1)
+            .checked_pow(self.n_vars as u32)
+            .and_then(|size| size.checked_mul(max_p))
+            .unwrap_or(usize::MAX);
+        if grid_size > MAX_GRID_SIZE {
+            return Err(JsValue::from_str(&format!(
+                "Lag grid too large: {} combinations (limit {})", grid_size, MAX_GRID_SIZE
+            )));
+        }
+
+        let start = max_p.max(max_q);
+        if start >= self.y.len() {
+            return Err(JsValue::from_str("Too few observations for the maximum lag orders"));
+        }
+        let mut candidates = Vec::with_capacity(grid_size);
+        for p in 1..=max_p {
+            let mut q = vec![0; self.n_vars];
+            loop {
+                if let Some(candidate) = self.lag_candidate(p, &q, start) {
+                    candidates.push(candidate);
+                }
+                // Next q combination, odometer style
+                let mut j = 0;
+                while j < q.len() && q[j] == max_q {
+                    q[j] = 0;
+                    j += 1;
+                }
+                if j == q.len() {
+                    break;
+                }
+                q[j] += 1;
+            }
+        }
+        if candidates.is_empty() {
+            return Err(JsValue::from_str("No ARDL order could be estimated on the common sample"));
+        }
+
+        let score = |c: &LagCandidate| match criterion.as_str() {
+            "aic" => c.aic,
+            "bic" => c.bic,
+            "adj_r2" => -c.adj_r_squared,
+            _ => panic!("Unknown criterion: {}", criterion),
+        };
+        candidates.sort_by(|a, b| score(a).total_cmp(&score(b)));
+
+        self.lag_candidates = candidates
+            .iter()
+            .flat_map(|c| {
+                let mut row: Vec<f64> = c.orders.iter().map(|&o| o as f64).collect();
+                row.extend([c.aic, c.bic, c.adj_r_squared]);
+                row
+            })
+            .collect();
+        let best = candidates.swap_remove(0).orders;
+        self.p = best[0];
+        self.q = best[1..].to_vec();
+        self.ecm_coefficients = Vec::new();
+        Ok(best)
+    }
+}
+
+// Internal helpers (not exposed to WASM)
+impl ARDL {
+    fn lag_candidate(&self, p: usize, q: &[usize], start: usize) -> Option<LagCandidate> {
+        let (columns, y) = self.lag_columns(p, q, start).ok()?;
+        let n = y.len() as f64;
+        let k = columns.len() as f64;
+        if n <= k + 1.0 {
+            return None;
+        }
+        let fit = ols(&columns, &y)?;
+        let mean = y.iter().sum::<f64>() / n;
+        let tss: f64 = y.iter().map(|v| (v - mean).powi(2)).sum();
+        let log_sigma2 = (fit.ssr / n).ln();
+        let mut orders = vec![p];
+        orders.extend_from_slice(q);
+        Some(LagCandidate {
+            orders,
+            aic: n * log_sigma2 + 2.0 * k,
+            bic: n * log_sigma2 + k * n.ln(),
+            adj_r_squared: 1.0 - (fit.ssr / (n - k)) / (tss / (n - 1.0)),
+        })
+    }
+}
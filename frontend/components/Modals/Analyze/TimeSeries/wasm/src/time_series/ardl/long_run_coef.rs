@@ -10,8 +10,9 @@ impl ARDL {
         
         // Extract coefficients from short_run_coef
         // Structure: [constant, α_1, ..., α_p, β_{1,0}, β_{1,1}, ..., β_{1,q1}, β_{2,0}, ...]
+        // (no constant in PSS case 1)
         
-        let mut idx = 1; // Skip constant
+        let mut idx = if self.case >= 2 { 1 } else { 0 }; // Skip constant
         
         // Sum of AR coefficients: Σα_i
         let mut sum_ar = 0.0;
@@ -53,7 +54,7 @@ impl ARDL {
     pub fn calculate_long_run_se(&self, short_run_se: Vec<f64>) -> Vec<f64> {
         let mut lr_se = Vec::new();
         
-        let mut idx = 1;
+        let mut idx = if self.case >= 2 { 1 } else { 0 };
         let mut sum_ar = 0.0;
         for _ in 0..self.p {
             if idx < short_run_se.len() {
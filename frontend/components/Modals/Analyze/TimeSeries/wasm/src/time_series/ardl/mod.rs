@@ -1,4 +1,7 @@
 pub mod ardl;
 pub mod lag_generation;
 pub mod bounds_test;
-pub mod long_run_coef;
\ No newline at end of file
+pub mod long_run_coef;
+pub mod lag_selection;
+pub mod error_correction_form;
+pub mod pss_critical_values;
\ No newline at end of file
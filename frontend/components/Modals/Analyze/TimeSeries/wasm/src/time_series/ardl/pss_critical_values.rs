@@ -0,0 +1,152 @@
+// Pesaran, Shin and Smith (2001) asymptotic bounds for k = 1..10 regressors,
+// [I(0), I(1)] at 10%, 5%, 2.5% and 1%
+
+// Table CI: F statistic, cases I-V
+const F_BOUNDS: [[[[f64; 2]; 4]; 10]; 5] = [
+    // Case I: no intercept, no trend
+    [
+        [[2.44, 3.28], [3.15, 4.11], [3.88, 4.92], [4.81, 6.02]],
+        [[2.17, 3.19], [2.72, 3.83], [3.22, 4.50], [3.88, 5.30]],
+        [[2.01, 3.10], [2.45, 3.63], [2.87, 4.16], [3.42, 4.84]],
+        [[1.90, 3.01], [2.26, 3.48], [2.62, 3.90], [3.07, 4.44]],
+        [[1.81, 2.93], [2.14, 3.34], [2.44, 3.71], [2.82, 4.21]],
+        [[1.75, 2.87], [2.04, 3.24], [2.32, 3.59], [2.66, 4.05]],
+        [[1.70, 2.83], [1.97, 3.18], [2.22, 3.49], [2.54, 3.91]],
+        [[1.66, 2.79], [1.91, 3.11], [2.15, 3.40], [2.45, 3.79]],
+        [[1.63, 2.75], [1.86, 3.05], [2.08, 3.33], [2.34, 3.68]],
+        [[1.60, 2.72], [1.82, 2.99], [2.02, 3.27], [2.26, 3.60]],
+    ],
+    // Case II: restricted intercept, no trend
+    [
+        [[3.02, 3.51], [3.62, 4.16], [4.18, 4.79], [4.94, 5.58]],
+        [[2.63, 3.35], [3.10, 3.87], [3.55, 4.38], [4.13, 5.00]],
+        [[2.37, 3.20], [2.79, 3.67], [3.15, 4.08], [3.65, 4.66]],
+        [[2.20, 3.09], [2.56, 3.49], [2.88, 3.87], [3.29, 4.37]],
+        [[2.08, 3.00], [2.39, 3.38], [2.70, 3.73], [3.06, 4.15]],
+        [[1.99, 2.94], [2.27, 3.28], [2.55, 3.61], [2.88, 3.99]],
+        [[1.92, 2.89], [2.17, 3.21], [2.43, 3.51], [2.73, 3.90]],
+        [[1.85, 2.85], [2.11, 3.15], [2.33, 3.42], [2.62, 3.77]],
+        [[1.80, 2.80], [2.04, 3.08], [2.24, 3.35], [2.50, 3.68]],
+        [[1.76, 2.77], [1.98, 3.04], [2.18, 3.28], [2.41, 3.61]],
+    ],
+    // Case III: unrestricted intercept, no trend
+    [
+        [[4.04, 4.78], [4.94, 5.73], [5.77, 6.68], [6.84, 7.84]],
+        [[3.17, 4.14], [3.79, 4.85], [4.41, 5.52], [5.15, 6.36]],
+        [[2.72, 3.77], [3.23, 4.35], [3.69, 4.89], [4.29, 5.61]],
+        [[2.45, 3.52], [2.86, 4.01], [3.25, 4.49], [3.74, 5.06]],
+        [[2.26, 3.35], [2.62, 3.79], [2.96, 4.18], [3.41, 4.68]],
+        [[2.12, 3.23], [2.45, 3.61], [2.75, 3.99], [3.15, 4.43]],
+        [[2.03, 3.13], [2.32, 3.50], [2.60, 3.84], [2.96, 4.26]],
+        [[1.95, 3.06], [2.22, 3.39], [2.48, 3.70], [2.79, 4.10]],
+        [[1.88, 2.99], [2.14, 3.30], [2.37, 3.60], [2.65, 3.97]],
+        [[1.83, 2.94], [2.06, 3.24], [2.28, 3.50], [2.54, 3.86]],
+    ],
+    // Case IV: unrestricted intercept, restricted trend
+    [
+        [[4.05, 4.49], [4.68, 5.15], [5.30, 5.83], [6.10, 6.73]],
+        [[3.38, 4.02], [3.88, 4.61], [4.37, 5.16], [4.99, 5.85]],
+        [[2.97, 3.74], [3.38, 4.23], [3.80, 4.68], [4.30, 5.23]],
+        [[2.68, 3.53], [3.05, 3.97], [3.40, 4.36], [3.81, 4.92]],
+        [[2.49, 3.38], [2.81, 3.76], [3.11, 4.13], [3.50, 4.63]],
+        [[2.33, 3.25], [2.63, 3.62], [2.90, 3.94], [3.27, 4.39]],
+        [[2.22, 3.17], [2.50, 3.50], [2.76, 3.81], [3.07, 4.23]],
+        [[2.13, 3.09], [2.38, 3.41], [2.62, 3.70], [2.93, 4.06]],
+        [[2.05, 3.02], [2.30, 3.33], [2.52, 3.60], [2.79, 3.93]],
+        [[1.98, 2.97], [2.21, 3.25], [2.42, 3.52], [2.68, 3.84]],
+    ],
+    // Case V: unrestricted intercept, unrestricted trend
+    [
+        [[5.59, 6.26], [6.56, 7.30], [7.46, 8.27], [8.74, 9.63]],
+        [[4.19, 5.06], [4.87, 5.85], [5.49, 6.59], [6.34, 7.52]],
+        [[3.47, 4.45], [4.01, 5.07], [4.52, 5.62], [5.17, 6.36]],
+        [[3.03, 4.06], [3.47, 4.57], [3.89, 5.07], [4.40, 5.72]],
+        [[2.75, 3.79], [3.12, 4.25], [3.47, 4.67], [3.93, 5.23]],
+        [[2.53, 3.59], [2.87, 4.00], [3.19, 4.38], [3.60, 4.90]],
+        [[2.38, 3.45], [2.69, 3.83], [2.98, 4.16], [3.34, 4.63]],
+        [[2.26, 3.34], [2.55, 3.68], [2.82, 4.02], [3.15, 4.43]],
+        [[2.16, 3.24], [2.43, 3.56], [2.67, 3.87], [2.97, 4.24]],
+        [[2.07, 3.16], [2.33, 3.46], [2.56, 3.76], [2.84, 4.10]],
+    ],
+];
+
+// Table CII: t statistic on y_{t-1}, cases I, III and V (the I(0) bound is the
+// Dickey-Fuller value and does not depend on k)
+const T_LOWER: [[f64; 4]; 3] = [
+    [-1.62, -1.95, -2.24, -2.58],
+    [-2.57, -2.86, -3.13, -3.43],
+    [-3.13, -3.41, -3.65, -3.96],
+];
+const T_UPPER: [[[f64; 4]; 10]; 3] = [
+    [
+        [-2.28, -2.60, -2.90, -3.22],
+        [-2.68, -3.02, -3.31, -3.66],
+        [-3.00, -3.33, -3.64, -3.97],
+        [-3.26, -3.60, -3.89, -4.23],
+        [-3.49, -3.83, -4.12, -4.44],
+        [-3.70, -4.04, -4.34, -4.67],
+        [-3.90, -4.23, -4.54, -4.88],
+        [-4.09, -4.43, -4.72, -5.07],
+        [-4.26, -4.61, -4.89, -5.25],
+        [-4.40, -4.76, -5.04, -5.39],
+    ],
+    [
+        [-2.91, -3.22, -3.50, -3.82],
+        [-3.21, -3.53, -3.80, -4.10],
+        [-3.46, -3.78, -4.05, -4.37],
+        [-3.66, -3.99, -4.26, -4.60],
+        [-3.86, -4.19, -4.46, -4.79],
+        [-4.04, -4.38, -4.66, -4.99],
+        [-4.23, -4.57, -4.85, -5.19],
+        [-4.40, -4.72, -5.02, -5.37],
+        [-4.56, -4.88, -5.18, -5.54],
+        [-4.69, -5.03, -5.34, -5.68],
+    ],
+    [
+        [-3.40, -3.69, -3.94, -4.26],
+        [-3.63, -3.95, -4.20, -4.53],
+        [-3.84, -4.16, -4.42, -4.73],
+        [-4.04, -4.36, -4.62, -4.96],
+        [-4.21, -4.52, -4.79, -5.13],
+        [-4.37, -4.69, -4.96, -5.31],
+        [-4.53, -4.85, -5.14, -5.49],
+        [-4.68, -5.01, -5.30, -5.65],
+        [-4.82, -5.15, -5.44, -5.79],
+        [-4.96, -5.29, -5.59, -5.94],
+    ],
+];
+
+fn level_index(level: &str) -> usize {
+    match level {
+        "10%" => 0,
+        "5%" => 1,
+        "2.5%" => 2,
+        "1%" => 3,
+        _ => panic!("Unknown significance level: {}", level),
+    }
+}
+
+/// [I(0), I(1)] bounds of the F statistic for case 1-5 and k regressors at
+/// "10%", "5%", "2.5%" or "1%"; NaN outside k = 1..10
+pub fn pss_f_bounds(case: usize, k: usize, level: &str) -> [f64; 2] {
+    if !(1..=5).contains(&case) || !(1..=10).contains(&k) {
+        return [f64::NAN; 2];
+    }
+    F_BOUNDS[case - 1][k - 1][level_index(level)]
+}
+
+/// [I(0), I(1)] bounds of the t statistic for cases 1, 3 and 5; NaN for the cases with
+/// restricted deterministic terms and outside k = 1..10
+pub fn pss_t_bounds(case: usize, k: usize, level: &str) -> [f64; 2] {
+    let row = match case {
+        1 => 0,
+        3 => 1,
+        5 => 2,
+        _ => return [f64::NAN; 2],
+    };
+    if !(1..=10).contains(&k) {
+        return [f64::NAN; 2];
+    }
+    let column = level_index(level);
+    [T_LOWER[row][column], T_UPPER[row][k - 1][column]]
+}
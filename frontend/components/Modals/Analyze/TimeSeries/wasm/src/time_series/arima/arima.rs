@@ -1,4 +1,5 @@
 use wasm_bindgen::prelude::*;
+use crate::SarimaOrder;
 
 #[wasm_bindgen]
 pub struct Arima {
@@ -6,10 +7,19 @@ pub struct Arima {
     ar_order: i32,
     i_order: i32,
     ma_order: i32,
+    sar_order: i32,         // Seasonal AR order (P)
+    si_order: i32,          // Seasonal differencing order (D)
+    sma_order: i32,         // Seasonal MA order (Q)
+    period: i32,            // Seasonal period (s)
     res_var: f64,
     constant: f64,
     ar_coef: Vec<f64>,
     ma_coef: Vec<f64>,
+    sar_coef: Vec<f64>,
+    sma_coef: Vec<f64>,
+    method: String,         // "css" or "ml"
+    log_likelihood: f64,    // Exact log-likelihood (ml only)
+    coef_cov: Vec<f64>,     // Flattened covariance of [constant, ar, ma, sar, sma] (ml only)
 }
 
 #[wasm_bindgen]
@@ -21,13 +31,45 @@ impl Arima{
             ar_order,
             i_order,
             ma_order,
+            sar_order: 0,
+            si_order: 0,
+            sma_order: 0,
+            period: 0,
             res_var: 0.0,
             constant: 0.0,
             ar_coef: Vec::new(),
             ma_coef: Vec::new(),
+            sar_coef: Vec::new(),
+            sma_coef: Vec::new(),
+            method: "css".to_string(),
+            log_likelihood: f64::NAN,
+            coef_cov: Vec::new(),
         }
     }
 
+    // Seasonal orders (P, D, Q) with period s for ARIMA(p,d,q)(P,D,Q)_s; the period must be
+    // at least 2 when any seasonal order is positive and the series longer than the seasonal lags
+    pub fn set_seasonal_order(&mut self, sar_order: i32, si_order: i32, sma_order: i32, period: i32) -> Result<(), JsValue>{
+        if sar_order < 0 || si_order < 0 || sma_order < 0 {
+            return Err(JsValue::from_str("Seasonal orders must be non-negative"));
+        }
+        let seasonal = sar_order > 0 || si_order > 0 || sma_order > 0;
+        if seasonal && period < 2 {
+            return Err(JsValue::from_str("Seasonal period must be at least 2"));
+        }
+        if seasonal && self.data.len() as i64 <= ((si_order + sar_order.max(sma_order)) as i64) * period as i64 {
+            return Err(JsValue::from_str(&format!(
+                "Too few observations ({}) for the seasonal lags with period {}",
+                self.data.len(), period
+            )));
+        }
+        self.sar_order = sar_order;
+        self.si_order = si_order;
+        self.sma_order = sma_order;
+        self.period = period;
+        Ok(())
+    }
+
     // Getters
     pub fn get_data(&self) -> Vec<f64>{
         self.data.clone()
@@ -41,6 +83,18 @@ impl Arima{
     pub fn get_ma_order(&self) -> i32{
         self.ma_order
     }
+    pub fn get_sar_order(&self) -> i32{
+        self.sar_order
+    }
+    pub fn get_si_order(&self) -> i32{
+        self.si_order
+    }
+    pub fn get_sma_order(&self) -> i32{
+        self.sma_order
+    }
+    pub fn get_period(&self) -> i32{
+        self.period
+    }
     pub fn get_res_var(&self) -> f64{
         self.res_var
     }
@@ -53,6 +107,21 @@ impl Arima{
     pub fn get_ma_coef(&self) -> Vec<f64>{
         self.ma_coef.clone()
     }
+    pub fn get_sar_coef(&self) -> Vec<f64>{
+        self.sar_coef.clone()
+    }
+    pub fn get_sma_coef(&self) -> Vec<f64>{
+        self.sma_coef.clone()
+    }
+    pub fn get_method(&self) -> String{
+        self.method.clone()
+    }
+    pub fn get_log_likelihood(&self) -> f64{
+        self.log_likelihood
+    }
+    pub fn get_coef_cov(&self) -> Vec<f64>{
+        self.coef_cov.clone()
+    }
 
     // Setters
     pub fn set_data(&mut self, data: Vec<f64>){
@@ -70,4 +139,59 @@ impl Arima{
     pub fn set_ma_coef(&mut self, ma_coef: Vec<f64>){
         self.ma_coef = ma_coef;
     }
+    pub fn set_sar_coef(&mut self, sar_coef: Vec<f64>){
+        self.sar_coef = sar_coef;
+    }
+    pub fn set_sma_coef(&mut self, sma_coef: Vec<f64>){
+        self.sma_coef = sma_coef;
+    }
+    pub fn set_method(&mut self, method: String){
+        self.method = method;
+    }
+    pub fn set_log_likelihood(&mut self, log_likelihood: f64){
+        self.log_likelihood = log_likelihood;
+    }
+    pub fn set_coef_cov(&mut self, coef_cov: Vec<f64>){
+        self.coef_cov = coef_cov;
+    }
+}
+
+// Internal helpers (not exposed to WASM)
+impl Arima{
+    pub fn sarima_order(&self) -> SarimaOrder{
+        SarimaOrder{
+            p: self.ar_order as usize,
+            d: self.i_order as usize,
+            q: self.ma_order as usize,
+            sp: self.sar_order as usize,
+            sd: self.si_order as usize,
+            sq: self.sma_order as usize,
+            period: self.period as usize,
+        }
+    }
+
+    // Number of estimated ARMA coefficients (constant excluded)
+    pub fn n_arma_coef(&self) -> i32{
+        self.sarima_order().n_arma() as i32
+    }
+
+    // Observations lost to ordinary and seasonal differencing
+    pub fn n_diff(&self) -> i32{
+        self.sarima_order().n_lost() as i32
+    }
+
+    // Series after ordinary and seasonal differencing
+    pub fn differenced_data(&self) -> Vec<f64>{
+        self.sarima_order().difference(self.get_data())
+    }
+
+    // Coefficient vector [constant, ar, ma, sar, sma]
+    pub fn coef_vector(&self) -> Vec<f64>{
+        let mut coef = vec![self.constant];
+        coef.extend(self.ar_coef.iter());
+        coef.extend(self.ma_coef.iter());
+        coef.extend(self.sar_coef.iter());
+        coef.extend(self.sma_coef.iter());
+        coef
+    }
 }
\ No newline at end of file
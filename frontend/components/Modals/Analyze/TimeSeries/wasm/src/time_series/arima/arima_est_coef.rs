@@ -1,26 +1,29 @@
 use wasm_bindgen::prelude::*;
-use crate::{Arima, est_coef};
+use crate::{Arima, est_coef_sarima, est_coef_ml_sarima};
 
 #[wasm_bindgen]
 impl Arima{
     pub fn estimate_coef(&mut self) -> Vec<f64> {
         let data = self.get_data();
-        let p = self.get_ar_order() as usize;
-        let q = self.get_ma_order() as usize;
-        let d = self.get_i_order() as usize;
+        let order = self.sarima_order();
         // let coef = estimate::fit(&data, p, d, q).unwrap();
-        let coef = est_coef(p, d, q, data.clone()).unwrap();
+        let coef = if self.get_method() == "ml" {
+            let fit = est_coef_ml_sarima(order, data.clone()).unwrap();
+            self.set_res_var(fit.sigma2);
+            self.set_log_likelihood(fit.log_likelihood);
+            self.set_coef_cov(fit.covariance.into_iter().flatten().collect());
+            fit.coef
+        } else {
+            est_coef_sarima(order, data.clone()).unwrap()
+        };
 
-        self.set_constant(coef[0]);
-        if p > 0 && q > 0 {
-            self.set_ar_coef(coef[1..p+1].to_vec());
-            self.set_ma_coef(coef[p+1..].to_vec());
-        } else if p > 0 {
-            self.set_ar_coef(coef[1..].to_vec());
-        }else if q > 0 {
-            self.set_ma_coef(coef[1..].to_vec());
-        }
+        let (constant, ar, ma, sar, sma) = order.split(&coef);
+        self.set_constant(constant);
+        self.set_ar_coef(ar);
+        self.set_ma_coef(ma);
+        self.set_sar_coef(sar);
+        self.set_sma_coef(sma);
 
         coef
     }
-}
\ No newline at end of file
+}
@@ -1,26 +1,20 @@
 use wasm_bindgen::prelude::*;
-use crate::{Arima, first_difference, invert_matrix, autocov_int};
+use crate::{Arima, css_residuals, invert_matrix, autocov_int};
 use nalgebra::DMatrix;
 use finitediff::FiniteDiff;
 
 #[wasm_bindgen]
 impl Arima{
     pub fn intercept_se(&self) -> f64{
-        let mut data = self.get_data();
-        
-        let d = self.get_i_order();
-        if d > 0 {
-            for _ in 0..d{
-                let diff = first_difference(data.clone());
-                data = diff;
-            }
-        } 
+        let data = self.differenced_data();
         let var;
-        if self.get_ar_order() > 0 || self.get_ma_order() > 0{
+        if self.n_arma_coef() > 0{
             let mut acov = Vec::new();
             let mut rho = Vec::new();
             let mut sum = 0.0;
-            for i in 0..=self.get_ar_order() as usize + self.get_ma_order() as usize{
+            let max_lag = (self.get_ar_order() + self.get_ma_order()) as usize
+                + (self.get_sar_order() + self.get_sma_order()) as usize * self.get_period().max(0) as usize;
+            for i in 0..=max_lag.min(data.len() - 1){
                 acov.push(autocov_int(i, &data, self.get_constant()));
                 rho.push(acov[i] / acov[0]);
                 if i > 0 {
@@ -32,44 +26,20 @@ impl Arima{
             let acov = autocov_int(0, &data, self.get_constant());
             var = acov / (data.len() as f64);
         }
-        var.abs().sqrt()
+        var.sqrt()
     }
 
     pub fn coeficient_se(&self) -> Vec<f64>{
-        let mut data = self.get_data();
-        let p = self.get_ar_coef().len();
-        let q = self.get_ma_coef().len();
-        let d = self.get_i_order();
-        if d > 0 {
-            for _ in 0..d{
-                let diff = first_difference(data.clone());
-                data = diff;
-            }
-        }
+        let data = self.differenced_data();
+        let order = self.sarima_order();
         let f = |coef: &Vec<f64>| {
-            let intercept = coef[0];
-            let ar = &coef[1..p+1];
-            let ma = &coef[p+1..];
-            let residuals = self.est_res(intercept, ar.to_vec(), ma.to_vec(), data.clone());
+            let (intercept, ar, ma) = order.full_polynomials(coef);
+            let residuals = css_residuals(intercept, &ar, &ma, &data);
             let css = residuals.iter().map(|x| x.powi(2)).sum::<f64>();
             css
         };
 
-        let mut coef = Vec::new();
-        coef.push(self.get_constant());
-        if p > 0 {
-            let ar = self.get_ar_coef();
-            for i in 0..p{
-                coef.push(ar[i]);
-            };
-        }
-        if q > 0 {
-            let ma = self.get_ma_coef();
-            for i in 0..q{
-                coef.push(ma[i]);
-            };
-        }
-        let coef = coef;
+        let coef = self.coef_vector();
         let hessian: Vec<Vec<f64>> = coef.forward_hessian_nograd(&f);
         let n = hessian.len(); // Ukuran matriks (n x n)
         let flat_hessian: Vec<f64> = hessian.clone().into_iter().flatten().collect();
@@ -83,17 +53,28 @@ impl Arima{
             let var_res = self.res_variance();
             let mut se = Vec::new();
             for i in 1..coef.len(){
-                se.push((2.0 * var_res * inv_hessian[i][i].abs()).sqrt());
+                se.push((2.0 * var_res * inv_hessian[i][i]).sqrt());
             }
             se
         }
     }
 
+    // Standard errors from the inverse Hessian of the exact likelihood; a negative
+    // variance (Hessian not positive definite) gives NaN
+    pub fn ml_se(&self) -> Vec<f64>{
+        let cov = self.get_coef_cov();
+        let k = (cov.len() as f64).sqrt() as usize;
+        (0..k).map(|i| cov[i * k + i].sqrt()).collect()
+    }
+
     pub fn estimate_se(&self) -> Vec<f64>{
+        if self.get_method() == "ml" {
+            return self.ml_se();
+        }
         let mut se = Vec::new();
         let intercept_se = self.intercept_se();
         se.push(intercept_se);
-        if self.get_ar_order() > 0 || self.get_ma_order() > 0{
+        if self.n_arma_coef() > 0{
             let coef_se = self.coeficient_se();
             for coef_se_value in coef_se{
                 se.push(coef_se_value);
@@ -4,6 +4,9 @@ use crate::{Arima, first_difference};
 #[wasm_bindgen]
 impl Arima{
     pub fn forecast(&self) -> Vec<f64> {
+        if self.sarima_order().is_seasonal() {
+            return self.seasonal_fitted();
+        }
         let intercept = self.get_constant();
         let ar = self.get_ar_coef();
         let ma = self.get_ma_coef();
@@ -121,4 +124,18 @@ impl Arima{
         }
         forecast
     }
+
+    // One-step fitted values x_t - e_t for seasonal models; the observations
+    // consumed by differencing are returned unchanged
+    pub fn seasonal_fitted(&self) -> Vec<f64> {
+        let data = self.get_data();
+        let w = self.differenced_data();
+        let residual = self.est_res(self.get_constant(), self.get_ar_coef(), self.get_ma_coef(), w);
+        let offset = self.n_diff() as usize;
+        let mut forecast = data[..offset].to_vec();
+        for i in offset..data.len() {
+            forecast.push(data[i] - residual[i - offset]);
+        }
+        forecast
+    }
 }
\ No newline at end of file
@@ -0,0 +1,171 @@
+use wasm_bindgen::prelude::*;
+use crate::{Arima, first_difference, seasonal_difference, multiply_seasonal};
+use crate::time_series::helper_structs::ForecastResult;
+use statrs::distribution::{ContinuousCDF, Normal};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+/// Coefficients φ*_i of the AR polynomial φ(B)(1-B)^d(1-B^s)^D written as 1 - Σφ*_i·B^i
+pub fn integrated_ar(phi: &[f64], d: usize, seasonal_d: usize, period: usize) -> Vec<f64> {
+    // poly holds the coefficients of 1, B, B^2, ...
+    let mut poly = vec![1.0];
+    for &phi_i in phi.iter() {
+        poly.push(-phi_i);
+    }
+    let lags = std::iter::repeat_n(1, d).chain(std::iter::repeat_n(period, seasonal_d));
+    for lag in lags {
+        let mut next = vec![0.0; poly.len() + lag];
+        for (i, &c) in poly.iter().enumerate() {
+            next[i] += c;
+            next[i + lag] -= c;
+        }
+        poly = next;
+    }
+    poly[1..].iter().map(|c| -c).collect()
+}
+
+/// ψ-weights of x_t = ψ(B)e_t for the model (1 - Σφ_i·B^i)x_t = (1 - Σθ_j·B^j)e_t
+pub fn psi_weights(phi: &[f64], theta: &[f64], h: usize) -> Vec<f64> {
+    let mut psi = vec![1.0];
+    for j in 1..h {
+        let mut value = if j <= theta.len() { -theta[j - 1] } else { 0.0 };
+        for i in 1..=phi.len().min(j) {
+            value += phi[i - 1] * psi[j - i];
+        }
+        psi.push(value);
+    }
+    psi
+}
+
+// Internal helpers (not exposed to WASM)
+impl Arima {
+    /// Differenced series together with, for every differencing step, its lag and
+    /// the last `lag` values of the series it was applied to
+    pub fn differenced_levels(&self) -> (Vec<f64>, Vec<(usize, Vec<f64>)>) {
+        let mut data = self.get_data();
+        let period = self.get_period() as usize;
+        let mut history = Vec::new();
+        for _ in 0..self.get_si_order() {
+            history.push((period, data[data.len() - period..].to_vec()));
+            data = seasonal_difference(data, period as i32);
+        }
+        for _ in 0..self.get_i_order() {
+            history.push((1, data[data.len() - 1..].to_vec()));
+            data = first_difference(data);
+        }
+        (data, history)
+    }
+
+    /// Continue the ARMA recursion of the differenced series with the given future shocks,
+    /// then integrate back through the differences to the original level
+    pub fn simulate_ahead(&self, w: &[f64], residuals: &[f64], history: &[(usize, Vec<f64>)], shocks: &[f64]) -> Vec<f64> {
+        let intercept = self.get_constant();
+        let period = self.get_period() as usize;
+        let ar = multiply_seasonal(&self.get_ar_coef(), &self.get_sar_coef(), period);
+        let ma = multiply_seasonal(&self.get_ma_coef(), &self.get_sma_coef(), period);
+        let n = w.len();
+        let mut w_ext = w.to_vec();
+        let mut e_ext = residuals.to_vec();
+        for &shock in shocks.iter() {
+            let t = w_ext.len();
+            let mut value = intercept + shock;
+            for (i, &ar_i) in ar.iter().enumerate() {
+                if t > i {
+                    value += ar_i * (w_ext[t - 1 - i] - intercept);
+                }
+            }
+            for (j, &ma_j) in ma.iter().enumerate() {
+                if t > j {
+                    value -= ma_j * e_ext[t - 1 - j];
+                }
+            }
+            w_ext.push(value);
+            e_ext.push(shock);
+        }
+        // x_{n+k} = w_{n+k} + x_{n+k-lag}, undoing the differences in reverse order
+        let mut path = w_ext[n..].to_vec();
+        for (lag, tail) in history.iter().rev() {
+            let mut level = tail.clone();
+            for value in path.iter_mut() {
+                *value += level[level.len() - lag];
+                level.push(*value);
+            }
+        }
+        path
+    }
+
+    // Residual variance used for the prediction intervals
+    fn forecast_variance(&self) -> f64 {
+        if self.get_res_var() > 0.0 {
+            self.get_res_var()
+        } else {
+            self.res_variance()
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Arima {
+    /// h-step-ahead forecasts with ψ-weight prediction intervals at the given level
+    /// (e.g. 0.95); needs 0 < level < 1
+    pub fn forecast_ahead(&self, h: usize, level: f64) -> Result<ForecastResult, JsValue> {
+        if !(level > 0.0 && level < 1.0) {
+            return Err(JsValue::from_str("level must lie strictly between 0 and 1"));
+        }
+        let (w, history) = self.differenced_levels();
+        let residuals = self.est_res(self.get_constant(), self.get_ar_coef(), self.get_ma_coef(), w.clone());
+        let forecast = self.simulate_ahead(&w, &residuals, &history, &vec![0.0; h]);
+
+        // Var(e_{n+h}) = σ²·Σ_{j<h} ψ_j²
+        let period = self.get_period() as usize;
+        let phi = multiply_seasonal(&self.get_ar_coef(), &self.get_sar_coef(), period);
+        let theta = multiply_seasonal(&self.get_ma_coef(), &self.get_sma_coef(), period);
+        let phi_star = integrated_ar(&phi, self.get_i_order() as usize, self.get_si_order() as usize, period);
+        let psi = psi_weights(&phi_star, &theta, h);
+        let sigma2 = self.forecast_variance();
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let z = normal.inverse_cdf(1.0 - (1.0 - level) / 2.0);
+
+        let mut se = Vec::with_capacity(h);
+        let mut sum_psi2 = 0.0;
+        for psi_j in psi.iter() {
+            sum_psi2 += psi_j * psi_j;
+            se.push((sigma2 * sum_psi2).sqrt());
+        }
+        let lower = forecast.iter().zip(se.iter()).map(|(f, s)| f - z * s).collect();
+        let upper = forecast.iter().zip(se.iter()).map(|(f, s)| f + z * s).collect();
+        Ok(ForecastResult::new(forecast, se, lower, upper, level))
+    }
+
+    /// h-step-ahead forecasts with intervals from bootstrap paths that resample
+    /// the centred in-sample residuals (no normality assumption); needs at least two
+    /// paths and 0 < level < 1
+    pub fn forecast_ahead_bootstrap(&self, h: usize, level: f64, n_paths: usize, seed: u64) -> Result<ForecastResult, JsValue> {
+        if n_paths < 2 {
+            return Err(JsValue::from_str("At least two bootstrap paths are needed"));
+        }
+        if !(level > 0.0 && level < 1.0) {
+            return Err(JsValue::from_str("level must lie strictly between 0 and 1"));
+        }
+        let (w, history) = self.differenced_levels();
+        let residuals = self.est_res(self.get_constant(), self.get_ar_coef(), self.get_ma_coef(), w.clone());
+        if residuals.is_empty() {
+            return Err(JsValue::from_str("No residuals to resample"));
+        }
+        let forecast = self.simulate_ahead(&w, &residuals, &history, &vec![0.0; h]);
+
+        let skip = (self.get_ar_order() + self.get_sar_order() * self.get_period())
+            .max(self.get_ma_order() + self.get_sma_order() * self.get_period()) as usize;
+        let pool: Vec<f64> = residuals[skip.min(residuals.len() - 1)..].to_vec();
+        let mean = pool.iter().sum::<f64>() / pool.len() as f64;
+        let pool: Vec<f64> = pool.iter().map(|e| e - mean).collect();
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut paths = Vec::with_capacity(n_paths);
+        for _ in 0..n_paths {
+            let shocks: Vec<f64> = (0..h).map(|_| pool[rng.gen_range(0..pool.len())]).collect();
+            paths.push(self.simulate_ahead(&w, &residuals, &history, &shocks));
+        }
+        Ok(ForecastResult::from_paths(forecast, paths, level))
+    }
+}
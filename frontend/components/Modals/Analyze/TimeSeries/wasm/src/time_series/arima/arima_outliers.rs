@@ -0,0 +1,238 @@
+use wasm_bindgen::prelude::*;
+use crate::{Arima, Arimax, css_residuals, multiply_seasonal};
+use crate::time_series::arima::arima_fore_ahead::{integrated_ar, psi_weights};
+
+// Decay rate of the temporary change pattern 1/(1 - δB)
+const TC_DELTA: f64 = 0.7;
+
+/// Outliers found by the Chen-Liu procedure with their effects jointly re-estimated
+// NOTE: Vec<f64> fields can't be public in WASM structs - use getters
+#[wasm_bindgen]
+pub struct OutlierResult {
+    index: Vec<usize>,          // 0-based position of each outlier
+    types: Vec<String>,         // "AO", "LS", "TC" or "IO"
+    effect: Vec<f64>,           // ω from the joint fit
+    t_stat: Vec<f64>,
+    adjusted: Vec<f64>,         // Series with the outlier effects removed
+    noise_coef: Vec<f64>,       // [constant, φ, θ, Φ, Θ] of the final ARIMA fit
+    pub critical: f64,
+    pub iterations: usize,
+}
+
+#[wasm_bindgen]
+impl OutlierResult {
+    pub fn get_index(&self) -> Vec<usize> { self.index.clone() }
+    pub fn get_types(&self) -> Vec<String> { self.types.clone() }
+    pub fn get_effect(&self) -> Vec<f64> { self.effect.clone() }
+    pub fn get_t_stat(&self) -> Vec<f64> { self.t_stat.clone() }
+    pub fn get_adjusted(&self) -> Vec<f64> { self.adjusted.clone() }
+    pub fn get_noise_coef(&self) -> Vec<f64> { self.noise_coef.clone() }
+}
+
+/// Default critical value of Chen and Liu (1993): 3 up to 50 observations,
+/// 4 from 450 on and linear in between
+pub fn outlier_critical_value(n: usize) -> f64 {
+    (3.0 + 0.0025 * (n as f64 - 50.0)).clamp(3.0, 4.0)
+}
+
+/// Effect pattern of an outlier at time 0 on the observed series, over `len` periods.
+/// The innovational outlier enters through the ψ-weights of the model.
+fn outlier_pattern(kind: &str, psi: &[f64], len: usize) -> Vec<f64> {
+    (0..len)
+        .map(|j| match kind {
+            "AO" => if j == 0 { 1.0 } else { 0.0 },
+            "LS" => 1.0,
+            "TC" => TC_DELTA.powi(j as i32),
+            "IO" => psi[j],
+            _ => panic!("Unknown outlier type: {}", kind),
+        })
+        .collect()
+}
+
+// Internal helpers (not exposed to WASM)
+impl Arima {
+    /// Expanded φ*(B) = φ(B)Φ(B^s)(1 - B)^d(1 - B^s)^D and θ(B)Θ(B^s) of the fitted model
+    fn outlier_polynomials(&self) -> (Vec<f64>, Vec<f64>) {
+        let period = self.get_period() as usize;
+        let phi = multiply_seasonal(&self.get_ar_coef(), &self.get_sar_coef(), period);
+        let theta = multiply_seasonal(&self.get_ma_coef(), &self.get_sma_coef(), period);
+        let phi_star = integrated_ar(&phi, self.get_i_order() as usize, self.get_si_order() as usize, period);
+        (phi_star, theta)
+    }
+
+    /// Same orders and method on other data, with fitted coefficients
+    fn refit(&self, data: Vec<f64>) -> Result<Arima, JsValue> {
+        let mut arima = Arima::new(data, self.get_ar_order(), self.get_i_order(), self.get_ma_order());
+        arima.set_seasonal_order(self.get_sar_order(), self.get_si_order(), self.get_sma_order(), self.get_period())?;
+        arima.set_method(self.get_method());
+        arima.estimate_coef();
+        Ok(arima)
+    }
+
+    /// Chen-Liu stage 1 on the residuals of a fitted model: repeatedly locate the largest
+    /// |τ| over all times and types, and while it exceeds the critical value, record the
+    /// outlier and remove its effect ω·π(B)L(B) from the residuals
+    fn locate_outliers(&self, types: &[String], critical: f64, known: &[(usize, String)]) -> Vec<(usize, String)> {
+        let n = self.get_data().len();
+        let lost = self.n_diff() as usize;
+        let (phi_star, theta) = self.outlier_polynomials();
+        let psi = psi_weights(&phi_star, &theta, n);
+        let mut residuals = self.est_res(self.get_constant(), self.get_ar_coef(), self.get_ma_coef(), self.differenced_data());
+
+        // Robust σ = 1.483·MAD of the residuals
+        let mut sorted = residuals.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let median = sorted[sorted.len() / 2];
+        let mut deviations: Vec<f64> = residuals.iter().map(|e| (e - median).abs()).collect();
+        deviations.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let sigma = 1.483 * deviations[deviations.len() / 2];
+        if sigma <= 0.0 || !sigma.is_finite() {
+            return Vec::new();
+        }
+
+        // Residual signature ξ = π(B)L(B) of each type for an outlier at the first residual
+        let signatures: Vec<Vec<f64>> = types
+            .iter()
+            .map(|kind| css_residuals(0.0, &phi_star, &theta, &outlier_pattern(kind, &psi, residuals.len())))
+            .collect();
+
+        let mut found: Vec<(usize, String)> = Vec::new();
+        let is_taken = |t: usize, found: &[(usize, String)]| found.iter().chain(known.iter()).any(|(i, _)| *i == t);
+        for _ in 0..n {
+            let mut best: Option<(f64, usize, usize, f64)> = None; // (|τ|, time, type, ω)
+            for start in 0..residuals.len() {
+                if is_taken(start + lost, &found) {
+                    continue;
+                }
+                for (k, xi) in signatures.iter().enumerate() {
+                    let len = residuals.len() - start;
+                    let xx: f64 = xi[..len].iter().map(|v| v * v).sum();
+                    let xe: f64 = xi[..len].iter().zip(residuals[start..].iter()).map(|(x, e)| x * e).sum();
+                    if xx <= 0.0 {
+                        continue;
+                    }
+                    let omega = xe / xx;
+                    let tau = (omega * xx.sqrt() / sigma).abs();
+                    if best.is_none_or(|b| tau > b.0) {
+                        best = Some((tau, start, k, omega));
+                    }
+                }
+            }
+            match best {
+                Some((tau, start, k, omega)) if tau > critical => {
+                    for (e, x) in residuals[start..].iter_mut().zip(signatures[k].iter()) {
+                        *e -= omega * x;
+                    }
+                    found.push((start + lost, types[k].clone()));
+                },
+                _ => break,
+            }
+        }
+        found
+    }
+
+    /// Regressor of each outlier on the original scale
+    fn outlier_regressors(&self, outliers: &[(usize, String)]) -> Vec<f64> {
+        let n = self.get_data().len();
+        let (phi_star, theta) = self.outlier_polynomials();
+        let psi = psi_weights(&phi_star, &theta, n);
+        let mut x_flat = Vec::with_capacity(n * outliers.len());
+        for (index, kind) in outliers.iter() {
+            x_flat.extend(std::iter::repeat_n(0.0, *index));
+            x_flat.extend(outlier_pattern(kind, &psi, n - index));
+        }
+        x_flat
+    }
+}
+
+#[wasm_bindgen]
+impl Arima {
+    /// Iterative outlier detection of Chen and Liu (1993) for the model's orders and method.
+    /// `types` is any of "AO" (additive), "LS" (level shift), "TC" (temporary change, δ = 0.7)
+    /// and "IO" (innovational); `critical` ≤ 0 uses outlier_critical_value. Each round fits
+    /// the ARIMA model to the outlier-adjusted series, locates new outliers from its
+    /// residuals, then re-estimates all effects jointly with the noise model by exact ML
+    /// and drops those with |t| below the critical value.
+    pub fn detect_outliers(&self, types: Vec<String>, critical: f64, max_iter: usize) -> Result<OutlierResult, JsValue> {
+        let data = self.get_data();
+        let n = data.len();
+        if types.is_empty() {
+            return Err(JsValue::from_str("At least one outlier type is needed"));
+        }
+        for kind in types.iter() {
+            if !["AO", "LS", "TC", "IO"].contains(&kind.as_str()) {
+                return Err(JsValue::from_str(&format!("Unknown outlier type: {}", kind)));
+            }
+        }
+        if n <= self.n_diff() as usize + self.n_arma_coef() as usize + 3 {
+            return Err(JsValue::from_str("Not enough observations for outlier detection"));
+        }
+        let critical = if critical > 0.0 { critical } else { outlier_critical_value(n) };
+
+        let mut outliers: Vec<(usize, String)> = Vec::new();
+        let mut effect: Vec<f64> = Vec::new();
+        let mut t_stat: Vec<f64> = Vec::new();
+        let mut adjusted = data.clone();
+        let mut model = self.refit(adjusted.clone())?;
+        let mut iterations = 0;
+        while iterations < max_iter.max(1) {
+            iterations += 1;
+            let new = model.locate_outliers(&types, critical, &outliers);
+            if new.is_empty() {
+                break;
+            }
+            outliers.extend(new);
+            outliers.sort_by_key(|(index, _)| *index);
+
+            // Joint estimation, dropping the least significant outlier until all pass
+            loop {
+                let x_flat = model.outlier_regressors(&outliers);
+                let order = model.sarima_order();
+                let mut arimax = Arimax::new(data.clone(), x_flat.clone(), outliers.len(), order.p, order.d, order.q)?;
+                arimax.set_seasonal_order(order.sp, order.sd, order.sq, order.period)?;
+                let coef = arimax.estimate_coef();
+                let t = arimax.calculate_t_stats();
+                effect = coef[1..=outliers.len()].to_vec();
+                t_stat = t[1..=outliers.len()].to_vec();
+                let weakest = (0..outliers.len())
+                    .filter(|&k| t_stat[k].is_nan() || t_stat[k].abs() < critical)
+                    .min_by(|&a, &b| t_stat[a].abs().partial_cmp(&t_stat[b].abs()).unwrap_or(std::cmp::Ordering::Equal));
+                match weakest {
+                    Some(k) => {
+                        outliers.remove(k);
+                        if outliers.is_empty() {
+                            effect.clear();
+                            t_stat.clear();
+                            adjusted = data.clone();
+                            break;
+                        }
+                    },
+                    None => {
+                        adjusted = data.clone();
+                        for (k, omega) in effect.iter().enumerate() {
+                            for (a, x) in adjusted.iter_mut().zip(x_flat[k * n..(k + 1) * n].iter()) {
+                                *a -= omega * x;
+                            }
+                        }
+                        break;
+                    },
+                }
+            }
+            model = self.refit(adjusted.clone())?;
+            if outliers.is_empty() {
+                break;
+            }
+        }
+
+        Ok(OutlierResult {
+            index: outliers.iter().map(|(index, _)| *index).collect(),
+            types: outliers.iter().map(|(_, kind)| kind.clone()).collect(),
+            effect,
+            t_stat,
+            adjusted,
+            noise_coef: model.coef_vector(),
+            critical,
+            iterations,
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use wasm_bindgen::prelude::*;
-use crate::{Arima, first_difference};
+use crate::Arima;
 use std::f64::consts::PI;
 use statrs::distribution::{ContinuousCDF, FisherSnedecor};
 
@@ -7,14 +7,14 @@ use statrs::distribution::{ContinuousCDF, FisherSnedecor};
 impl Arima{
     pub fn selection_criteria(&self) -> Vec<f64>{
         let mut sel_crit = Vec::new();
-        if self.get_ar_order() > 0 || self.get_ma_order() > 0 {
+        if self.n_arma_coef() > 0 {
             sel_crit.push(self.calculate_r2());
             sel_crit.push(self.calculate_r2_adj());
         }
         sel_crit.push(self.calculate_se_reg());
         sel_crit.push(self.calculate_sse());
         sel_crit.push(self.calculate_log_likelihood());
-        if self.get_ar_order() > 0 || self.get_ma_order() > 0 { 
+        if self.n_arma_coef() > 0 { 
             sel_crit.push(self.calculate_f_stat());
             sel_crit.push(self.calculate_f_prob());
         }
@@ -30,13 +30,7 @@ impl Arima{
         let intercept = self.get_constant();
         let ar = self.get_ar_coef();
         let ma = self.get_ma_coef();
-        let mut data = self.get_data();
-        if self.get_i_order() > 0 {
-            for _ in 0..self.get_i_order() {
-                let diff = first_difference(data.clone());
-                data = diff;
-            }
-        }
+        let data = self.differenced_data();
         let residual = self.est_res(intercept, ar.clone(), ma.clone(), data);
         let sse = residual.iter().map(|x| x.powi(2)).sum::<f64>();
         sse
@@ -45,10 +39,9 @@ impl Arima{
     pub fn calculate_mse(&self)-> f64 {
         let sse = self.calculate_sse();
         let n = self.get_data().len() as f64;
-        let p = self.get_ar_order() as f64;
-        let q = self.get_ma_order() as f64;
-        let d = self.get_i_order() as f64;
-        let mse = sse / (n - p - q - d - 1.0);
+        let k = self.n_arma_coef() as f64;
+        let d = self.n_diff() as f64;
+        let mse = sse / (n - k - d - 1.0);
         mse
     }
 
@@ -74,7 +67,7 @@ impl Arima{
     pub fn calculate_r2_adj(&self)-> f64 {
         let r2 = self.calculate_r2();
         let n = self.get_data().len() as f64;
-        let k = (self.get_ar_order() + self.get_ma_order() + self.get_i_order() + 1) as f64;
+        let k = (self.n_arma_coef() + self.n_diff() + 1) as f64;
         let r2_adj = 1.0 - (1.0 - r2) * (n - 1.0) / (n - k);
         r2_adj
     }
@@ -86,6 +79,9 @@ impl Arima{
     }
 
     pub fn calculate_log_likelihood(&self)-> f64{
+        if self.get_method() == "ml" && self.get_log_likelihood().is_finite() {
+            return self.get_log_likelihood();
+        }
         let n = self.get_data().len() as f64;
         let sse = self.calculate_sse();
         let var = sse / n;
@@ -98,7 +94,7 @@ impl Arima{
         let sse = self.calculate_sse();
         let n = self.get_data().len() as f64;
         let ssr = sst - sse;
-        let k = (self.get_ar_order() + self.get_ma_order() + self.get_i_order() + 1) as f64;
+        let k = (self.n_arma_coef() + self.n_diff() + 1) as f64;
         let f_stat = (ssr / 1.0) / (sse / (n - k));
         f_stat
     }
@@ -106,7 +102,7 @@ impl Arima{
     pub fn calculate_f_prob(&self)-> f64 {
         let f_stat = self.calculate_f_stat();
         let n = self.get_data().len() as f64;
-        let k = (self.get_ar_order() + self.get_ma_order() + self.get_i_order() + 1) as f64;
+        let k = (self.n_arma_coef() + self.n_diff() + 1) as f64;
         let f = FisherSnedecor::new(1.0, n - k).unwrap();
         let f_prob = 1.0 - f.cdf(f_stat);
         f_prob
@@ -133,7 +129,7 @@ impl Arima{
 
     pub fn calculate_aic(&self)-> f64 {
         let n = self.get_data().len() as f64;
-        let p = (self.get_ar_order() + self.get_ma_order() + 1) as f64;
+        let p = (self.n_arma_coef() + 1) as f64;
         let likelihood = self.calculate_log_likelihood();
         let aic = -2.0 * likelihood + 2.0 * p;
         aic / n
@@ -141,21 +137,14 @@ impl Arima{
 
     pub fn calculate_sbc(&self)-> f64 {
         let n = self.get_data().len() as f64;
-        let p = (self.get_ar_order() + self.get_ma_order() + 1) as f64;
+        let p = (self.n_arma_coef() + 1) as f64;
         let likelihood = self.calculate_log_likelihood();
         let bic = -2.0 * likelihood + p * (n.ln());
         bic / n
     }
 
     pub fn calculate_dw(&self)-> f64 {
-        let mut data = self.get_data();
-        let d = self.get_i_order();
-        if d > 0 {
-            for _ in 0..d{
-                let diff = first_difference(data.clone());
-                data = diff;
-            }
-        }
+        let data = self.differenced_data();
         let intercept = self.get_constant();
         let ma_coef = self.get_ma_coef();
         let ar_coef = self.get_ar_coef();
@@ -171,7 +160,7 @@ impl Arima{
     pub fn calculate_hqc(&self)-> f64 {
         let likelihood = self.calculate_log_likelihood();
         let n = self.get_data().len() as f64;
-        let k = (self.get_ar_order() + self.get_ma_order() + 1) as f64;
+        let k = (self.n_arma_coef() + 1) as f64;
         let hc = -2.0 * likelihood + 2.0 * k * (n.ln()).ln();
         hc / n
     }
@@ -14,10 +14,9 @@ impl Arima {
     pub fn p_value(&mut self) -> Vec<f64> {
         let t_stat = self.t_stat();
         let n = self.get_data().len() as f64;
-        let p = self.get_ar_order() as f64;
-        let d = self.get_i_order() as f64;
-        let q = self.get_ma_order() as f64;
-        let df = n - p - d - q - 1.0;
+        let k = self.n_arma_coef() as f64;
+        let d = self.n_diff() as f64;
+        let df = n - k - d - 1.0;
         let t_dist = StudentsT::new(0.0, 1.0, df).unwrap();
         let p_value = t_stat.iter().map(|t| 2.0 * (1.0 - t_dist.cdf(t.abs()))).collect();
         p_value
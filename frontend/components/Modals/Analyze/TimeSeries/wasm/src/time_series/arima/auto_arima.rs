@@ -0,0 +1,121 @@
+use wasm_bindgen::prelude::*;
+use serde::Serialize;
+use crate::Arima;
+
+// One model tried by the automatic order search
+#[derive(Serialize, Clone)]
+pub struct ArimaCandidate {
+    pub p: usize,
+    pub d: usize,
+    pub q: usize,
+    pub sp: usize,
+    pub sd: usize,
+    pub sq: usize,
+    pub period: usize,
+    pub log_likelihood: f64,
+    pub aicc: f64,
+    pub bic: f64,
+}
+
+#[wasm_bindgen]
+pub struct AutoArima {
+    data: Vec<f64>,
+    period: usize,          // Seasonal period (0 or 1 for non-seasonal data)
+    max_p: usize,
+    max_d: usize,
+    max_q: usize,
+    max_sp: usize,
+    max_sd: usize,
+    max_sq: usize,
+    criterion: String,      // "aicc" or "bic"
+    method: String,         // "css" or "ml"
+    unit_root_test: String, // "adf", "kpss" or "pp"
+    alpha: f64,             // Significance level of the unit-root test
+    seasonal_strength: f64,
+    best_order: Vec<usize>, // [p, d, q, P, D, Q, s]
+    candidates: Vec<ArimaCandidate>,
+}
+
+#[wasm_bindgen]
+impl AutoArima {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, period: usize) -> AutoArima {
+        AutoArima {
+            data,
+            period,
+            max_p: 5,
+            max_d: 2,
+            max_q: 5,
+            max_sp: 2,
+            max_sd: 1,
+            max_sq: 2,
+            criterion: "aicc".to_string(),
+            method: "css".to_string(),
+            unit_root_test: "adf".to_string(),
+            alpha: 0.05,
+            seasonal_strength: 0.0,
+            best_order: Vec::new(),
+            candidates: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> { self.data.clone() }
+    pub fn get_period(&self) -> usize { self.period }
+    pub fn get_criterion(&self) -> String { self.criterion.clone() }
+    pub fn get_method(&self) -> String { self.method.clone() }
+    pub fn get_unit_root_test(&self) -> String { self.unit_root_test.clone() }
+    pub fn get_alpha(&self) -> f64 { self.alpha }
+    pub fn get_seasonal_strength(&self) -> f64 { self.seasonal_strength }
+    pub fn get_best_order(&self) -> Vec<usize> { self.best_order.clone() }
+
+    // Ranked table of every model tried (best first)
+    pub fn get_candidates(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.candidates).unwrap()
+    }
+
+    // Setters
+    pub fn set_max_order(&mut self, max_p: usize, max_d: usize, max_q: usize) {
+        self.max_p = max_p;
+        self.max_d = max_d;
+        self.max_q = max_q;
+    }
+    pub fn set_max_seasonal_order(&mut self, max_sp: usize, max_sd: usize, max_sq: usize) {
+        self.max_sp = max_sp;
+        self.max_sd = max_sd;
+        self.max_sq = max_sq;
+    }
+    pub fn set_criterion(&mut self, criterion: String) { self.criterion = criterion; }
+    pub fn set_method(&mut self, method: String) { self.method = method; }
+    pub fn set_unit_root_test(&mut self, unit_root_test: String) -> Result<(), JsValue> {
+        if !matches!(unit_root_test.as_str(), "adf" | "kpss" | "pp") {
+            return Err(JsValue::from_str(&format!("Unknown unit root test: {}", unit_root_test)));
+        }
+        self.unit_root_test = unit_root_test;
+        Ok(())
+    }
+    pub fn set_alpha(&mut self, alpha: f64) { self.alpha = alpha; }
+
+    // Chosen model, estimated and ready for forecasting
+    pub fn get_best_model(&self) -> Result<Arima, JsValue> {
+        let order = &self.best_order;
+        if order.is_empty() {
+            return Err(JsValue::from_str("No model selected yet"));
+        }
+        let mut arima = Arima::new(self.data.clone(), order[0] as i32, order[1] as i32, order[2] as i32);
+        arima.set_seasonal_order(order[3] as i32, order[4] as i32, order[5] as i32, order[6] as i32)?;
+        arima.set_method(self.method.clone());
+        arima.estimate_coef();
+        Ok(arima)
+    }
+}
+
+// Internal accessors (not exposed to WASM)
+impl AutoArima {
+    pub fn candidates(&self) -> &[ArimaCandidate] { &self.candidates }
+    pub fn max_order(&self) -> (usize, usize, usize) { (self.max_p, self.max_d, self.max_q) }
+    pub fn max_seasonal_order(&self) -> (usize, usize, usize) { (self.max_sp, self.max_sd, self.max_sq) }
+    pub fn set_seasonal_strength(&mut self, strength: f64) { self.seasonal_strength = strength; }
+    pub fn set_best_order(&mut self, order: Vec<usize>) { self.best_order = order; }
+    pub fn set_candidates(&mut self, candidates: Vec<ArimaCandidate>) { self.candidates = candidates; }
+}
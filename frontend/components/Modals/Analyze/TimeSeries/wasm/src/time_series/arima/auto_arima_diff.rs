@@ -0,0 +1,79 @@
+use wasm_bindgen::prelude::*;
+use crate::{AutoArima, AugmentedDickeyFuller, Decomposition, Kpss, PhillipsPerron};
+use crate::{difference_series, first_difference};
+
+#[wasm_bindgen]
+impl AutoArima {
+    /// Seasonal strength F_s = max(0, 1 - Var(R_t) / Var(S_t + R_t)) from the
+    /// classical additive decomposition
+    pub fn calculate_seasonal_strength(&self) -> f64 {
+        let data = self.get_data();
+        let period = self.get_period();
+        if period < 2 || data.len() < 2 * period + 2 {
+            return 0.0;
+        }
+        let mut decomposition = Decomposition::new(data, period as i32);
+        decomposition.additive_decomposition();
+        let seasonal = decomposition.get_seasonal_component();
+        let irregular = decomposition.get_irregular_component();
+        let seasonal_irregular: Vec<f64> = seasonal.iter().zip(irregular.iter()).map(|(s, r)| s + r).collect();
+        let variance = |x: &[f64]| {
+            let mean = x.iter().sum::<f64>() / x.len() as f64;
+            x.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (x.len() as f64 - 1.0)
+        };
+        let var_si = variance(&seasonal_irregular);
+        if var_si <= 0.0 {
+            return 0.0;
+        }
+        (1.0 - variance(&irregular) / var_si).max(0.0)
+    }
+
+    /// Seasonal differencing order: D = 1 when the seasonal strength exceeds 0.64
+    pub fn select_seasonal_d(&mut self) -> usize {
+        let (_, max_sd, _) = self.max_seasonal_order();
+        let strength = self.calculate_seasonal_strength();
+        self.set_seasonal_strength(strength);
+        if max_sd > 0 && strength > 0.64 { 1 } else { 0 }
+    }
+
+    /// Ordinary differencing order: difference until the unit-root test declares
+    /// the (seasonally differenced) series stationary
+    pub fn select_d(&self, seasonal_d: usize) -> usize {
+        let (_, max_d, _) = self.max_order();
+        let mut series = difference_series(self.get_data(), 0, seasonal_d, self.get_period());
+        for d in 0..max_d {
+            if self.is_stationary(series.clone()) {
+                return d;
+            }
+            series = first_difference(series);
+        }
+        max_d
+    }
+
+    /// Stationarity decision of the configured unit-root test at level alpha
+    pub fn is_stationary(&self, series: Vec<f64>) -> bool {
+        match self.get_unit_root_test().as_str() {
+            "adf" => {
+                // H0: unit root; lag order ⌊(n-1)^(1/3)⌋
+                let lag = ((series.len() as f64 - 1.0).cbrt().floor() as u8).max(1);
+                let mut adf = AugmentedDickeyFuller::new(series, "no_trend".to_string(), "level".to_string(), lag);
+                adf.calculate_test_stat();
+                adf.calculate_pvalue() < self.get_alpha()
+            },
+            "kpss" => {
+                // H0: level stationarity; the tabulated p-value lies in [0.01, 0.10]
+                let mut kpss = Kpss::new(series, "no_trend".to_string(), "level".to_string(), "short".to_string())
+                    .expect("no_trend is a KPSS equation");
+                kpss.calculate_test_stat();
+                kpss.calculate_pvalue() > self.get_alpha()
+            },
+            "pp" => {
+                // H0: unit root
+                let mut pp = PhillipsPerron::new(series, "no_trend".to_string(), "level".to_string(), "short".to_string());
+                pp.calculate_test_stat();
+                pp.calculate_pvalue() < self.get_alpha()
+            },
+            _ => unreachable!(),
+        }
+    }
+}
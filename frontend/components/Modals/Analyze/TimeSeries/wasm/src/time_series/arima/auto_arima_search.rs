@@ -0,0 +1,122 @@
+use wasm_bindgen::prelude::*;
+use crate::{Arima, AutoArima};
+use crate::time_series::arima::auto_arima::ArimaCandidate;
+
+// Internal search helpers (not exposed to WASM)
+impl AutoArima {
+    /// Estimate ARIMA(p,d,q)(P,D,Q)_s and compute AICc and BIC with k = p+q+P+Q+2
+    /// (constant and innovation variance included)
+    pub fn fit_candidate(&self, order: [usize; 6]) -> ArimaCandidate {
+        let [p, d, q, sp, sd, sq] = order;
+        let period = if sp + sd + sq > 0 { self.get_period() } else { 0 };
+        let mut arima = Arima::new(self.get_data(), p as i32, d as i32, q as i32);
+        if arima.set_seasonal_order(sp as i32, sd as i32, sq as i32, period as i32).is_err() {
+            // Seasonal lags longer than the series: rank last
+            let (log_likelihood, aicc, bic) = (f64::NAN, f64::INFINITY, f64::INFINITY);
+            return ArimaCandidate { p, d, q, sp, sd, sq, period, log_likelihood, aicc, bic };
+        }
+        arima.set_method(self.get_method());
+        arima.estimate_coef();
+
+        let log_likelihood = arima.calculate_log_likelihood();
+        let n = (self.get_data().len() - d - sd * period) as f64;
+        let k = (p + q + sp + sq + 2) as f64;
+        let aicc = if n - k - 1.0 > 0.0 {
+            -2.0 * log_likelihood + 2.0 * k + 2.0 * k * (k + 1.0) / (n - k - 1.0)
+        } else {
+            f64::INFINITY
+        };
+        let bic = -2.0 * log_likelihood + k * n.ln();
+        ArimaCandidate { p, d, q, sp, sd, sq, period, log_likelihood, aicc, bic }
+    }
+
+    // Value of the selection criterion (non-finite fits rank last)
+    fn score(&self, candidate: &ArimaCandidate) -> f64 {
+        let value = if self.get_criterion() == "bic" { candidate.bic } else { candidate.aicc };
+        if value.is_finite() { value } else { f64::INFINITY }
+    }
+
+    /// Stepwise search over (p, q, P, Q) for fixed d and D (Hyndman-Khandakar).
+    /// Every model tried is stored, ranked by the criterion.
+    pub fn search_orders(&mut self, d: usize, sd: usize) -> Vec<usize> {
+        let (max_p, _, max_q) = self.max_order();
+        let (max_sp, _, max_sq) = self.max_seasonal_order();
+        let seasonal = self.get_period() > 1 && (max_sp > 0 || max_sq > 0 || sd > 0);
+        let (max_sp, max_sq) = if seasonal { (max_sp, max_sq) } else { (0, 0) };
+
+        let mut tried: Vec<ArimaCandidate> = Vec::new();
+        let mut best: Option<ArimaCandidate> = None;
+        let try_order = |this: &AutoArima, tried: &mut Vec<ArimaCandidate>, o: [usize; 4]| -> Option<ArimaCandidate> {
+            if o[0] > max_p || o[1] > max_q || o[2] > max_sp || o[3] > max_sq {
+                return None;
+            }
+            if tried.iter().any(|c| [c.p, c.q, c.sp, c.sq] == o) {
+                return None;
+            }
+            let candidate = this.fit_candidate([o[0], d, o[1], o[2], sd, o[3]]);
+            tried.push(candidate.clone());
+            Some(candidate)
+        };
+
+        // Starting models
+        let starts = [[2, 2, 1, 1], [0, 0, 0, 0], [1, 0, 1, 0], [0, 1, 0, 1]];
+        for start in starts.iter() {
+            let o = [start[0].min(max_p), start[1].min(max_q), start[2].min(max_sp), start[3].min(max_sq)];
+            if let Some(candidate) = try_order(self, &mut tried, o) {
+                if best.as_ref().is_none_or(|b| self.score(&candidate) < self.score(b)) {
+                    best = Some(candidate);
+                }
+            }
+        }
+
+        // Move to the first neighbour that improves the criterion until none does
+        let steps: [[i32; 4]; 12] = [
+            [-1, 0, 0, 0], [1, 0, 0, 0], [0, -1, 0, 0], [0, 1, 0, 0],
+            [-1, -1, 0, 0], [1, 1, 0, 0], [0, 0, -1, 0], [0, 0, 1, 0],
+            [0, 0, 0, -1], [0, 0, 0, 1], [0, 0, -1, -1], [0, 0, 1, 1],
+        ];
+        for _ in 0..100 {
+            let current = match best.clone() {
+                Some(c) => c,
+                None => break,
+            };
+            let base = [current.p as i32, current.q as i32, current.sp as i32, current.sq as i32];
+            let mut improved = false;
+            for step in steps.iter() {
+                let next: Vec<i32> = base.iter().zip(step.iter()).map(|(b, s)| b + s).collect();
+                if next.iter().any(|&v| v < 0) {
+                    continue;
+                }
+                let o = [next[0] as usize, next[1] as usize, next[2] as usize, next[3] as usize];
+                if let Some(candidate) = try_order(self, &mut tried, o) {
+                    if self.score(&candidate) < self.score(&current) {
+                        best = Some(candidate);
+                        improved = true;
+                        break;
+                    }
+                }
+            }
+            if !improved {
+                break;
+            }
+        }
+
+        tried.sort_by(|a, b| self.score(a).partial_cmp(&self.score(b)).unwrap_or(std::cmp::Ordering::Equal));
+        let best = tried[0].clone();
+        self.set_candidates(tried);
+        let order = vec![best.p, best.d, best.q, best.sp, best.sd, best.sq, best.period];
+        self.set_best_order(order.clone());
+        order
+    }
+}
+
+#[wasm_bindgen]
+impl AutoArima {
+    /// Choose D by seasonal strength, d by repeated unit-root tests, then search
+    /// p, q, P, Q stepwise; returns [p, d, q, P, D, Q, s]
+    pub fn search(&mut self) -> Vec<usize> {
+        let seasonal_d = if self.get_period() > 1 { self.select_seasonal_d() } else { 0 };
+        let d = self.select_d(seasonal_d);
+        self.search_orders(d, seasonal_d)
+    }
+}
@@ -1,60 +1,38 @@
-use crate::{css, durb_lev_alg, first_difference, innov_alg};
+use crate::{css_residuals, durb_lev_alg, innov_alg, SarimaOrder};
 use finitediff::FiniteDiff;
 use liblbfgs::lbfgs;
 use anyhow::Result;
 
 pub fn est_coef(p: usize, d: usize, q: usize, data: Vec<f64>) -> Result<Vec<f64>> {
-    let mut data = data.clone();
+    est_coef_sarima(SarimaOrder::new(p, d, q), data)
+}
+
+// Conditional sum of squares estimation of a (seasonal) ARIMA model
+pub fn est_coef_sarima(order: SarimaOrder, data: Vec<f64>) -> Result<Vec<f64>> {
+    let p = order.p;
+    let q = order.q;
+    let data = order.difference(data);
     let mut coef = Vec::new();
-    if d > 0 {
-        for _ in 0..d{
-            let diff = first_difference(data.clone());
-            data = diff;
-        }
-    }
     let mean = data.iter().sum::<f64>() / data.len() as f64;
     coef.push(mean);
-    if p > 0 && q > 0 {
-        let phi = durb_lev_alg(p, data.clone());
-        for i in 0..p{
-            coef.push(phi[i]);
-        }
-        let theta = innov_alg(q, data.clone());
-        for i in 0..q{
-            coef.push(theta[i]);
-        }
-    } else if p > 0 {
+    if p > 0 {
         let phi = durb_lev_alg(p, data.clone());
         for i in 0..p{
             coef.push(phi[i]);
         }
-    }else if q > 0 {
+    }
+    if q > 0 {
         let theta = innov_alg(q, data.clone());
         for i in 0..q{
             coef.push(theta[i]);
         }
-    } 
+    }
+    // Seasonal coefficients start from zero
+    coef.resize(coef.len() + order.sp + order.sq, 0.0);
     let f = |coef: &Vec<f64>| {
-        let intercept = coef[0];
-        let phi ;
-        let theta ;
-        if p > 0 && q > 0{
-            phi = coef[1..p+1].to_vec();
-            theta = coef[p+1..].to_vec();
-        } else if p > 0 {
-            phi = coef[1..].to_vec();
-            theta = Vec::new();
-        } else {
-            phi = Vec::new();
-            theta = coef[1..].to_vec();
-        }
-        // let cs = css(p, q, intercept, phi, theta, data.clone());
-        // let n = data.len() as f64;
-        // let df = n - 2.0*p as f64 - q as f64 - 1.0;
-        // let var_res = cs / df;
-        // let log_like = - n / 2.0 * (2.0 * std::f64::consts::PI * var_res).ln() - cs / (2.0 * var_res);
-        // log_like
-        css(p, q, intercept, phi, theta, data.clone())
+        let (intercept, phi, theta) = order.full_polynomials(coef);
+        let residuals = css_residuals(intercept, &phi, &theta, &data);
+        residuals.iter().map(|x| x.powi(2)).sum::<f64>()
     };
     let g = |coef: &Vec<f64>| coef.forward_diff(&f);
     let eval = |x: &[f64], gx: &mut [f64]| {
@@ -76,4 +54,4 @@ pub fn est_coef(p: usize, d: usize, q: usize, data: Vec<f64>) -> Result<Vec<f64>
         tracing::warn!("Got error during fit: {}", e);
     }
     Ok(coef)
-}
\ No newline at end of file
+}
@@ -0,0 +1,107 @@
+use crate::{arma_kalman, est_coef_sarima, invert_matrix, inverse_transform_par, transform_par, SarimaOrder};
+use finitediff::FiniteDiff;
+use liblbfgs::lbfgs;
+use anyhow::Result;
+
+/// Result of exact maximum-likelihood estimation
+pub struct MlEstimate {
+    pub coef: Vec<f64>,          // [constant, φ_1..φ_p, θ_1..θ_q, Φ_1..Φ_P, Θ_1..Θ_Q]
+    pub covariance: Vec<Vec<f64>>, // Inverse of the Hessian of -log L
+    pub log_likelihood: f64,
+    pub sigma2: f64,
+}
+
+// Expand the coefficients and evaluate the exact log-likelihood on the differenced series
+fn exact_log_likelihood(order: &SarimaOrder, coef: &[f64], data: &[f64]) -> (f64, f64) {
+    let (mean, phi, theta) = order.full_polynomials(coef);
+    let centered: Vec<f64> = data.iter().map(|x| x - mean).collect();
+    let out = arma_kalman(&phi, &theta, &centered);
+    (out.log_likelihood, out.sigma2)
+}
+
+/// Central-difference Hessian with steps scaled to the parameter size
+pub fn numerical_hessian(f: &dyn Fn(&Vec<f64>) -> f64, x: &[f64]) -> Vec<Vec<f64>> {
+    let k = x.len();
+    let h: Vec<f64> = x.iter().map(|v| 1e-4 * v.abs().max(1.0)).collect();
+    let mut hessian = vec![vec![0.0; k]; k];
+    let shifted = |i: usize, si: f64, j: usize, sj: f64| {
+        let mut y = x.to_vec();
+        y[i] += si * h[i];
+        y[j] += sj * h[j];
+        f(&y)
+    };
+    for i in 0..k {
+        for j in i..k {
+            let value = (shifted(i, 1.0, j, 1.0) - shifted(i, 1.0, j, -1.0)
+                - shifted(i, -1.0, j, 1.0) + shifted(i, -1.0, j, -1.0))
+                / (4.0 * h[i] * h[j]);
+            hessian[i][j] = value;
+            hessian[j][i] = value;
+        }
+    }
+    hessian
+}
+
+pub fn est_coef_ml(p: usize, d: usize, q: usize, data: Vec<f64>) -> Result<MlEstimate> {
+    est_coef_ml_sarima(SarimaOrder::new(p, d, q), data)
+}
+
+/// Exact Gaussian maximum likelihood for (seasonal) ARIMA through the Kalman filter.
+/// Starting values are the conditional sum of squares estimates.
+pub fn est_coef_ml_sarima(order: SarimaOrder, data: Vec<f64>) -> Result<MlEstimate> {
+    let start = est_coef_sarima(order, data.clone())?;
+    let data = order.difference(data);
+    let blocks = [order.p, order.q, order.sp, order.sq];
+
+    // Optimize on the transformed scale so that every AR block stays stationary
+    // and every MA block invertible
+    let to_natural = |raw: &[f64]| -> Vec<f64> {
+        let mut coef = vec![raw[0]];
+        let mut idx = 1;
+        for &k in blocks.iter() {
+            coef.extend(transform_par(&raw[idx..idx + k]));
+            idx += k;
+        }
+        coef
+    };
+    let mut raw = vec![start[0]];
+    let mut idx = 1;
+    for &k in blocks.iter() {
+        raw.extend(inverse_transform_par(&start[idx..idx + k]));
+        idx += k;
+    }
+
+    let f = |raw: &Vec<f64>| {
+        let coef = to_natural(raw);
+        let (log_lik, _) = exact_log_likelihood(&order, &coef, &data);
+        if log_lik.is_finite() { -log_lik } else { f64::MAX }
+    };
+    let g = |raw: &Vec<f64>| raw.central_diff(&f);
+    let eval = |x: &[f64], gx: &mut [f64]| {
+        let x_vec = x.to_vec();
+        let fx = f(&x_vec);
+        let gx_eval = g(&x_vec);
+        gx[..gx_eval.len()].copy_from_slice(&gx_eval[..]);
+        Ok(fx)
+    };
+    let fmin = lbfgs().with_max_iterations(200);
+    if let Err(e) = fmin.minimize(&mut raw, eval, |_prgr| false) {
+        tracing::warn!("Got error during exact likelihood fit: {}", e);
+    }
+
+    let coef = to_natural(&raw);
+    let (log_likelihood, sigma2) = exact_log_likelihood(&order, &coef, &data);
+
+    // Observed information on the natural scale
+    let neg_log_lik = |c: &Vec<f64>| -exact_log_likelihood(&order, c, &data).0;
+    let hessian = numerical_hessian(&neg_log_lik, &coef);
+    let covariance = invert_matrix(&hessian)
+        .unwrap_or_else(|| vec![vec![f64::NAN; coef.len()]; coef.len()]);
+
+    Ok(MlEstimate {
+        coef,
+        covariance,
+        log_likelihood,
+        sigma2,
+    })
+}
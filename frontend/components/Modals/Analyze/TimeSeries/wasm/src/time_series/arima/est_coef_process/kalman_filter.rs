@@ -0,0 +1,96 @@
+use nalgebra::{DMatrix, DVector};
+use std::f64::consts::PI;
+
+/// Output of the ARMA Kalman filter (innovations are NaN at missing observations)
+pub struct KalmanOutput {
+    pub log_likelihood: f64,
+    pub sigma2: f64,
+    pub innovations: Vec<f64>,
+    pub innovation_var: Vec<f64>,
+    pub n_used: usize,
+}
+
+/// Build the state-space form of a zero-mean ARMA(p,q) model (Harvey representation)
+/// x_t = Σφ_i·x_{t-i} + e_t - Σθ_j·e_{t-j}, state dimension r = max(p, q+1)
+pub fn arma_state_space(phi: &[f64], theta: &[f64]) -> (DMatrix<f64>, DVector<f64>) {
+    let p = phi.len();
+    let q = theta.len();
+    let r = p.max(q + 1);
+    let mut t_mat = DMatrix::<f64>::zeros(r, r);
+    for (i, &phi_i) in phi.iter().enumerate() {
+        t_mat[(i, 0)] = phi_i;
+    }
+    for i in 0..r - 1 {
+        t_mat[(i, i + 1)] = 1.0;
+    }
+    let mut r_vec = DVector::<f64>::zeros(r);
+    r_vec[0] = 1.0;
+    for (j, &theta_j) in theta.iter().enumerate() {
+        r_vec[j + 1] = -theta_j;
+    }
+    (t_mat, r_vec)
+}
+
+/// Stationary state covariance P = T·P·T' + R·R' by the doubling algorithm
+pub fn stationary_covariance(t_mat: &DMatrix<f64>, r_vec: &DVector<f64>) -> DMatrix<f64> {
+    let mut p_mat = r_vec * r_vec.transpose();
+    let mut a_mat = t_mat.clone();
+    for _ in 0..64 {
+        let increment = &a_mat * &p_mat * a_mat.transpose();
+        p_mat += &increment;
+        a_mat = &a_mat * &a_mat;
+        if increment.amax() < 1e-12 * p_mat.amax().max(1.0) {
+            break;
+        }
+    }
+    p_mat
+}
+
+/// Exact Gaussian log-likelihood of a zero-mean ARMA process with σ² concentrated out.
+/// Missing observations (NaN) are skipped in the update step.
+pub fn arma_kalman(phi: &[f64], theta: &[f64], data: &[f64]) -> KalmanOutput {
+    let (t_mat, r_vec) = arma_state_space(phi, theta);
+    let r = r_vec.len();
+    let rr = &r_vec * r_vec.transpose();
+    let mut a = DVector::<f64>::zeros(r);
+    let mut p_mat = stationary_covariance(&t_mat, &r_vec);
+
+    let mut innovations = Vec::with_capacity(data.len());
+    let mut innovation_var = Vec::with_capacity(data.len());
+    let mut sum_sq = 0.0;
+    let mut sum_log_f = 0.0;
+    let mut n_used = 0;
+
+    for &y in data.iter() {
+        let f = p_mat[(0, 0)];
+        if y.is_nan() || f <= 0.0 {
+            innovations.push(f64::NAN);
+            innovation_var.push(f);
+        } else {
+            let v = y - a[0];
+            let k = p_mat.column(0) / f;
+            a += &k * v;
+            let update = &k * p_mat.row(0);
+            p_mat -= update;
+            sum_sq += v * v / f;
+            sum_log_f += f.ln();
+            n_used += 1;
+            innovations.push(v);
+            innovation_var.push(f);
+        }
+        a = &t_mat * &a;
+        p_mat = &t_mat * &p_mat * t_mat.transpose() + &rr;
+    }
+
+    let n = n_used as f64;
+    let sigma2 = if n_used > 0 { sum_sq / n } else { f64::NAN };
+    let log_likelihood = -0.5 * (n * ((2.0 * PI * sigma2).ln() + 1.0) + sum_log_f);
+
+    KalmanOutput {
+        log_likelihood,
+        sigma2,
+        innovations,
+        innovation_var,
+        n_used,
+    }
+}
@@ -2,4 +2,8 @@ pub mod durb_lev_alg;
 pub mod autocov;
 pub mod innov_alg;
 pub mod est_coef;
-pub mod css;
\ No newline at end of file
+pub mod css;
+pub mod kalman_filter;
+pub mod param_transform;
+pub mod est_coef_ml;
+pub mod sarima_order;
\ No newline at end of file
@@ -0,0 +1,34 @@
+/// Map unconstrained values to the coefficients of a stationary polynomial
+/// 1 - c_1·B - ... - c_k·B^k through partial autocorrelations (Jones, 1980).
+/// The same map keeps MA polynomials invertible.
+pub fn transform_par(raw: &[f64]) -> Vec<f64> {
+    let k = raw.len();
+    let mut coef = vec![0.0; k];
+    let mut work = vec![0.0; k];
+    for i in 0..k {
+        let u = raw[i].tanh();
+        work[i] = u;
+        for j in 0..i {
+            work[j] = coef[j] - u * coef[i - j - 1];
+        }
+        coef[..=i].copy_from_slice(&work[..=i]);
+    }
+    coef
+}
+
+/// Inverse of `transform_par`; partial autocorrelations are clamped inside (-1, 1)
+pub fn inverse_transform_par(coef: &[f64]) -> Vec<f64> {
+    let k = coef.len();
+    let mut current = coef.to_vec();
+    let mut raw = vec![0.0; k];
+    for i in (0..k).rev() {
+        let u = current[i].clamp(-0.99, 0.99);
+        raw[i] = u.atanh();
+        let mut previous = vec![0.0; i];
+        for j in 0..i {
+            previous[j] = (current[j] + u * current[i - j - 1]) / (1.0 - u * u);
+        }
+        current = previous;
+    }
+    raw
+}
@@ -0,0 +1,86 @@
+use crate::difference_series;
+
+/// Orders of a multiplicative ARIMA(p,d,q)(P,D,Q)_s model.
+/// Coefficient vectors are laid out as [constant, φ_1..φ_p, θ_1..θ_q, Φ_1..Φ_P, Θ_1..Θ_Q].
+#[derive(Clone, Copy, Debug)]
+pub struct SarimaOrder {
+    pub p: usize,
+    pub d: usize,
+    pub q: usize,
+    pub sp: usize,
+    pub sd: usize,
+    pub sq: usize,
+    pub period: usize,
+}
+
+/// Coefficients of (1 - Σa_i·B^i)(1 - ΣA_k·B^{s·k}) written as 1 - Σc_i·B^i
+pub fn multiply_seasonal(coef: &[f64], seasonal_coef: &[f64], period: usize) -> Vec<f64> {
+    if seasonal_coef.is_empty() {
+        return coef.to_vec();
+    }
+    let len = coef.len() + seasonal_coef.len() * period;
+    let mut poly = vec![0.0; len + 1];
+    let mut left = vec![1.0];
+    left.extend(coef.iter().map(|c| -c));
+    let mut right = vec![0.0; seasonal_coef.len() * period + 1];
+    right[0] = 1.0;
+    for (k, &c) in seasonal_coef.iter().enumerate() {
+        right[(k + 1) * period] = -c;
+    }
+    for (i, &a) in left.iter().enumerate() {
+        for (j, &b) in right.iter().enumerate() {
+            poly[i + j] += a * b;
+        }
+    }
+    poly[1..].iter().map(|c| -c).collect()
+}
+
+impl SarimaOrder {
+    pub fn new(p: usize, d: usize, q: usize) -> SarimaOrder {
+        SarimaOrder { p, d, q, sp: 0, sd: 0, sq: 0, period: 0 }
+    }
+
+    pub fn is_seasonal(&self) -> bool {
+        self.period > 1 && (self.sp > 0 || self.sd > 0 || self.sq > 0)
+    }
+
+    /// Number of ARMA coefficients (constant excluded)
+    pub fn n_arma(&self) -> usize {
+        self.p + self.q + self.sp + self.sq
+    }
+
+    /// Observations lost to differencing
+    pub fn n_lost(&self) -> usize {
+        self.d + self.sd * self.period
+    }
+
+    /// Apply D seasonal and d ordinary differences
+    pub fn difference(&self, data: Vec<f64>) -> Vec<f64> {
+        difference_series(data, self.d, self.sd, self.period)
+    }
+
+    /// Split a coefficient vector into (constant, φ, θ, Φ, Θ)
+    pub fn split(&self, coef: &[f64]) -> (f64, Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>) {
+        let mut idx = 1;
+        let mut take = |k: usize| {
+            let part = coef[idx..idx + k].to_vec();
+            idx += k;
+            part
+        };
+        let ar = take(self.p);
+        let ma = take(self.q);
+        let sar = take(self.sp);
+        let sma = take(self.sq);
+        (coef[0], ar, ma, sar, sma)
+    }
+
+    /// Constant with the expanded AR and MA polynomials of the multiplicative model
+    pub fn full_polynomials(&self, coef: &[f64]) -> (f64, Vec<f64>, Vec<f64>) {
+        let (constant, ar, ma, sar, sma) = self.split(coef);
+        (
+            constant,
+            multiply_seasonal(&ar, &sar, self.period),
+            multiply_seasonal(&ma, &sma, self.period),
+        )
+    }
+}
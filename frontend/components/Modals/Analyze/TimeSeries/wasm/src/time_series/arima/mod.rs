@@ -2,8 +2,13 @@ pub mod arima;
 pub mod arima_est_coef;
 pub mod arima_est_se;
 pub mod arima_fore;
+pub mod arima_fore_ahead;
 pub mod arima_sel_crit;
 pub mod arima_fore_eval;
 pub mod arima_t_test;
 pub mod arima_est_res;
-pub mod est_coef_process;
\ No newline at end of file
+pub mod auto_arima;
+pub mod auto_arima_diff;
+pub mod auto_arima_search;
+pub mod est_coef_process;
+pub mod arima_outliers;
\ No newline at end of file
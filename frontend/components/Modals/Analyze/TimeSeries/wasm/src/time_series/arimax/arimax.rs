@@ -0,0 +1,162 @@
+use wasm_bindgen::prelude::*;
+use crate::SarimaOrder;
+
+/// Numerator (ω_0..ω_s) and denominator (δ_1..δ_r) coefficients of one input
+pub type TransferCoef = (Vec<f64>, Vec<f64>);
+
+/// Regression with (seasonal) ARIMA errors and transfer-function inputs:
+/// y_t = Σ_k ω_k(B)B^{b_k}/δ_k(B)·x_{k,t} + N_t, with N_t ~ ARIMA(p,d,q)(P,D,Q)_s.
+/// An input with numerator order 0, denominator order 0 and delay 0 is an ordinary regressor.
+#[wasm_bindgen]
+pub struct Arimax {
+    pub(crate) y: Vec<f64>,
+    pub(crate) x_flat: Vec<f64>,     // Flattened 2D array: [x1[0], x1[1], ..., x2[0], x2[1], ...]
+    pub(crate) n_vars: usize,        // Number of X variables
+    pub(crate) n_obs: usize,         // Number of observations per variable
+    pub(crate) order: SarimaOrder,   // Orders of the noise model
+    pub(crate) num_order: Vec<usize>, // Numerator lags s_k (ω_0..ω_s) per input
+    pub(crate) den_order: Vec<usize>, // Denominator lags r_k (δ_1..δ_r) per input
+    pub(crate) delay: Vec<usize>,     // Dead time b_k per input
+
+    pub(crate) coefficients: Vec<f64>, // [constant, (ω_k, δ_k) per input, φ, θ, Φ, Θ]
+    pub(crate) coef_cov: Vec<f64>,     // Flattened covariance matrix of the coefficients
+    pub(crate) log_likelihood: f64,
+    pub(crate) sigma2: f64,
+}
+
+#[wasm_bindgen]
+impl Arimax {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        y: Vec<f64>,
+        x_flat: Vec<f64>, // Flattened X matrix
+        n_vars: usize,    // Number of X variables
+        p: usize,
+        d: usize,
+        q: usize,
+    ) -> Result<Arimax, JsValue> {
+        let n_obs = y.len();
+
+        // Validate dimensions
+        if x_flat.len() != n_vars * n_obs {
+            return Err(JsValue::from_str(&format!(
+                "X dimensions mismatch: expected {} ({}×{}), got {}",
+                n_vars * n_obs, n_vars, n_obs, x_flat.len()
+            )));
+        }
+
+        Ok(Arimax {
+            y,
+            x_flat,
+            n_vars,
+            n_obs,
+            order: SarimaOrder::new(p, d, q),
+            num_order: vec![0; n_vars],
+            den_order: vec![0; n_vars],
+            delay: vec![0; n_vars],
+            coefficients: Vec::new(),
+            coef_cov: Vec::new(),
+            log_likelihood: f64::NAN,
+            sigma2: f64::NAN,
+        })
+    }
+
+    /// Seasonal part (P, D, Q)_s of the noise model; the period must be at least 2 when any
+    /// seasonal order is positive and the series longer than the seasonal lags
+    pub fn set_seasonal_order(&mut self, sp: usize, sd: usize, sq: usize, period: usize) -> Result<(), JsValue> {
+        let seasonal = sp > 0 || sd > 0 || sq > 0;
+        if seasonal && period < 2 {
+            return Err(JsValue::from_str("Seasonal period must be at least 2"));
+        }
+        if seasonal && self.n_obs <= (sd + sp.max(sq)) * period {
+            return Err(JsValue::from_str(&format!(
+                "Too few observations ({}) for the seasonal lags with period {}",
+                self.n_obs, period
+            )));
+        }
+        self.order.sp = sp;
+        self.order.sd = sd;
+        self.order.sq = sq;
+        self.order.period = period;
+        self.clear_estimates();
+        Ok(())
+    }
+
+    /// Transfer-function orders, one entry per input: numerator lags s, denominator lags r and delay b
+    pub fn set_transfer(&mut self, num_flat: Vec<usize>, den_flat: Vec<usize>, delay_flat: Vec<usize>) -> Result<(), JsValue> {
+        if num_flat.len() != self.n_vars || den_flat.len() != self.n_vars || delay_flat.len() != self.n_vars {
+            return Err(JsValue::from_str(&format!(
+                "Transfer order length mismatch: expected {} entries per order",
+                self.n_vars
+            )));
+        }
+        self.num_order = num_flat;
+        self.den_order = den_flat;
+        self.delay = delay_flat;
+        self.clear_estimates();
+        Ok(())
+    }
+
+    // Helper: Get X variable i at observation t
+    pub fn get_x(&self, var_index: usize, obs_index: usize) -> f64 {
+        if var_index >= self.n_vars || obs_index >= self.n_obs {
+            return 0.0;
+        }
+        self.x_flat[var_index * self.n_obs + obs_index]
+    }
+
+    // Getters
+    pub fn get_y(&self) -> Vec<f64> { self.y.clone() }
+    pub fn get_n_vars(&self) -> usize { self.n_vars }
+    pub fn get_n_obs(&self) -> usize { self.n_obs }
+    pub fn get_coefficients(&self) -> Vec<f64> { self.coefficients.clone() }
+    pub fn get_coef_cov(&self) -> Vec<f64> { self.coef_cov.clone() }
+    pub fn get_log_likelihood(&self) -> f64 { self.log_likelihood }
+    pub fn get_sigma2(&self) -> f64 { self.sigma2 }
+    pub fn get_num_order(&self) -> Vec<usize> { self.num_order.clone() }
+    pub fn get_den_order(&self) -> Vec<usize> { self.den_order.clone() }
+    pub fn get_delay(&self) -> Vec<usize> { self.delay.clone() }
+}
+
+// Internal helpers (not exposed to WASM)
+impl Arimax {
+    /// Observations of input k
+    pub fn input(&self, k: usize) -> &[f64] {
+        &self.x_flat[k * self.n_obs..(k + 1) * self.n_obs]
+    }
+
+    /// Number of transfer-function coefficients (ω and δ over all inputs)
+    pub fn n_transfer_coef(&self) -> usize {
+        (0..self.n_vars).map(|k| self.num_order[k] + 1 + self.den_order[k]).sum()
+    }
+
+    /// Whether the stored coefficients were estimated for the current orders
+    pub fn is_estimated(&self) -> bool {
+        self.coefficients.len() == 1 + self.n_transfer_coef() + self.order.n_arma()
+    }
+
+    // Drop the estimates of a previous specification
+    fn clear_estimates(&mut self) {
+        self.coefficients = Vec::new();
+        self.coef_cov = Vec::new();
+        self.log_likelihood = f64::NAN;
+        self.sigma2 = f64::NAN;
+    }
+
+    /// Split a coefficient vector into the constant, the (ω, δ) pair of every input
+    /// and the noise-model ARMA coefficients (with the constant in front, SarimaOrder layout)
+    pub fn split_coef(&self, coef: &[f64]) -> (Vec<TransferCoef>, Vec<f64>) {
+        let mut idx = 1;
+        let mut transfer = Vec::with_capacity(self.n_vars);
+        for k in 0..self.n_vars {
+            let omega = coef[idx..idx + self.num_order[k] + 1].to_vec();
+            idx += self.num_order[k] + 1;
+            let delta = coef[idx..idx + self.den_order[k]].to_vec();
+            idx += self.den_order[k];
+            transfer.push((omega, delta));
+        }
+        let mut noise = vec![coef[0]];
+        noise.extend_from_slice(&coef[idx..]);
+        (transfer, noise)
+    }
+}
@@ -0,0 +1,193 @@
+use wasm_bindgen::prelude::*;
+use crate::{Arimax, arma_kalman, css_residuals, est_coef_sarima, transfer_filter};
+use crate::{inverse_transform_par, transform_par, numerical_hessian};
+use crate::{invert_matrix, multiply_matrix, multiply_matrix_vector, transpose};
+use finitediff::FiniteDiff;
+use liblbfgs::lbfgs;
+
+// Internal helpers (not exposed to WASM)
+impl Arimax {
+    /// Noise series N_t = y_t - Σ_k z_{k,t} implied by the coefficients
+    pub fn noise_series(&self, coef: &[f64]) -> Vec<f64> {
+        let (transfer, _) = self.split_coef(coef);
+        let mut noise = self.y.clone();
+        for (k, (omega, delta)) in transfer.iter().enumerate() {
+            let z = transfer_filter(self.input(k), omega, delta, self.delay[k]);
+            for (n_t, z_t) in noise.iter_mut().zip(z.iter()) {
+                *n_t -= z_t;
+            }
+        }
+        noise
+    }
+
+    /// Exact log-likelihood and σ² of the differenced noise series through the Kalman filter
+    pub fn exact_log_likelihood(&self, coef: &[f64]) -> (f64, f64) {
+        let (_, noise_coef) = self.split_coef(coef);
+        let w = self.order.difference(self.noise_series(coef));
+        let (mean, phi, theta) = self.order.full_polynomials(&noise_coef);
+        let centered: Vec<f64> = w.iter().map(|v| v - mean).collect();
+        let out = arma_kalman(&phi, &theta, &centered);
+        (out.log_likelihood, out.sigma2)
+    }
+
+    // Sizes of the coefficient blocks after the constant and whether each is
+    // kept stationary (δ, AR) or invertible (MA) through the PACF transform
+    fn coef_blocks(&self) -> Vec<(usize, bool)> {
+        let mut blocks = Vec::new();
+        for k in 0..self.n_vars {
+            blocks.push((self.num_order[k] + 1, false));
+            blocks.push((self.den_order[k], true));
+        }
+        for &size in [self.order.p, self.order.q, self.order.sp, self.order.sq].iter() {
+            blocks.push((size, true));
+        }
+        blocks
+    }
+
+    fn to_natural(&self, raw: &[f64]) -> Vec<f64> {
+        let mut coef = vec![raw[0]];
+        let mut idx = 1;
+        for (size, constrained) in self.coef_blocks() {
+            let block = &raw[idx..idx + size];
+            if constrained {
+                coef.extend(transform_par(block));
+            } else {
+                coef.extend_from_slice(block);
+            }
+            idx += size;
+        }
+        coef
+    }
+
+    fn to_raw(&self, coef: &[f64]) -> Vec<f64> {
+        let mut raw = vec![coef[0]];
+        let mut idx = 1;
+        for (size, constrained) in self.coef_blocks() {
+            let block = &coef[idx..idx + size];
+            if constrained {
+                raw.extend(inverse_transform_par(block));
+            } else {
+                raw.extend_from_slice(block);
+            }
+            idx += size;
+        }
+        raw
+    }
+
+    /// Starting values: ω from OLS of the differenced response on the differenced,
+    /// delayed inputs (δ = 0), then CSS estimates of the noise model
+    pub fn start_values(&self) -> Vec<f64> {
+        let y = self.order.difference(self.y.clone());
+        let mut columns = vec![vec![1.0; y.len()]];
+        for k in 0..self.n_vars {
+            let x = self.input(k);
+            for i in 0..=self.num_order[k] {
+                let lag = self.delay[k] + i;
+                let lagged: Vec<f64> = (0..self.n_obs).map(|t| if t >= lag { x[t - lag] } else { x[0] }).collect();
+                columns.push(self.order.difference(lagged));
+            }
+        }
+        let xt = transpose(&columns);
+        let xtx = multiply_matrix(&xt, &columns);
+        let beta = match invert_matrix(&xtx) {
+            Some(inv) => multiply_matrix_vector(&inv, &multiply_matrix_vector(&xt, &y)),
+            None => vec![0.0; columns.len()],
+        };
+
+        let mut coef = vec![beta[0]];
+        let mut idx = 1;
+        for k in 0..self.n_vars {
+            coef.extend_from_slice(&beta[idx..idx + self.num_order[k] + 1]);
+            idx += self.num_order[k] + 1;
+            coef.resize(coef.len() + self.den_order[k], 0.0);
+        }
+        let noise = self.noise_series(&coef);
+        let noise_start = est_coef_sarima(self.order, noise)
+            .unwrap_or_else(|_| vec![0.0; self.order.n_arma() + 1]);
+        coef[0] = noise_start[0];
+        coef.extend_from_slice(&noise_start[1..]);
+        coef
+    }
+}
+
+#[wasm_bindgen]
+impl Arimax {
+    /// Exact maximum-likelihood estimates of all coefficients, laid out as
+    /// [constant, (ω_0..ω_s, δ_1..δ_r) per input, φ, θ, Φ, Θ]
+    pub fn estimate_coef(&mut self) -> Vec<f64> {
+        let start = self.start_values();
+        let mut raw = self.to_raw(&start);
+
+        let f = |raw: &Vec<f64>| {
+            let coef = self.to_natural(raw);
+            let (log_lik, _) = self.exact_log_likelihood(&coef);
+            if log_lik.is_finite() { -log_lik } else { f64::MAX }
+        };
+        let g = |raw: &Vec<f64>| raw.central_diff(&f);
+        let eval = |x: &[f64], gx: &mut [f64]| {
+            let x_vec = x.to_vec();
+            let fx = f(&x_vec);
+            let gx_eval = g(&x_vec);
+            gx[..gx_eval.len()].copy_from_slice(&gx_eval[..]);
+            Ok(fx)
+        };
+        let fmin = lbfgs().with_max_iterations(300);
+        if let Err(e) = fmin.minimize(&mut raw, eval, |_prgr| false) {
+            tracing::warn!("Got error during ARIMAX fit: {}", e);
+        }
+
+        let coef = self.to_natural(&raw);
+        let (log_likelihood, sigma2) = self.exact_log_likelihood(&coef);
+
+        // Observed information on the natural scale
+        let neg_log_lik = |c: &Vec<f64>| -self.exact_log_likelihood(c).0;
+        let hessian = numerical_hessian(&neg_log_lik, &coef);
+        let k = coef.len();
+        let covariance = invert_matrix(&hessian).unwrap_or_else(|| vec![vec![f64::NAN; k]; k]);
+
+        self.coefficients = coef.clone();
+        self.coef_cov = covariance.into_iter().flatten().collect();
+        self.log_likelihood = log_likelihood;
+        self.sigma2 = sigma2;
+        coef
+    }
+
+    /// Standard errors from the inverse observed information
+    pub fn calculate_std_errors(&self) -> Vec<f64> {
+        let k = self.coefficients.len();
+        (0..k).map(|i| self.coef_cov[i * k + i].sqrt()).collect()
+    }
+
+    pub fn calculate_t_stats(&self) -> Vec<f64> {
+        self.coefficients.iter().zip(self.calculate_std_errors().iter()).map(|(c, se)| c / se).collect()
+    }
+
+    /// One-step residuals of the differenced noise model
+    pub fn calculate_residuals(&self) -> Result<Vec<f64>, JsValue> {
+        if !self.is_estimated() {
+            return Err(JsValue::from_str("Model not estimated"));
+        }
+        let (_, noise_coef) = self.split_coef(&self.coefficients);
+        let w = self.order.difference(self.noise_series(&self.coefficients));
+        let (mean, phi, theta) = self.order.full_polynomials(&noise_coef);
+        Ok(css_residuals(mean, &phi, &theta, &w))
+    }
+
+    /// Series y_t with the estimated transfer-function effects removed
+    pub fn calculate_noise(&self) -> Result<Vec<f64>, JsValue> {
+        if !self.is_estimated() {
+            return Err(JsValue::from_str("Model not estimated"));
+        }
+        Ok(self.noise_series(&self.coefficients))
+    }
+
+    /// AIC = -2 log L + 2(k + 1), counting σ² as a parameter
+    pub fn calculate_aic(&self) -> f64 {
+        -2.0 * self.log_likelihood + 2.0 * (self.coefficients.len() + 1) as f64
+    }
+
+    pub fn calculate_bic(&self) -> f64 {
+        let n = (self.n_obs - self.order.n_lost()) as f64;
+        -2.0 * self.log_likelihood + (self.coefficients.len() + 1) as f64 * n.ln()
+    }
+}
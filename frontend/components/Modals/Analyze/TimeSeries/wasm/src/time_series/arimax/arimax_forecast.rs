@@ -0,0 +1,68 @@
+use wasm_bindgen::prelude::*;
+use crate::{Arima, Arimax, transfer_filter};
+use crate::time_series::helper_structs::ForecastResult;
+
+// Internal helpers (not exposed to WASM)
+impl Arimax {
+    /// ARIMA model of the noise series with the estimated noise coefficients
+    pub fn noise_model(&self) -> Result<Arima, JsValue> {
+        let (_, noise_coef) = self.split_coef(&self.coefficients);
+        let (constant, ar, ma, sar, sma) = self.order.split(&noise_coef);
+        let order = self.order;
+        let mut arima = Arima::new(self.calculate_noise()?, order.p as i32, order.d as i32, order.q as i32);
+        arima.set_seasonal_order(order.sp as i32, order.sd as i32, order.sq as i32, order.period as i32)?;
+        arima.set_constant(constant);
+        arima.set_ar_coef(ar);
+        arima.set_ma_coef(ma);
+        arima.set_sar_coef(sar);
+        arima.set_sma_coef(sma);
+        arima.set_res_var(self.sigma2);
+        Ok(arima)
+    }
+}
+
+#[wasm_bindgen]
+impl Arimax {
+    /// Contribution Σ_k z_{k,t} of the inputs over the sample followed by h future periods,
+    /// given the future covariates flattened as [x1[n], .., x1[n+h-1], x2[n], ...]
+    pub fn transfer_effect(&self, x_future_flat: Vec<f64>, h: usize) -> Result<Vec<f64>, JsValue> {
+        if !self.is_estimated() {
+            return Err(JsValue::from_str("Model not estimated"));
+        }
+        if x_future_flat.len() != self.n_vars * h {
+            return Err(JsValue::from_str(&format!(
+                "Future X dimensions mismatch: expected {} ({}×{}), got {}",
+                self.n_vars * h, self.n_vars, h, x_future_flat.len()
+            )));
+        }
+        let (transfer, _) = self.split_coef(&self.coefficients);
+        let mut effect = vec![0.0; self.n_obs + h];
+        for (k, (omega, delta)) in transfer.iter().enumerate() {
+            let mut x = self.input(k).to_vec();
+            x.extend_from_slice(&x_future_flat[k * h..(k + 1) * h]);
+            let z = transfer_filter(&x, omega, delta, self.delay[k]);
+            for (e_t, z_t) in effect.iter_mut().zip(z.iter()) {
+                *e_t += z_t;
+            }
+        }
+        Ok(effect)
+    }
+
+    /// h-step-ahead forecasts given the future covariates. The intervals come from the
+    /// ψ-weights of the noise model and treat the future covariates as known.
+    pub fn forecast_ahead(&self, h: usize, x_future_flat: Vec<f64>, level: f64) -> Result<ForecastResult, JsValue> {
+        let effect = self.transfer_effect(x_future_flat, h)?;
+        let noise = self.noise_model()?.forecast_ahead(h, level)?;
+        let future = &effect[self.n_obs..];
+        let shift = |values: Vec<f64>| -> Vec<f64> {
+            values.iter().zip(future.iter()).map(|(v, z)| v + z).collect()
+        };
+        Ok(ForecastResult::new(
+            shift(noise.get_forecast()),
+            noise.get_se(),
+            shift(noise.get_lower()),
+            shift(noise.get_upper()),
+            level,
+        ))
+    }
+}
@@ -0,0 +1,39 @@
+use wasm_bindgen::prelude::*;
+use crate::Arimax;
+
+/// Intervention dummy over n periods starting at `index` (0-based): "pulse" is 1 at index only,
+/// "step" is 1 from index on and "ramp" is 1, 2, 3, ... from index on. For forecasts, build
+/// the dummy over n + h periods and pass the last h values as future covariates.
+#[wasm_bindgen]
+pub fn intervention_dummy(kind: String, index: usize, n: usize) -> Vec<f64> {
+    (0..n)
+        .map(|t| match kind.as_str() {
+            "pulse" => if t == index { 1.0 } else { 0.0 },
+            "step" => if t >= index { 1.0 } else { 0.0 },
+            "ramp" => if t >= index { (t - index + 1) as f64 } else { 0.0 },
+            _ => panic!("Unknown intervention: {}", kind),
+        })
+        .collect()
+}
+
+#[wasm_bindgen]
+impl Arimax {
+    /// Add a "pulse", "step" or "ramp" intervention at `index` as a new input whose dynamic
+    /// response is ω(B)B^b/δ(B) with the given numerator lags, denominator lags and delay
+    /// (0, 0, 0 for an abrupt, permanent effect of the dummy). Clears earlier estimates.
+    pub fn add_intervention(&mut self, kind: String, index: usize, num_order: usize, den_order: usize, delay: usize) -> Result<(), JsValue> {
+        if index >= self.n_obs {
+            return Err(JsValue::from_str(&format!(
+                "Intervention index {} is outside the {} observations", index, self.n_obs
+            )));
+        }
+        self.x_flat.extend(intervention_dummy(kind, index, self.n_obs));
+        self.n_vars += 1;
+        self.num_order.push(num_order);
+        self.den_order.push(den_order);
+        self.delay.push(delay);
+        self.coefficients = Vec::new();
+        self.coef_cov = Vec::new();
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod arimax;
+pub mod transfer_function;
+pub mod arimax_est_coef;
+pub mod arimax_forecast;
+pub mod intervention;
\ No newline at end of file
@@ -0,0 +1,37 @@
+/// Output z_t = ω(B)B^b/δ(B)·x_t of a transfer function with
+/// ω(B) = ω_0 + ω_1·B + ... + ω_s·B^s and δ(B) = 1 - δ_1·B - ... - δ_r·B^r.
+/// The input is held at x_0 before the sample and the filter starts at its steady state.
+pub fn transfer_filter(x: &[f64], omega: &[f64], delta: &[f64], delay: usize) -> Vec<f64> {
+    if x.is_empty() {
+        return Vec::new();
+    }
+    let x_at = |t: usize, lag: usize| if t >= lag { x[t - lag] } else { x[0] };
+    let gain = omega.iter().sum::<f64>() / (1.0 - delta.iter().sum::<f64>());
+    let mut z: Vec<f64> = Vec::with_capacity(x.len());
+    for t in 0..x.len() {
+        let mut value = 0.0;
+        for (i, &omega_i) in omega.iter().enumerate() {
+            value += omega_i * x_at(t, delay + i);
+        }
+        for (j, &delta_j) in delta.iter().enumerate() {
+            value += delta_j * if t > j { z[t - 1 - j] } else { gain * x[0] };
+        }
+        z.push(value);
+    }
+    z
+}
+
+/// Impulse-response weights ν_0..ν_{h-1} of ω(B)B^b/δ(B)
+pub fn impulse_response(omega: &[f64], delta: &[f64], delay: usize, h: usize) -> Vec<f64> {
+    let mut nu: Vec<f64> = Vec::with_capacity(h);
+    for j in 0..h {
+        let mut value = if j >= delay && j - delay < omega.len() { omega[j - delay] } else { 0.0 };
+        for (i, &delta_i) in delta.iter().enumerate() {
+            if j > i {
+                value += delta_i * nu[j - 1 - i];
+            }
+        }
+        nu.push(value);
+    }
+    nu
+}
@@ -0,0 +1,93 @@
+use wasm_bindgen::prelude::*;
+use crate::{Arima, CrossCorrelation};
+use statrs::distribution::{ContinuousCDF, Normal};
+
+/// Sample cross-correlations r_xy(k) = c_xy(k)/√(c_xx(0)·c_yy(0)) for k = -lag..=lag, where
+/// c_xy(k) = (1/n)·Σ (x_t - x̄)(y_{t+k} - ȳ); positive k pairs x with later values of y
+pub fn cross_correlation(x: &[f64], y: &[f64], lag: usize) -> Vec<f64> {
+    let n = x.len().min(y.len());
+    let mean_x = x[..n].iter().sum::<f64>() / n as f64;
+    let mean_y = y[..n].iter().sum::<f64>() / n as f64;
+    let dx: Vec<f64> = x[..n].iter().map(|v| v - mean_x).collect();
+    let dy: Vec<f64> = y[..n].iter().map(|v| v - mean_y).collect();
+    let scale = (dx.iter().map(|v| v * v).sum::<f64>() * dy.iter().map(|v| v * v).sum::<f64>()).sqrt();
+    let lag = lag as i64;
+    (-lag..=lag)
+        .map(|k| {
+            let shift = k.unsigned_abs() as usize;
+            if shift >= n {
+                return f64::NAN;
+            }
+            let sum: f64 = if k >= 0 {
+                (0..n - shift).map(|t| dx[t] * dy[t + shift]).sum()
+            } else {
+                (0..n - shift).map(|t| dx[t + shift] * dy[t]).sum()
+            };
+            sum / scale
+        })
+        .collect()
+}
+
+#[wasm_bindgen]
+impl CrossCorrelation {
+    /// Prewhiten both series with the ARIMA filter fitted to x. `order` is [p, d, q] or
+    /// [p, d, q, P, D, Q, s]: both series are differenced alike and passed through
+    /// φ(B)Φ(B^s)/θ(B)Θ(B^s), each around its own mean, so x becomes white noise and the CCF
+    /// of the filtered series shows the impulse response of y to x. The first p + P·s
+    /// filtered values, which rest on missing pre-sample data, are dropped.
+    pub fn prewhiten(&mut self, order: Vec<i32>) -> Result<Vec<f64>, JsValue> {
+        if order.len() != 3 && order.len() != 7 {
+            return Err(JsValue::from_str("order must be [p, d, q] or [p, d, q, P, D, Q, s]"));
+        }
+        if self.x.len() != self.y.len() {
+            return Err(JsValue::from_str("x and y must have the same length"));
+        }
+        let mut arima = Arima::new(self.x.clone(), order[0], order[1], order[2]);
+        if order.len() == 7 {
+            arima.set_seasonal_order(order[3], order[4], order[5], order[6])?;
+        }
+        let burn_in = (arima.get_ar_order() + arima.get_sar_order() * arima.get_period()) as usize;
+        if self.x.len() <= arima.n_diff() as usize + burn_in + arima.n_arma_coef() as usize + 2 {
+            return Err(JsValue::from_str("Not enough observations for the prewhitening model"));
+        }
+        arima.estimate_coef();
+
+        let diff_x = arima.differenced_data();
+        let diff_y = arima.sarima_order().difference(self.y.clone());
+        let mean_y = diff_y.iter().sum::<f64>() / diff_y.len() as f64;
+        let (ar, ma) = (arima.get_ar_coef(), arima.get_ma_coef());
+        let filtered_x = arima.est_res(arima.get_constant(), ar.clone(), ma.clone(), diff_x);
+        let filtered_y = arima.est_res(mean_y, ar, ma, diff_y);
+        self.filtered_x = filtered_x[burn_in..].to_vec();
+        self.filtered_y = filtered_y[burn_in..].to_vec();
+        self.prewhiten_coef = arima.coef_vector();
+        Ok(self.prewhiten_coef.clone())
+    }
+
+    /// CCF at lags -lag..=lag of the prewhitened series (or the raw series when prewhiten
+    /// has not been run), with standard errors 1/√(n - |k|) and bands ±z·se at `level`
+    pub fn calculate_ccf(&mut self, level: f64) -> Result<Vec<f64>, JsValue> {
+        let (x, y) = if self.filtered_x.is_empty() {
+            (&self.x, &self.y)
+        } else {
+            (&self.filtered_x, &self.filtered_y)
+        };
+        if x.len() != y.len() {
+            return Err(JsValue::from_str("x and y must have the same length"));
+        }
+        let n = x.len();
+        if n <= self.lag + 2 {
+            return Err(JsValue::from_str("The lag must be below the number of observations minus two"));
+        }
+        self.ccf = cross_correlation(x, y, self.lag);
+        let z = Normal::new(0.0, 1.0).unwrap().inverse_cdf(1.0 - (1.0 - level) / 2.0);
+        self.ccf_se = self
+            .get_lags()
+            .iter()
+            .map(|k| 1.0 / ((n - k.unsigned_abs() as usize) as f64).sqrt())
+            .collect();
+        self.lower = self.ccf_se.iter().map(|se| -z * se).collect();
+        self.upper = self.ccf_se.iter().map(|se| z * se).collect();
+        Ok(self.ccf.clone())
+    }
+}
@@ -0,0 +1,89 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct CrossCorrelation {
+    pub(crate) x: Vec<f64>,            // Input (leading) series
+    pub(crate) y: Vec<f64>,            // Output series
+    pub(crate) lag: usize,             // Lags -lag..=lag
+    pub(crate) filtered_x: Vec<f64>,   // Prewhitened series (empty: use the raw series)
+    pub(crate) filtered_y: Vec<f64>,
+    pub(crate) prewhiten_coef: Vec<f64>, // [constant, ar, ma, sar, sma] of the filter fitted to x
+    pub(crate) ccf: Vec<f64>,          // r_xy(k) = corr(x_t, y_{t+k}), k = -lag..=lag
+    pub(crate) ccf_se: Vec<f64>,
+    pub(crate) lower: Vec<f64>,
+    pub(crate) upper: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl CrossCorrelation {
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: Vec<f64>, y: Vec<f64>, lag: usize) -> CrossCorrelation {
+        CrossCorrelation {
+            x,
+            y,
+            lag,
+            filtered_x: Vec::new(),
+            filtered_y: Vec::new(),
+            prewhiten_coef: Vec::new(),
+            ccf: Vec::new(),
+            ccf_se: Vec::new(),
+            lower: Vec::new(),
+            upper: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_x(&self) -> Vec<f64> {
+        self.x.clone()
+    }
+    pub fn get_y(&self) -> Vec<f64> {
+        self.y.clone()
+    }
+    pub fn get_lag(&self) -> usize {
+        self.lag
+    }
+    pub fn get_filtered_x(&self) -> Vec<f64> {
+        self.filtered_x.clone()
+    }
+    pub fn get_filtered_y(&self) -> Vec<f64> {
+        self.filtered_y.clone()
+    }
+    pub fn get_prewhiten_coef(&self) -> Vec<f64> {
+        self.prewhiten_coef.clone()
+    }
+    pub fn get_ccf(&self) -> Vec<f64> {
+        self.ccf.clone()
+    }
+    pub fn get_ccf_se(&self) -> Vec<f64> {
+        self.ccf_se.clone()
+    }
+    pub fn get_lower(&self) -> Vec<f64> {
+        self.lower.clone()
+    }
+    pub fn get_upper(&self) -> Vec<f64> {
+        self.upper.clone()
+    }
+    /// Lags matching get_ccf: -lag..=lag (positive: x leads y)
+    pub fn get_lags(&self) -> Vec<i32> {
+        let lag = self.lag as i32;
+        (-lag..=lag).collect()
+    }
+
+    // Setters (new data drops the prewhitening)
+    pub fn set_x(&mut self, x: Vec<f64>) {
+        self.x = x;
+        self.clear_prewhitening();
+    }
+    pub fn set_y(&mut self, y: Vec<f64>) {
+        self.y = y;
+        self.clear_prewhitening();
+    }
+    pub fn set_lag(&mut self, lag: usize) {
+        self.lag = lag;
+    }
+    pub fn clear_prewhitening(&mut self) {
+        self.filtered_x = Vec::new();
+        self.filtered_y = Vec::new();
+        self.prewhiten_coef = Vec::new();
+    }
+}
@@ -3,4 +3,6 @@ pub mod acf;
 pub mod pacf;
 pub mod ljung_box;
 pub mod autocorrelate;
-pub mod bartlet;
\ No newline at end of file
+pub mod bartlet;
+pub mod cross_correlation;
+pub mod ccf;
\ No newline at end of file
@@ -0,0 +1,134 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct BoxCox {
+    pub(crate) data: Vec<f64>,  // Must be positive
+    pub(crate) period: usize,   // Subseries length for Guerrero's method and season dummies for the likelihood
+    pub(crate) lambda: f64,     // NaN until set or estimated
+}
+
+/// Box-Cox transform (x^λ - 1)/λ, or ln x when λ = 0
+pub fn box_cox_transform(x: f64, lambda: f64) -> f64 {
+    if lambda == 0.0 {
+        x.ln()
+    } else {
+        (x.powf(lambda) - 1.0) / lambda
+    }
+}
+
+/// Inverse transform (λw + 1)^(1/λ), or exp(w) when λ = 0.
+/// Values outside the range of the transform (λw + 1 < 0 with λ < 0) give NaN.
+pub fn inv_box_cox(w: f64, lambda: f64) -> f64 {
+    if lambda == 0.0 {
+        return w.exp();
+    }
+    let base = lambda * w + 1.0;
+    if lambda < 0.0 && base < 0.0 {
+        return f64::NAN;
+    }
+    base.signum() * base.abs().powf(1.0 / lambda)
+}
+
+/// Derivative of the inverse transform, (λw + 1)^(1/λ - 1) or exp(w) when λ = 0
+pub fn inv_box_cox_derivative(w: f64, lambda: f64) -> f64 {
+    if lambda == 0.0 {
+        w.exp()
+    } else {
+        (lambda * w + 1.0).abs().powf(1.0 / lambda - 1.0)
+    }
+}
+
+/// Back-transformed mean of a normal w with variance σ² (second-order Taylor expansion):
+/// (λw + 1)^(1/λ)·[1 + σ²(1 - λ)/(2(λw + 1)²)], or exp(w)·(1 + σ²/2) when λ = 0
+pub fn inv_box_cox_bias_adjusted(w: f64, variance: f64, lambda: f64) -> f64 {
+    let median = inv_box_cox(w, lambda);
+    if lambda == 0.0 {
+        median * (1.0 + variance / 2.0)
+    } else {
+        median * (1.0 + variance * (1.0 - lambda) / (2.0 * (lambda * w + 1.0).powi(2)))
+    }
+}
+
+#[wasm_bindgen]
+impl BoxCox {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, period: usize) -> BoxCox {
+        BoxCox {
+            data,
+            period,
+            lambda: f64::NAN,
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_period(&self) -> usize {
+        self.period
+    }
+    pub fn get_lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>) {
+        self.data = data;
+    }
+    pub fn set_period(&mut self, period: usize) {
+        self.period = period;
+    }
+    pub fn set_lambda(&mut self, lambda: f64) {
+        self.lambda = lambda;
+    }
+
+    /// The data on the transformed scale
+    pub fn transform(&self) -> Result<Vec<f64>, JsValue> {
+        self.check()?;
+        Ok(self.data.iter().map(|&x| box_cox_transform(x, self.lambda)).collect())
+    }
+
+    /// Values on the transformed scale mapped back to the data scale. With `bias_adjust`
+    /// the result is the mean rather than the median, using the matching entries of
+    /// `variance` (transformed-scale variances, e.g. squared forecast standard errors).
+    pub fn back_transform(&self, values: Vec<f64>, variance: Vec<f64>, bias_adjust: bool) -> Result<Vec<f64>, JsValue> {
+        self.check_lambda()?;
+        if bias_adjust && variance.len() != values.len() {
+            return Err(JsValue::from_str("values and variance must have the same length"));
+        }
+        Ok(values
+            .iter()
+            .enumerate()
+            .map(|(i, &w)| {
+                if bias_adjust {
+                    inv_box_cox_bias_adjusted(w, variance[i], self.lambda)
+                } else {
+                    inv_box_cox(w, self.lambda)
+                }
+            })
+            .collect())
+    }
+}
+
+// Internal helpers (not exposed to WASM)
+impl BoxCox {
+    /// Lambda is set and the data are positive
+    pub(crate) fn check(&self) -> Result<(), JsValue> {
+        self.check_lambda()?;
+        Self::check_positive(&self.data)
+    }
+
+    pub(crate) fn check_lambda(&self) -> Result<(), JsValue> {
+        if self.lambda.is_nan() {
+            return Err(JsValue::from_str("Lambda has not been set or estimated"));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn check_positive(data: &[f64]) -> Result<(), JsValue> {
+        if data.is_empty() || data.iter().any(|&x| x.is_nan() || x <= 0.0) {
+            return Err(JsValue::from_str("Box-Cox transformation needs positive data"));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use wasm_bindgen::prelude::*;
+use crate::BoxCox;
+use crate::time_series::box_cox::box_cox::{inv_box_cox, inv_box_cox_bias_adjusted, inv_box_cox_derivative};
+use crate::time_series::evaluation::cross_validation::model_forecast_result;
+use crate::time_series::helper_structs::ForecastResult;
+
+#[wasm_bindgen]
+impl BoxCox {
+    /// Fit a model (see model_forecast_result: "arima", "sma", "ses", "holt", "winter",
+    /// "additive", "multiplicative", ...) on the transformed scale and back-transform its
+    /// forecasts. The interval bounds are back-transformed directly; the point forecast is
+    /// the median, or the mean when `bias_adjust` is set. The standard errors are mapped
+    /// by the delta method.
+    pub fn forecast(&self, model: String, params: Vec<f64>, h: usize, level: f64, bias_adjust: bool) -> Result<ForecastResult, JsValue> {
+        let transformed = self.transform()?;
+        let result = model_forecast_result(&model, &params, &transformed, h, level)?;
+        let lambda = self.lambda;
+        let se_t = result.get_se();
+        let forecast = result
+            .get_forecast()
+            .iter()
+            .zip(se_t.iter())
+            .map(|(&w, &s)| {
+                if bias_adjust {
+                    inv_box_cox_bias_adjusted(w, s * s, lambda)
+                } else {
+                    inv_box_cox(w, lambda)
+                }
+            })
+            .collect();
+        let se = result
+            .get_forecast()
+            .iter()
+            .zip(se_t.iter())
+            .map(|(&w, &s)| s * inv_box_cox_derivative(w, lambda))
+            .collect();
+        let lower = result.get_lower().iter().map(|&w| inv_box_cox(w, lambda)).collect();
+        let upper = result.get_upper().iter().map(|&w| inv_box_cox(w, lambda)).collect();
+        Ok(ForecastResult::new(forecast, se, lower, upper, level))
+    }
+}
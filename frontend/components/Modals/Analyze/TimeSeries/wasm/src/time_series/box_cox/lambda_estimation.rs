@@ -0,0 +1,84 @@
+use wasm_bindgen::prelude::*;
+use crate::BoxCox;
+use crate::time_series::box_cox::box_cox::box_cox_transform;
+use crate::time_series::ecm::ols_helper::ols;
+use crate::time_series::optimization::golden_section_minimize;
+
+/// Guerrero's coefficient of variation of sd_i/mean_i^(1-λ) over the non-overlapping
+/// subseries of length `period` (at least 2), taken from the end of the data
+fn guerrero_cv(data: &[f64], period: usize, lambda: f64) -> f64 {
+    let period = period.max(2);
+    let n_groups = data.len() / period;
+    let start = data.len() - n_groups * period;
+    let ratios: Vec<f64> = data[start..]
+        .chunks(period)
+        .map(|group| {
+            let mean = group.iter().sum::<f64>() / period as f64;
+            let var = group.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (period as f64 - 1.0);
+            var.sqrt() / mean.powf(1.0 - lambda)
+        })
+        .collect();
+    let k = ratios.len() as f64;
+    let mean = ratios.iter().sum::<f64>() / k;
+    let sd = (ratios.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (k - 1.0)).sqrt();
+    sd / mean
+}
+
+/// Lambda in [lower, upper] minimizing Guerrero's coefficient of variation
+pub fn guerrero_lambda(data: &[f64], period: usize, lower: f64, upper: f64) -> f64 {
+    golden_section_minimize(|lambda| guerrero_cv(data, period, lambda), lower, upper)
+}
+
+/// Profile log-likelihood -n/2·ln(SSR) of the transformed series, scaled by the geometric
+/// mean ẋ^(λ-1) so the Jacobian is absorbed, regressed on a trend (and season dummies)
+fn box_cox_log_likelihood(data: &[f64], columns: &[Vec<f64>], lambda: f64) -> f64 {
+    let n = data.len() as f64;
+    let geometric_mean = (data.iter().map(|x| x.ln()).sum::<f64>() / n).exp();
+    let scale = geometric_mean.powf(lambda - 1.0);
+    let z: Vec<f64> = data.iter().map(|&x| box_cox_transform(x, lambda) / scale).collect();
+    match ols(columns, &z) {
+        Some(fit) => -n / 2.0 * fit.ssr.ln(),
+        None => f64::NEG_INFINITY,
+    }
+}
+
+/// Lambda in [lower, upper] maximizing the profile likelihood of a regression on
+/// an intercept, a linear trend and, when period > 1, seasonal dummies
+pub fn loglik_lambda(data: &[f64], period: usize, lower: f64, upper: f64) -> f64 {
+    let n = data.len();
+    let mut columns = vec![vec![1.0; n], (1..=n).map(|t| t as f64).collect::<Vec<f64>>()];
+    if period > 1 && n > 2 * period {
+        for s in 1..period {
+            columns.push((0..n).map(|t| if t % period == s { 1.0 } else { 0.0 }).collect());
+        }
+    }
+    golden_section_minimize(|lambda| -box_cox_log_likelihood(data, &columns, lambda), lower, upper)
+}
+
+#[wasm_bindgen]
+impl BoxCox {
+    /// Estimate lambda on [lower, upper] (usually [-1, 2]) by "guerrero" or "loglik"
+    /// and store it for transform, back_transform and forecast
+    pub fn estimate_lambda(&mut self, method: String, lower: f64, upper: f64) -> Result<f64, JsValue> {
+        Self::check_positive(&self.data)?;
+        if lower >= upper {
+            return Err(JsValue::from_str("lower must be below upper"));
+        }
+        self.lambda = match method.as_str() {
+            "guerrero" => {
+                if self.data.len() < 2 * self.period.max(2) {
+                    return Err(JsValue::from_str("Guerrero's method needs at least two full periods"));
+                }
+                guerrero_lambda(&self.data, self.period, lower, upper)
+            },
+            "loglik" => {
+                if self.data.len() < 3 {
+                    return Err(JsValue::from_str("Not enough observations"));
+                }
+                loglik_lambda(&self.data, self.period, lower, upper)
+            },
+            _ => panic!("Unknown method: {}", method),
+        };
+        Ok(self.lambda)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod box_cox;
+pub mod lambda_estimation;
+pub mod box_cox_forecast;
\ No newline at end of file
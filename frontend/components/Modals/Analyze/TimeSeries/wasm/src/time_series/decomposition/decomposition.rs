@@ -9,6 +9,9 @@ pub struct Decomposition {
     seasonal_indices: Vec<f64>,
     period: i32,
     trend_equation: String,
+    seasonal_periods: Vec<usize>,       // Periods of the MSTL seasonal components
+    multi_seasonal_component: Vec<f64>, // MSTL seasonal components, one series per period (flattened)
+    robustness_weights: Vec<f64>,       // Final STL robustness weights
 }
 
 #[wasm_bindgen]
@@ -23,6 +26,9 @@ impl Decomposition{
             seasonal_indices: Vec::new(),
             period,
             trend_equation: String::new(),
+            seasonal_periods: Vec::new(),
+            multi_seasonal_component: Vec::new(),
+            robustness_weights: Vec::new(),
         }
     }
 
@@ -48,6 +54,15 @@ impl Decomposition{
     pub fn get_trend_equation(&self) -> String{
         self.trend_equation.clone()
     }
+    pub fn get_seasonal_periods(&self) -> Vec<usize>{
+        self.seasonal_periods.clone()
+    }
+    pub fn get_multi_seasonal_component(&self) -> Vec<f64>{
+        self.multi_seasonal_component.clone()
+    }
+    pub fn get_robustness_weights(&self) -> Vec<f64>{
+        self.robustness_weights.clone()
+    }
 
     // Setters
     pub fn set_seasonal_component(&mut self, seasonal_component: Vec<f64>){
@@ -65,4 +80,13 @@ impl Decomposition{
     pub fn set_trend_equation(&mut self, trend_equation: String){
         self.trend_equation = trend_equation.clone();
     }
+    pub fn set_seasonal_periods(&mut self, seasonal_periods: Vec<usize>){
+        self.seasonal_periods = seasonal_periods;
+    }
+    pub fn set_multi_seasonal_component(&mut self, multi_seasonal_component: Vec<f64>){
+        self.multi_seasonal_component = multi_seasonal_component;
+    }
+    pub fn set_robustness_weights(&mut self, robustness_weights: Vec<f64>){
+        self.robustness_weights = robustness_weights;
+    }
 }
\ No newline at end of file
@@ -0,0 +1,98 @@
+use wasm_bindgen::prelude::*;
+use crate::{Decomposition, SimpleExponentialRegression, SimpleLinearRegression};
+use crate::time_series::helper_structs::ForecastResult;
+use crate::time_series::smoothing::smoothing_forecast::normal_forecast_result;
+use statrs::distribution::{ContinuousCDF, Normal};
+
+// Internal helpers (not exposed to WASM)
+impl Decomposition {
+    /// Seasonal index of observation t (0-based), continuing past the sample
+    fn seasonal_index_at(&self, t: usize) -> f64 {
+        let indices = self.get_seasonal_indices();
+        indices[t % indices.len()]
+    }
+
+    /// Prediction-variance factor 1 + 1/n + (t_h - t̄)²/Σ(t - t̄)² of a trend line fitted on t = 1..n
+    fn trend_variance_factor(n: usize, t_h: f64) -> f64 {
+        let mean_t = (n as f64 + 1.0) / 2.0;
+        let sxx: f64 = (1..=n).map(|t| (t as f64 - mean_t).powi(2)).sum();
+        1.0 + 1.0 / n as f64 + (t_h - mean_t).powi(2) / sxx
+    }
+}
+
+#[wasm_bindgen]
+impl Decomposition {
+    /// h-step forecast of the additive decomposition: a linear trend fitted to the seasonally
+    /// adjusted series, extrapolated and shifted by the seasonal index of each future period.
+    /// The intervals use the residual variance of the in-sample fit and the trend leverage.
+    pub fn forecast_additive(&mut self, h: usize, level: f64) -> ForecastResult {
+        let data = self.get_data();
+        let n = data.len();
+        self.additive_decomposition();
+        let t: Vec<f64> = (1..=n).map(|i| i as f64).collect();
+        let adjusted: Vec<f64> = (0..n).map(|i| data[i] - self.seasonal_index_at(i)).collect();
+        let mut regression = SimpleLinearRegression::new(t, adjusted);
+        regression.calculate_regression();
+        let (b0, b1) = (regression.get_b0(), regression.get_b1());
+
+        let sse: f64 = (0..n)
+            .map(|i| (data[i] - (b0 + b1 * (i as f64 + 1.0)) - self.seasonal_index_at(i)).powi(2))
+            .sum();
+        let sigma2 = sse / (n as f64 - 2.0);
+        let mut forecast = Vec::with_capacity(h);
+        let mut se = Vec::with_capacity(h);
+        for j in 0..h {
+            let t_h = (n + j + 1) as f64;
+            forecast.push(b0 + b1 * t_h + self.seasonal_index_at(n + j));
+            se.push((sigma2 * Self::trend_variance_factor(n, t_h)).sqrt());
+        }
+        normal_forecast_result(forecast, se, level)
+    }
+
+    /// h-step forecast of the multiplicative decomposition: the "linear" or "exponential" trend
+    /// equation extrapolated and multiplied by the seasonal index of each future period.
+    /// The intervals are built on the log scale, so they are asymmetric around the forecast.
+    pub fn forecast_multiplicative(&mut self, trend: String, h: usize, level: f64) -> ForecastResult {
+        let data = self.get_data();
+        let n = data.len();
+        self.multiplicative_decomposition(trend.clone());
+        let t: Vec<f64> = (1..=n).map(|i| i as f64).collect();
+        let adjusted: Vec<f64> = (0..n).map(|i| data[i] / self.seasonal_index_at(i)).collect();
+        let trend_at: Box<dyn Fn(f64) -> f64> = match trend.as_str() {
+            "linear" => {
+                let mut regression = SimpleLinearRegression::new(t, adjusted);
+                regression.calculate_regression();
+                let (b0, b1) = (regression.get_b0(), regression.get_b1());
+                Box::new(move |t: f64| b0 + b1 * t)
+            },
+            "exponential" => {
+                let mut regression = SimpleExponentialRegression::new(t, adjusted);
+                regression.calculate_regression();
+                let (b0, b1) = (regression.get_b0(), regression.get_b1());
+                Box::new(move |t: f64| (b0 + b1 * t).exp())
+            },
+            _ => panic!("Unknown trend: {}", trend),
+        };
+
+        // log(y_t / ŷ_t) as the irregular component on the log scale
+        let sse: f64 = (0..n)
+            .map(|i| (data[i] / (trend_at(i as f64 + 1.0) * self.seasonal_index_at(i))).ln().powi(2))
+            .sum();
+        let sigma2 = sse / (n as f64 - 2.0);
+        let z = Normal::new(0.0, 1.0).unwrap().inverse_cdf(1.0 - (1.0 - level) / 2.0);
+        let mut forecast = Vec::with_capacity(h);
+        let mut se = Vec::with_capacity(h);
+        let mut lower = Vec::with_capacity(h);
+        let mut upper = Vec::with_capacity(h);
+        for j in 0..h {
+            let t_h = (n + j + 1) as f64;
+            let point = trend_at(t_h) * self.seasonal_index_at(n + j);
+            let log_se = (sigma2 * Self::trend_variance_factor(n, t_h)).sqrt();
+            forecast.push(point);
+            se.push(point * log_se);
+            lower.push(point * (-z * log_se).exp());
+            upper.push(point * (z * log_se).exp());
+        }
+        ForecastResult::new(forecast, se, lower, upper, level)
+    }
+}
@@ -0,0 +1,50 @@
+/// Local regression of `y` (observed at positions 0..n) evaluated at position `xs`, which may
+/// lie outside the sample. Uses the `span` nearest points with tricube weights times the
+/// robustness `weights`, and a local constant (degree 0) or local line (degree 1).
+/// Returns None when every weight in the neighbourhood is zero.
+pub fn loess_at(y: &[f64], span: usize, degree: usize, weights: &[f64], xs: f64) -> Option<f64> {
+    let n = y.len();
+    let q = span.min(n);
+    let nleft = (xs - (q as f64 - 1.0) / 2.0).round().clamp(0.0, (n - q) as f64) as usize;
+    let nright = nleft + q - 1;
+    let mut h = (xs - nleft as f64).max(nright as f64 - xs);
+    if span > n {
+        h += ((span - n) / 2) as f64;
+    }
+
+    // Tricube weights, with a flat core near xs and a cut-off just inside h
+    let (h_low, h_high) = (0.001 * h, 0.999 * h);
+    let mut w = vec![0.0; q];
+    for (i, j) in (nleft..=nright).enumerate() {
+        let r = (j as f64 - xs).abs();
+        if r <= h_high {
+            w[i] = if r <= h_low { 1.0 } else { (1.0 - (r / h).powi(3)).powi(3) };
+            w[i] *= weights[j];
+        }
+    }
+    let total: f64 = w.iter().sum();
+    if total <= 0.0 {
+        return None;
+    }
+    w.iter_mut().for_each(|w_i| *w_i /= total);
+
+    if degree > 0 && h > 0.0 {
+        // Local line through the weighted mean of the positions
+        let a: f64 = (nleft..=nright).zip(w.iter()).map(|(j, w_j)| w_j * j as f64).sum();
+        let c: f64 = (nleft..=nright).zip(w.iter()).map(|(j, w_j)| w_j * (j as f64 - a).powi(2)).sum();
+        if c.sqrt() > 0.001 * (n as f64 - 1.0) {
+            let b = (xs - a) / c;
+            for (j, w_j) in (nleft..=nright).zip(w.iter_mut()) {
+                *w_j *= b * (j as f64 - a) + 1.0;
+            }
+        }
+    }
+    Some((nleft..=nright).zip(w.iter()).map(|(j, w_j)| w_j * y[j]).sum())
+}
+
+/// Loess fit at every observation; points with an empty neighbourhood keep their value
+pub fn loess_smooth(y: &[f64], span: usize, degree: usize, weights: &[f64]) -> Vec<f64> {
+    (0..y.len())
+        .map(|i| loess_at(y, span, degree, weights, i as f64).unwrap_or(y[i]))
+        .collect()
+}
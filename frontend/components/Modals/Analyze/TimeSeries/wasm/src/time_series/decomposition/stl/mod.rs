@@ -0,0 +1,3 @@
+pub mod loess;
+pub mod stl_fit;
+pub mod stl_decomposition;
\ No newline at end of file
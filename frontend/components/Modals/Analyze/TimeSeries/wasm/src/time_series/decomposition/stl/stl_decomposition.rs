@@ -0,0 +1,61 @@
+use wasm_bindgen::prelude::*;
+use crate::Decomposition;
+use crate::time_series::decomposition::stl::stl_fit::{mstl, stl};
+
+#[wasm_bindgen]
+impl Decomposition {
+    /// STL decomposition with the series period. `s_window` is the seasonal span (0 for a
+    /// periodic seasonal pattern), `t_window` the trend span (0 for the default) and `robust`
+    /// switches on the outer loop with robustness weights. Sets the trend, seasonal and
+    /// irregular (remainder) components, with the last cycle as the seasonal indices,
+    /// and returns the fitted values trend + seasonal.
+    pub fn stl_decomposition(&mut self, s_window: usize, t_window: usize, robust: bool) -> Result<Vec<f64>, JsValue> {
+        let data = self.get_data();
+        let period = self.get_period().max(0) as usize;
+        if period < 2 || data.len() < 2 * period {
+            return Err(JsValue::from_str("STL needs a period of at least 2 and two full cycles of data"));
+        }
+        let fit = stl(&data, period, s_window, t_window, robust);
+
+        // Seasonal index of each position in the cycle from the last full cycle
+        let n = data.len();
+        let mut seasonal_indices = vec![0.0; period];
+        for t in n - period..n {
+            seasonal_indices[t % period] = fit.seasonal[t];
+        }
+        let fitted = (0..n).map(|t| fit.trend[t] + fit.seasonal[t]).collect();
+        self.set_trend_component(fit.trend);
+        self.set_seasonal_component(fit.seasonal);
+        self.set_irregular_component(fit.remainder);
+        self.set_seasonal_indices(seasonal_indices);
+        self.set_seasonal_periods(vec![period]);
+        self.set_multi_seasonal_component(Vec::new());
+        self.set_robustness_weights(fit.weights);
+        Ok(fitted)
+    }
+
+    /// MSTL decomposition for several seasonal periods, each with its own seasonal span
+    /// (empty `s_windows` for the defaults 7 + 4i). The seasonal component is the sum of the
+    /// per-period components, which are available period by period (ascending, see
+    /// `get_seasonal_periods`) from `get_multi_seasonal_component`. Returns trend + seasonal.
+    pub fn mstl_decomposition(&mut self, periods: Vec<usize>, s_windows: Vec<usize>, t_window: usize, robust: bool) -> Result<Vec<f64>, JsValue> {
+        if !s_windows.is_empty() && s_windows.len() != periods.len() {
+            return Err(JsValue::from_str("s_windows must be empty or have one span per period"));
+        }
+        let data = self.get_data();
+        let n = data.len();
+        let fit = mstl(&data, &periods, &s_windows, t_window, robust);
+
+        let seasonal: Vec<f64> = (0..n).map(|t| fit.seasonals.iter().map(|s| s[t]).sum()).collect();
+        let fitted = (0..n).map(|t| fit.trend[t] + seasonal[t]).collect();
+        self.set_trend_component(fit.trend);
+        self.set_seasonal_component(seasonal);
+        self.set_irregular_component(fit.remainder);
+        // A single set of indices does not describe several overlapping cycles
+        self.set_seasonal_indices(Vec::new());
+        self.set_seasonal_periods(fit.periods);
+        self.set_multi_seasonal_component(fit.seasonals.concat());
+        self.set_robustness_weights(fit.weights);
+        Ok(fitted)
+    }
+}
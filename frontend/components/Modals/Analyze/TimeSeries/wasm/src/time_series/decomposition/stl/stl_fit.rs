@@ -0,0 +1,164 @@
+use crate::time_series::decomposition::stl::loess::{loess_at, loess_smooth};
+
+/// Components of an STL fit; `weights` are the robustness weights of the final pass
+/// (all ones without the robust mode)
+pub struct StlFit {
+    pub seasonal: Vec<f64>,
+    pub trend: Vec<f64>,
+    pub remainder: Vec<f64>,
+    pub weights: Vec<f64>,
+}
+
+/// Components of an MSTL fit, one seasonal series per period (ascending)
+pub struct MstlFit {
+    pub periods: Vec<usize>,
+    pub seasonals: Vec<Vec<f64>>,
+    pub trend: Vec<f64>,
+    pub remainder: Vec<f64>,
+    pub weights: Vec<f64>,
+}
+
+pub fn next_odd(x: usize) -> usize {
+    if x.is_multiple_of(2) { x + 1 } else { x }
+}
+
+/// Default trend window nextodd(⌈1.5·period / (1 - 1.5/s_window)⌉) of Cleveland et al. (1990)
+pub fn default_trend_window(period: usize, s_window: usize) -> usize {
+    next_odd((1.5 * period as f64 / (1.0 - 1.5 / s_window as f64)).ceil() as usize)
+}
+
+// Means of every run of `len` consecutive values (n - len + 1 values)
+fn running_mean(x: &[f64], len: usize) -> Vec<f64> {
+    x.windows(len).map(|w| w.iter().sum::<f64>() / len as f64).collect()
+}
+
+// Bisquare weights of the remainder scaled by six times its median absolute value
+fn robustness_weights(remainder: &[f64]) -> Vec<f64> {
+    let mut abs: Vec<f64> = remainder.iter().map(|r| r.abs()).collect();
+    abs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = abs.len();
+    let median = if n.is_multiple_of(2) { (abs[n / 2 - 1] + abs[n / 2]) / 2.0 } else { abs[n / 2] };
+    let h = 6.0 * median;
+    remainder
+        .iter()
+        .map(|r| {
+            let u = if h > 0.0 { r.abs() / h } else { 0.0 };
+            if u <= 0.001 {
+                1.0
+            } else if u <= 0.999 {
+                (1.0 - u * u).powi(2)
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+// Loess smoothing of every cycle-subseries, extended by one cycle at each end (n + 2·period values)
+fn smooth_cycle_subseries(detrended: &[f64], weights: &[f64], period: usize, s_span: usize, s_degree: usize) -> Vec<f64> {
+    let n = detrended.len();
+    let mut cycle = vec![0.0; n + 2 * period];
+    for j in 0..period.min(n) {
+        let ys: Vec<f64> = detrended.iter().skip(j).step_by(period).cloned().collect();
+        let ws: Vec<f64> = weights.iter().skip(j).step_by(period).cloned().collect();
+        let k = ys.len();
+        let smoothed: Vec<f64> = (0..k).map(|m| loess_at(&ys, s_span, s_degree, &ws, m as f64).unwrap_or(ys[m])).collect();
+        let before = loess_at(&ys, s_span, s_degree, &ws, -1.0).unwrap_or(smoothed[0]);
+        let after = loess_at(&ys, s_span, s_degree, &ws, k as f64).unwrap_or(smoothed[k - 1]);
+        cycle[j] = before;
+        for (m, value) in smoothed.into_iter().enumerate() {
+            cycle[j + (m + 1) * period] = value;
+        }
+        cycle[j + (k + 1) * period] = after;
+    }
+    cycle
+}
+
+/// STL decomposition (Cleveland et al., 1990) of a series with one seasonal period.
+/// `s_window` is the seasonal loess span (0 for a periodic, fixed seasonal pattern) and
+/// `t_window` the trend span (0 for the default). The robust mode runs 15 outer passes
+/// that downweight large remainders; otherwise two inner passes are made.
+pub fn stl(data: &[f64], period: usize, s_window: usize, t_window: usize, robust: bool) -> StlFit {
+    let n = data.len();
+    let periodic = s_window == 0;
+    let (s_span, s_degree) = if periodic { (10 * n + 1, 0) } else { (next_odd(s_window.max(3)), 0) };
+    let t_span = if t_window == 0 { default_trend_window(period, s_span) } else { next_odd(t_window) };
+    let l_span = next_odd(period);
+    let (inner, outer) = if robust { (1, 15) } else { (2, 0) };
+
+    let ones = vec![1.0; n];
+    let mut weights = ones.clone();
+    let mut trend = vec![0.0; n];
+    let mut seasonal = vec![0.0; n];
+    for pass in 0..=outer {
+        for _ in 0..inner {
+            let detrended: Vec<f64> = (0..n).map(|t| data[t] - trend[t]).collect();
+            let cycle = smooth_cycle_subseries(&detrended, &weights, period, s_span, s_degree);
+            // Low-pass filter: moving averages of length period, period and 3, then loess
+            let low_pass = running_mean(&running_mean(&running_mean(&cycle, period), period), 3);
+            let low_pass = loess_smooth(&low_pass, l_span, 1, &ones);
+            seasonal = (0..n).map(|t| cycle[period + t] - low_pass[t]).collect();
+            let deseasonalized: Vec<f64> = (0..n).map(|t| data[t] - seasonal[t]).collect();
+            trend = loess_smooth(&deseasonalized, t_span, 1, &weights);
+        }
+        if robust {
+            let remainder: Vec<f64> = (0..n).map(|t| data[t] - seasonal[t] - trend[t]).collect();
+            weights = robustness_weights(&remainder);
+        }
+        if pass == outer {
+            break;
+        }
+    }
+
+    if periodic {
+        // Replace the seasonal component by its cycle means
+        for j in 0..period.min(n) {
+            let positions: Vec<usize> = (j..n).step_by(period).collect();
+            let mean = positions.iter().map(|&t| seasonal[t]).sum::<f64>() / positions.len() as f64;
+            positions.iter().for_each(|&t| seasonal[t] = mean);
+        }
+    }
+    let remainder = (0..n).map(|t| data[t] - seasonal[t] - trend[t]).collect();
+    StlFit { seasonal, trend, remainder, weights }
+}
+
+/// MSTL decomposition (Bandara et al., 2021) for several seasonal periods: each seasonal
+/// component is re-estimated by STL on the series with the other components removed,
+/// shortest period first. Periods of at least half the series length are dropped.
+/// Empty `s_windows` uses the spans 7 + 4i.
+pub fn mstl(data: &[f64], periods: &[usize], s_windows: &[usize], t_window: usize, robust: bool) -> MstlFit {
+    let n = data.len();
+    let mut order: Vec<usize> = (0..periods.len()).filter(|&i| periods[i] >= 2 && 2 * periods[i] < n).collect();
+    order.sort_by_key(|&i| periods[i]);
+    let iterations = if order.len() > 1 { 2 } else { 1 };
+
+    let mut deseasonalized = data.to_vec();
+    let mut seasonals = vec![vec![0.0; n]; order.len()];
+    let mut last_fit = None;
+    for _ in 0..iterations {
+        for (i, &idx) in order.iter().enumerate() {
+            let s_window = if s_windows.is_empty() { 7 + 4 * i } else { s_windows[idx] };
+            (0..n).for_each(|t| deseasonalized[t] += seasonals[i][t]);
+            let fit = stl(&deseasonalized, periods[idx], s_window, t_window, robust);
+            (0..n).for_each(|t| deseasonalized[t] -= fit.seasonal[t]);
+            seasonals[i] = fit.seasonal.clone();
+            last_fit = Some(fit);
+        }
+    }
+    let (trend, weights) = match last_fit {
+        Some(fit) => (fit.trend, fit.weights),
+        None => {
+            // No usable period: trend only
+            let span = if t_window == 0 { next_odd(n / 2) } else { next_odd(t_window) };
+            (loess_smooth(data, span, 1, &vec![1.0; n]), vec![1.0; n])
+        },
+    };
+    let remainder = (0..n).map(|t| deseasonalized[t] - trend[t]).collect();
+    MstlFit {
+        periods: order.iter().map(|&i| periods[i]).collect(),
+        seasonals,
+        trend,
+        remainder,
+        weights,
+    }
+}
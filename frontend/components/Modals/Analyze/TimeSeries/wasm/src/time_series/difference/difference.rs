@@ -21,4 +21,16 @@ pub fn seasonal_difference(data: Vec<f64>, season: i32) -> Vec<f64>{
         seasonal_diff.push(data[i] - data[i-season as usize]);
     }
     seasonal_diff
-}   
\ No newline at end of file
+}   
+
+// Apply D seasonal differences followed by d ordinary differences
+pub fn difference_series(data: Vec<f64>, d: usize, seasonal_d: usize, season: usize) -> Vec<f64>{
+    let mut diff: Vec<f64> = data;
+    for _ in 0..seasonal_d{
+        diff = seasonal_difference(diff, season as i32);
+    }
+    for _ in 0..d{
+        diff = first_difference(diff);
+    }
+    diff
+}
@@ -1,4 +1,5 @@
 use wasm_bindgen::prelude::*;
+use crate::least_squares;
 
 // Helper struct for OLS result
 // NOTE: Vec<f64> fields can't be public in WASM structs - use getters
@@ -20,3 +21,27 @@ impl OLSResult {
         self.residuals.clone()
     }
 }
+
+// Least-squares fit of y on the columns of X (internal, no JS conversion needed)
+pub struct OlsFit {
+    pub beta: Vec<f64>,
+    pub se: Vec<f64>,
+    pub residuals: Vec<f64>,
+    pub ssr: f64,
+    pub sigma2: f64,             // SSR / (n - k)
+    pub xtx_inv: Vec<Vec<f64>>,  // (X'X)^-1
+}
+
+/// OLS estimates for y = Xβ + e, with X given as a list of columns (include a column
+/// of ones for the intercept), solved by QR. Returns None when X is rank deficient.
+pub fn ols(columns: &[Vec<f64>], y: &[f64]) -> Option<OlsFit> {
+    let fit = least_squares(columns, y)?;
+    if fit.rank < columns.len() {
+        return None;
+    }
+    let ssr = fit.residuals.iter().map(|e| e * e).sum::<f64>();
+    let df = (y.len() as f64 - columns.len() as f64).max(1.0);
+    let sigma2 = ssr / df;
+    let se = (0..columns.len()).map(|i| (sigma2 * fit.xtx_inv[i][i]).sqrt()).collect();
+    Some(OlsFit { beta: fit.beta, se, residuals: fit.residuals, ssr, sigma2, xtx_inv: fit.xtx_inv })
+}
\ No newline at end of file
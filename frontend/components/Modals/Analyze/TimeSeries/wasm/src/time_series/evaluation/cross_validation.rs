@@ -0,0 +1,132 @@
+use wasm_bindgen::prelude::*;
+use crate::{Arima, Decomposition, Smoothing};
+use crate::time_series::helper_structs::ForecastResult;
+use crate::time_series::smoothing::smoothing_forecast::normal_forecast_result;
+
+#[wasm_bindgen]
+pub struct CrossValidation {
+    pub(crate) data: Vec<f64>,
+    pub(crate) horizon: usize,  // Forecast steps at each origin
+    pub(crate) initial: usize,  // Training size at the first origin (window size when sliding)
+    pub(crate) step: usize,     // Observations between origins
+    pub(crate) window: String,  // "expanding" or "sliding"
+    pub(crate) period: usize,   // Seasonal period of the naive scaling in MASE and RMSSE (1: none)
+    pub(crate) origins: Vec<usize>,  // Training sizes, one per origin
+    pub(crate) forecasts: Vec<f64>,  // Flattened [origin][horizon]
+    pub(crate) errors: Vec<f64>,     // y - ŷ, flattened [origin][horizon]; NaN past the data
+}
+
+/// Forecasts with intervals h steps past `train` from one of the crate's models:
+/// "naive", "seasonal_naive" [period], "arima" [p, d, q] or [p, d, q, P, D, Q, s],
+/// "sma" [distance], "ses" [alpha], "holt" [alpha, beta], "winter" [alpha, beta, gamma, period],
+/// "additive" [period] and "multiplicative" [period] decomposition with a linear trend
+pub fn model_forecast_result(model: &str, params: &[f64], train: &[f64], h: usize, level: f64) -> Result<ForecastResult, JsValue> {
+    let needed = match model {
+        "naive" => 0,
+        "seasonal_naive" | "sma" | "ses" | "additive" | "multiplicative" => 1,
+        "holt" => 2,
+        "arima" => 3,
+        "winter" => 4,
+        _ => return Err(JsValue::from_str(&format!("Unknown model: {}", model))),
+    };
+    if params.len() < needed {
+        return Err(JsValue::from_str(&format!(
+            "Model {} needs {} parameters, got {}", model, needed, params.len()
+        )));
+    }
+    let n = train.len();
+    if n < 2 {
+        return Err(JsValue::from_str("The training window needs at least 2 observations"));
+    }
+    let result = match model {
+        "naive" | "seasonal_naive" => {
+            // Random walk on the (seasonal) lag m: Var(e_{n+h}) = σ²(⌊(h - 1)/m⌋ + 1)
+            let m = if model == "naive" { 1 } else { (params[0] as usize).clamp(1, n - 1) };
+            let diffs: Vec<f64> = (m..n).map(|t| train[t] - train[t - m]).collect();
+            let sigma2 = diffs.iter().map(|d| d * d).sum::<f64>() / diffs.len() as f64;
+            let forecast = (0..h).map(|j| train[n - m + j % m]).collect();
+            let se = (0..h).map(|j| (sigma2 * (j / m + 1) as f64).sqrt()).collect();
+            normal_forecast_result(forecast, se, level)
+        },
+        "arima" => {
+            let mut arima = Arima::new(train.to_vec(), params[0] as i32, params[1] as i32, params[2] as i32);
+            if params.len() >= 7 {
+                arima.set_seasonal_order(params[3] as i32, params[4] as i32, params[5] as i32, params[6] as i32)?;
+            }
+            arima.estimate_coef();
+            arima.forecast_ahead(h, level)?
+        },
+        "sma" => Smoothing::new(train.to_vec()).forecast_sma(params[0] as usize, h, level)?,
+        "ses" => Smoothing::new(train.to_vec()).forecast_ses(params[0], h, level),
+        "holt" => Smoothing::new(train.to_vec()).forecast_holt(params[0], params[1], h, level),
+        "winter" => Smoothing::new(train.to_vec()).forecast_winter(params[0], params[1], params[2], params[3] as usize, h, level)?,
+        "additive" => Decomposition::new(train.to_vec(), params[0] as i32).forecast_additive(h, level),
+        "multiplicative" => Decomposition::new(train.to_vec(), params[0] as i32).forecast_multiplicative("linear".to_string(), h, level),
+        _ => unreachable!(),
+    };
+    Ok(result)
+}
+
+/// Point forecasts of model_forecast_result
+pub fn model_forecast(model: &str, params: &[f64], train: &[f64], h: usize) -> Result<Vec<f64>, JsValue> {
+    Ok(model_forecast_result(model, params, train, h, 0.95)?.get_forecast())
+}
+
+#[wasm_bindgen]
+impl CrossValidation {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, horizon: usize, initial: usize, step: usize, window: String, period: usize) -> CrossValidation {
+        CrossValidation {
+            data,
+            horizon,
+            initial,
+            step,
+            window,
+            period,
+            origins: Vec::new(),
+            forecasts: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> { self.data.clone() }
+    pub fn get_horizon(&self) -> usize { self.horizon }
+    pub fn get_initial(&self) -> usize { self.initial }
+    pub fn get_step(&self) -> usize { self.step }
+    pub fn get_window(&self) -> String { self.window.clone() }
+    pub fn get_period(&self) -> usize { self.period }
+    pub fn get_origins(&self) -> Vec<usize> { self.origins.clone() }
+    pub fn get_forecasts(&self) -> Vec<f64> { self.forecasts.clone() }
+    pub fn get_errors(&self) -> Vec<f64> { self.errors.clone() }
+
+    /// Rolling-origin evaluation: the model (see model_forecast) is refitted on
+    /// data[..origin] (expanding) or on the last `initial` observations before the origin
+    /// (sliding) for origin = initial, initial + step, .. < n, and forecasts `horizon` steps.
+    /// Returns the errors flattened [origin][horizon].
+    pub fn run(&mut self, model: String, params: Vec<f64>) -> Result<Vec<f64>, JsValue> {
+        let n = self.data.len();
+        if self.initial < 2 || self.initial >= n {
+            return Err(JsValue::from_str(&format!(
+                "Initial training size must be between 2 and {}, got {}", n - 1, self.initial
+            )));
+        }
+        let step = self.step.max(1);
+        self.origins = (self.initial..n).step_by(step).collect();
+        self.forecasts = Vec::with_capacity(self.origins.len() * self.horizon);
+        self.errors = Vec::with_capacity(self.origins.len() * self.horizon);
+        for &origin in self.origins.iter() {
+            let start = match self.window.as_str() {
+                "expanding" => 0,
+                "sliding" => origin - self.initial,
+                _ => return Err(JsValue::from_str(&format!("Unknown window: {}", self.window))),
+            };
+            let forecast = model_forecast(&model, &params, &self.data[start..origin], self.horizon)?;
+            for (j, f) in forecast.iter().enumerate() {
+                self.forecasts.push(*f);
+                self.errors.push(if origin + j < n { self.data[origin + j] - f } else { f64::NAN });
+            }
+        }
+        Ok(self.errors.clone())
+    }
+}
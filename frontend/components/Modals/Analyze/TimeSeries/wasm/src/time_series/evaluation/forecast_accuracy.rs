@@ -0,0 +1,123 @@
+use wasm_bindgen::prelude::*;
+use crate::time_series::evaluation::cross_validation::CrossValidation;
+use statrs::distribution::{ContinuousCDF, StudentsT};
+
+/// Diebold-Mariano test of equal accuracy for h-step forecast errors e1 and e2 with loss
+/// |e|^power. The loss differential's long-run variance uses autocovariances to lag h - 1,
+/// and the statistic carries the Harvey-Leybourne-Newbold small-sample correction.
+/// Pairs with a NaN are dropped. Returns [statistic, two-sided p-value from t(T - 1), T].
+pub fn diebold_mariano(e1: &[f64], e2: &[f64], h: usize, power: f64) -> Vec<f64> {
+    let d: Vec<f64> = e1
+        .iter()
+        .zip(e2.iter())
+        .filter(|(a, b)| !a.is_nan() && !b.is_nan())
+        .map(|(a, b)| a.abs().powf(power) - b.abs().powf(power))
+        .collect();
+    let t = d.len();
+    if t < 2 {
+        return vec![f64::NAN, f64::NAN, t as f64];
+    }
+    let t_f = t as f64;
+    let mean = d.iter().sum::<f64>() / t_f;
+    let autocov = |k: usize| (k..t).map(|i| (d[i] - mean) * (d[i - k] - mean)).sum::<f64>() / t_f;
+    let mut long_run = autocov(0);
+    for k in 1..h.min(t) {
+        long_run += 2.0 * autocov(k);
+    }
+    if long_run <= 0.0 {
+        // Truncated long-run variance can turn negative; fall back to the variance
+        long_run = autocov(0);
+    }
+    let h_f = h as f64;
+    let correction = ((t_f + 1.0 - 2.0 * h_f + h_f * (h_f - 1.0) / t_f) / t_f).sqrt();
+    let statistic = correction * mean / (long_run / t_f).sqrt();
+    let dist = StudentsT::new(0.0, 1.0, t_f - 1.0).unwrap();
+    let p_value = 2.0 * (1.0 - dist.cdf(statistic.abs()));
+    vec![statistic, p_value, t_f]
+}
+
+// Internal helpers (not exposed to WASM)
+impl CrossValidation {
+    /// First index of the training sample behind origin number i
+    fn training_start(&self, i: usize) -> usize {
+        if self.window == "sliding" { self.origins[i] - self.initial } else { 0 }
+    }
+
+    /// In-sample (seasonal) naive mean absolute and mean squared one-step errors of the
+    /// training sample behind origin number i; NaN when they are zero (no scale to divide by)
+    fn naive_scale(&self, i: usize) -> (f64, f64) {
+        let train = &self.data[self.training_start(i)..self.origins[i]];
+        let m = self.period.max(1).min(train.len() - 1);
+        let diffs: Vec<f64> = (m..train.len()).map(|t| train[t] - train[t - m]).collect();
+        let count = diffs.len() as f64;
+        let positive = |v: f64| if v > 0.0 { v } else { f64::NAN };
+        (
+            positive(diffs.iter().map(|d| d.abs()).sum::<f64>() / count),
+            positive(diffs.iter().map(|d| d * d).sum::<f64>() / count),
+        )
+    }
+
+    /// Mean of f(origin number, error, forecast) over the origins with an error at step j
+    fn horizon_mean(&self, f: &dyn Fn(usize, f64, f64) -> f64) -> Vec<f64> {
+        (0..self.horizon)
+            .map(|j| {
+                let values: Vec<f64> = (0..self.origins.len())
+                    .map(|i| (i, self.errors[i * self.horizon + j], self.forecasts[i * self.horizon + j]))
+                    .filter(|(_, e, _)| !e.is_nan())
+                    .map(|(i, e, fc)| f(i, e, fc))
+                    .collect();
+                if values.is_empty() { f64::NAN } else { values.iter().sum::<f64>() / values.len() as f64 }
+            })
+            .collect()
+    }
+
+    /// Errors at step j (1-based) across origins
+    pub fn horizon_errors(&self, j: usize) -> Vec<f64> {
+        (0..self.origins.len()).map(|i| self.errors[i * self.horizon + j - 1]).collect()
+    }
+}
+
+#[wasm_bindgen]
+impl CrossValidation {
+    /// Root mean squared error at each horizon 1..h
+    pub fn rmse_by_horizon(&self) -> Vec<f64> {
+        self.horizon_mean(&|_, e, _| e * e).iter().map(|v| v.sqrt()).collect()
+    }
+
+    /// Mean absolute error at each horizon 1..h
+    pub fn mae_by_horizon(&self) -> Vec<f64> {
+        self.horizon_mean(&|_, e, _| e.abs())
+    }
+
+    /// MASE: |e| scaled by the training sample's in-sample (seasonal) naive MAE at each origin;
+    /// NaN at a horizon whose origins include a training sample with a zero naive MAE
+    pub fn mase_by_horizon(&self) -> Vec<f64> {
+        let scales: Vec<f64> = (0..self.origins.len()).map(|i| self.naive_scale(i).0).collect();
+        self.horizon_mean(&|i, e, _| e.abs() / scales[i])
+    }
+
+    /// RMSSE: square root of the mean of e² scaled by the training sample's in-sample
+    /// (seasonal) naive mean squared error at each origin (NaN as for MASE)
+    pub fn rmsse_by_horizon(&self) -> Vec<f64> {
+        let scales: Vec<f64> = (0..self.origins.len()).map(|i| self.naive_scale(i).1).collect();
+        self.horizon_mean(&|i, e, _| e * e / scales[i]).iter().map(|v| v.sqrt()).collect()
+    }
+
+    /// sMAPE (%): 200·|e| / (|y| + |ŷ|) with y = ŷ + e
+    pub fn smape_by_horizon(&self) -> Vec<f64> {
+        self.horizon_mean(&|_, e, f| {
+            let denominator = (f + e).abs() + f.abs();
+            if denominator > 0.0 { 200.0 * e.abs() / denominator } else { 0.0 }
+        })
+    }
+
+    /// Diebold-Mariano test of this model's step-`horizon` errors against another
+    /// cross-validation run over the same origins (see diebold_mariano).
+    /// A negative statistic favours this model.
+    pub fn diebold_mariano_test(&self, other: &CrossValidation, horizon: usize, power: f64) -> Result<Vec<f64>, JsValue> {
+        if self.origins != other.origins || horizon == 0 || horizon > self.horizon.min(other.horizon) {
+            return Err(JsValue::from_str("Both runs need the same origins and the horizon within both"));
+        }
+        Ok(diebold_mariano(&self.horizon_errors(horizon), &other.horizon_errors(horizon), horizon, power))
+    }
+}
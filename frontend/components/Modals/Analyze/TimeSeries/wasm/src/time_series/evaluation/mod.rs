@@ -1 +1,3 @@
-pub mod basic_evaluation;
\ No newline at end of file
+pub mod basic_evaluation;
+pub mod cross_validation;
+pub mod forecast_accuracy;
\ No newline at end of file
@@ -0,0 +1,84 @@
+use wasm_bindgen::prelude::*;
+use crate::CycleFilter;
+use std::f64::consts::PI;
+
+/// Ideal band-pass weights B_0 = (b - a)/π, B_j = (sin jb - sin ja)/(πj) for j = 0..=k,
+/// passing periods between low and high observations (a = 2π/high, b = 2π/low)
+pub fn ideal_band_pass_weights(low: f64, high: f64, k: usize) -> Vec<f64> {
+    let a = 2.0 * PI / high;
+    let b = 2.0 * PI / low;
+    (0..=k)
+        .map(|j| {
+            if j == 0 {
+                (b - a) / PI
+            } else {
+                let j = j as f64;
+                ((j * b).sin() - (j * a).sin()) / (PI * j)
+            }
+        })
+        .collect()
+}
+
+#[wasm_bindgen]
+impl CycleFilter {
+    /// Baxter-King band-pass filter: symmetric moving average of order 2K + 1 with the
+    /// ideal weights truncated at lag K and shifted to sum to zero. low, high <= 0
+    /// select the default band and k = 0 selects three years of observations. The first
+    /// and last K values of the trend and cycle are NaN. Returns the cycle.
+    pub fn baxter_king(&mut self, low: f64, high: f64, k: usize) -> Result<Vec<f64>, JsValue> {
+        let [low, high] = self.band(low, high)?;
+        let k = if k > 0 { k } else { 3 * self.frequency.max(1) };
+        self.check_data(2 * k + 1)?;
+        let mut weights = ideal_band_pass_weights(low, high, k);
+        let theta = -(weights[0] + 2.0 * weights[1..].iter().sum::<f64>()) / (2 * k + 1) as f64;
+        weights.iter_mut().for_each(|w| *w += theta);
+
+        let n = self.data.len();
+        let cycle: Vec<f64> = (0..n)
+            .map(|t| {
+                if t < k || t + k >= n {
+                    return f64::NAN;
+                }
+                weights[0] * self.data[t]
+                    + (1..=k).map(|j| weights[j] * (self.data[t - j] + self.data[t + j])).sum::<f64>()
+            })
+            .collect();
+        self.set_cycle(cycle.clone());
+        Ok(cycle)
+    }
+
+    /// Christiano-Fitzgerald asymmetric band-pass filter, optimal for a random walk.
+    /// Each cycle value uses the whole sample: y_s gets weight B_|s-t| and the end
+    /// points absorb the remaining weight so the filter sums to zero (removing the unit
+    /// root). With `drift` the line through the first and last observations is removed
+    /// first. low, high <= 0 select the default band. Returns the cycle.
+    pub fn christiano_fitzgerald(&mut self, low: f64, high: f64, drift: bool) -> Result<Vec<f64>, JsValue> {
+        let [low, high] = self.band(low, high)?;
+        self.check_data(3)?;
+        let n = self.data.len();
+        let slope = if drift { (self.data[n - 1] - self.data[0]) / (n - 1) as f64 } else { 0.0 };
+        let y: Vec<f64> = self.data.iter().enumerate().map(|(t, v)| v - slope * t as f64).collect();
+        let b = ideal_band_pass_weights(low, high, n - 1);
+
+        let cycle: Vec<f64> = (0..n)
+            .map(|t| {
+                let mut weights: Vec<f64> = (0..n).map(|s| b[s.abs_diff(t)]).collect();
+                // Remaining weight on each end point: -B_0/2 - Σ B_j over the interior
+                if t < n - 1 {
+                    weights[n - 1] = -0.5 * b[0] - (1..n - 1 - t).map(|j| b[j]).sum::<f64>();
+                }
+                if t > 0 {
+                    weights[0] = -0.5 * b[0] - (1..t).map(|j| b[j]).sum::<f64>();
+                }
+                // At an end point the own weight makes the total zero
+                if t == 0 || t == n - 1 {
+                    weights[t] = 0.0;
+                    weights[t] = -weights.iter().sum::<f64>();
+                }
+                weights.iter().zip(y.iter()).map(|(w, v)| w * v).sum::<f64>()
+            })
+            .collect();
+        self.set_cycle(cycle.clone());
+        Ok(cycle)
+    }
+}
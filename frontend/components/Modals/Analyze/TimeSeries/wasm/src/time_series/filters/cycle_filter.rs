@@ -0,0 +1,87 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct CycleFilter {
+    pub(crate) data: Vec<f64>,
+    pub(crate) frequency: usize, // Observations per year (1 annual, 4 quarterly, 12 monthly)
+    pub(crate) trend: Vec<f64>,  // NaN where the filter is undefined (Baxter-King ends)
+    pub(crate) cycle: Vec<f64>,
+}
+
+/// Ravn-Uhlig HP smoothing parameter 1600·(frequency/4)^4: 6.25 annual, 1600 quarterly,
+/// 129600 monthly
+pub fn hp_default_lambda(frequency: usize) -> f64 {
+    1600.0 * (frequency.max(1) as f64 / 4.0).powi(4)
+}
+
+/// Burns-Mitchell business-cycle band of 1.5 to 8 years, as [low, high] periods in
+/// observations (low at least 2)
+pub fn default_band(frequency: usize) -> [f64; 2] {
+    let frequency = frequency.max(1) as f64;
+    [(1.5 * frequency).max(2.0), 8.0 * frequency]
+}
+
+#[wasm_bindgen]
+impl CycleFilter {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, frequency: usize) -> CycleFilter {
+        CycleFilter {
+            data,
+            frequency,
+            trend: Vec::new(),
+            cycle: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_frequency(&self) -> usize {
+        self.frequency
+    }
+    pub fn get_trend(&self) -> Vec<f64> {
+        self.trend.clone()
+    }
+    pub fn get_cycle(&self) -> Vec<f64> {
+        self.cycle.clone()
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>) {
+        self.data = data;
+    }
+    pub fn set_frequency(&mut self, frequency: usize) {
+        self.frequency = frequency;
+    }
+}
+
+// Internal helpers (not exposed to WASM)
+impl CycleFilter {
+    pub fn check_data(&self, min_len: usize) -> Result<(), JsValue> {
+        if self.data.iter().any(|v| !v.is_finite()) {
+            return Err(JsValue::from_str("Data must not contain missing values"));
+        }
+        if self.data.len() < min_len {
+            return Err(JsValue::from_str(&format!("The filter needs at least {} observations", min_len)));
+        }
+        Ok(())
+    }
+
+    /// Store the cycle and the trend data - cycle
+    pub fn set_cycle(&mut self, cycle: Vec<f64>) {
+        self.trend = self.data.iter().zip(cycle.iter()).map(|(y, c)| y - c).collect();
+        self.cycle = cycle;
+    }
+
+    /// Band [low, high] in observations; values <= 0 select default_band
+    pub fn band(&self, low: f64, high: f64) -> Result<[f64; 2], JsValue> {
+        let [default_low, default_high] = default_band(self.frequency);
+        let low = if low > 0.0 { low } else { default_low };
+        let high = if high > 0.0 { high } else { default_high };
+        if low < 2.0 || high <= low {
+            return Err(JsValue::from_str("The band needs 2 <= low < high"));
+        }
+        Ok([low, high])
+    }
+}
@@ -0,0 +1,87 @@
+use wasm_bindgen::prelude::*;
+use crate::{hp_default_lambda, CycleFilter};
+
+/// Hodrick-Prescott trend: the τ minimizing Σ(y_t - τ_t)² + λΣ(Δ²τ_t)², i.e. the
+/// solution of (I + λD'D)τ = y with D the second-difference matrix. The system is
+/// pentadiagonal and is solved by a banded Cholesky factorization in O(n).
+pub fn hp_trend(data: &[f64], lambda: f64) -> Vec<f64> {
+    let n = data.len();
+    // Bands of I + λD'D: diag[i], off1[i] = A[i][i+1], off2[i] = A[i][i+2]
+    let mut diag = vec![1.0; n];
+    let mut off1 = vec![0.0; n];
+    let mut off2 = vec![0.0; n];
+    for t in 2..n {
+        // Row (1, -2, 1) of D on positions t-2, t-1, t
+        let idx = [t - 2, t - 1, t];
+        let coef = [1.0, -2.0, 1.0];
+        for a in 0..3 {
+            diag[idx[a]] += lambda * coef[a] * coef[a];
+        }
+        off1[t - 2] += lambda * coef[0] * coef[1];
+        off1[t - 1] += lambda * coef[1] * coef[2];
+        off2[t - 2] += lambda * coef[0] * coef[2];
+    }
+
+    // A = LL' with L lower triangular of bandwidth 2
+    let mut l0 = vec![0.0; n]; // L[i][i]
+    let mut l1 = vec![0.0; n]; // L[i][i-1]
+    let mut l2 = vec![0.0; n]; // L[i][i-2]
+    for i in 0..n {
+        if i >= 2 {
+            l2[i] = off2[i - 2] / l0[i - 2];
+        }
+        if i >= 1 {
+            let prior = if i >= 2 { l2[i] * l1[i - 1] } else { 0.0 };
+            l1[i] = (off1[i - 1] - prior) / l0[i - 1];
+        }
+        l0[i] = (diag[i] - l1[i] * l1[i] - l2[i] * l2[i]).sqrt();
+    }
+    let mut z = vec![0.0; n];
+    for i in 0..n {
+        let mut v = data[i];
+        if i >= 1 {
+            v -= l1[i] * z[i - 1];
+        }
+        if i >= 2 {
+            v -= l2[i] * z[i - 2];
+        }
+        z[i] = v / l0[i];
+    }
+    let mut trend = vec![0.0; n];
+    for i in (0..n).rev() {
+        let mut v = z[i];
+        if i + 1 < n {
+            v -= l1[i + 1] * trend[i + 1];
+        }
+        if i + 2 < n {
+            v -= l2[i + 2] * trend[i + 2];
+        }
+        trend[i] = v / l0[i];
+    }
+    trend
+}
+
+/// One-sided HP trend: τ_t is the end point of the two-sided filter on y_1..y_t, so it
+/// uses no future observations (the Kalman-filtered trend of the HP state-space model)
+pub fn hp_trend_one_sided(data: &[f64], lambda: f64) -> Vec<f64> {
+    (1..=data.len()).map(|t| hp_trend(&data[..t], lambda)[t - 1]).collect()
+}
+
+#[wasm_bindgen]
+impl CycleFilter {
+    /// Hodrick-Prescott filter; lambda <= 0 selects hp_default_lambda(frequency).
+    /// `one_sided` uses only past and current observations for each trend value.
+    /// Stores the trend and cycle and returns the cycle.
+    pub fn hp_filter(&mut self, lambda: f64, one_sided: bool) -> Result<Vec<f64>, JsValue> {
+        self.check_data(3)?;
+        let lambda = if lambda > 0.0 { lambda } else { hp_default_lambda(self.frequency) };
+        let trend = if one_sided {
+            hp_trend_one_sided(&self.data, lambda)
+        } else {
+            hp_trend(&self.data, lambda)
+        };
+        let cycle: Vec<f64> = self.data.iter().zip(trend.iter()).map(|(y, t)| y - t).collect();
+        self.set_cycle(cycle.clone());
+        Ok(cycle)
+    }
+}
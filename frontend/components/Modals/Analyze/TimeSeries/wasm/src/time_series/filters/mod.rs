@@ -0,0 +1,3 @@
+pub mod cycle_filter;
+pub mod hp_filter;
+pub mod band_pass;
\ No newline at end of file
@@ -1,4 +1,5 @@
 use wasm_bindgen::prelude::*;
+use crate::time_series::garch::garch_filter::GarchSpec;
 #[wasm_bindgen]
 pub struct GARCH {
     pub(crate) data: Vec<f64>,      // Returns
@@ -11,6 +12,17 @@ pub struct GARCH {
     aic: f64,
     bic: f64,
     log_likelihood: f64,
+    variance_model: String,       // "garch", "egarch" or "tgarch" for `fit`
+    distribution: String,         // "normal", "t", "skew_t" or "ged"
+    ar_order: usize,              // AR order of the mean equation
+    ma_order: usize,              // MA order of the mean equation
+    garch_in_mean: bool,          // λσ_t in the mean equation
+    gamma: Vec<f64>,              // Asymmetry coefficients (EGARCH, TGARCH)
+    shape: Vec<f64>,              // Distribution shape: ν (t, GED) or [ν, ξ] (skew-t)
+    coefficients: Vec<f64>,       // [μ, φ, θ, λ, ω, α, γ, β, shape]
+    std_errors: Vec<f64>,         // From the inverse Hessian
+    robust_std_errors: Vec<f64>,  // Bollerslev-Wooldridge sandwich
+    residuals: Vec<f64>,
 }
 #[wasm_bindgen]
 impl GARCH {
@@ -27,6 +39,17 @@ impl GARCH {
             aic: 0.0,
             bic: 0.0,
             log_likelihood: 0.0,
+            variance_model: "garch".to_string(),
+            distribution: "normal".to_string(),
+            ar_order: 0,
+            ma_order: 0,
+            garch_in_mean: false,
+            gamma: Vec::new(),
+            shape: Vec::new(),
+            coefficients: Vec::new(),
+            std_errors: Vec::new(),
+            robust_std_errors: Vec::new(),
+            residuals: Vec::new(),
         }
     }
     // Getters
@@ -40,6 +63,17 @@ impl GARCH {
     pub fn get_aic(&self) -> f64 { self.aic }
     pub fn get_bic(&self) -> f64 { self.bic }
     pub fn get_log_likelihood(&self) -> f64 { self.log_likelihood }
+    pub fn get_variance_model(&self) -> String { self.variance_model.clone() }
+    pub fn get_distribution(&self) -> String { self.distribution.clone() }
+    pub fn get_ar_order(&self) -> usize { self.ar_order }
+    pub fn get_ma_order(&self) -> usize { self.ma_order }
+    pub fn get_garch_in_mean(&self) -> bool { self.garch_in_mean }
+    pub fn get_gamma(&self) -> Vec<f64> { self.gamma.clone() }
+    pub fn get_shape(&self) -> Vec<f64> { self.shape.clone() }
+    pub fn get_coefficients(&self) -> Vec<f64> { self.coefficients.clone() }
+    pub fn get_std_errors(&self) -> Vec<f64> { self.std_errors.clone() }
+    pub fn get_robust_std_errors(&self) -> Vec<f64> { self.robust_std_errors.clone() }
+    pub fn get_residuals(&self) -> Vec<f64> { self.residuals.clone() }
     // Setters
     pub fn set_omega(&mut self, omega: f64) { self.omega = omega; }
     pub fn set_alpha(&mut self, alpha: Vec<f64>) { self.alpha = alpha; }
@@ -48,4 +82,44 @@ impl GARCH {
     pub fn set_aic(&mut self, aic: f64) { self.aic = aic; }
     pub fn set_bic(&mut self, bic: f64) { self.bic = bic; }
     pub fn set_log_likelihood(&mut self, ll: f64) { self.log_likelihood = ll; }
+    // Specification setters change the parameter layout, so they discard the fitted coefficients
+    pub fn set_variance_model(&mut self, variance_model: String) {
+        self.variance_model = variance_model;
+        self.coefficients.clear();
+    }
+    pub fn set_distribution(&mut self, distribution: String) {
+        self.distribution = distribution;
+        self.coefficients.clear();
+    }
+    pub fn set_mean_order(&mut self, ar_order: usize, ma_order: usize) {
+        self.ar_order = ar_order;
+        self.ma_order = ma_order;
+        self.coefficients.clear();
+    }
+    pub fn set_garch_in_mean(&mut self, garch_in_mean: bool) {
+        self.garch_in_mean = garch_in_mean;
+        self.coefficients.clear();
+    }
+    pub fn set_gamma(&mut self, gamma: Vec<f64>) { self.gamma = gamma; }
+    pub fn set_shape(&mut self, shape: Vec<f64>) { self.shape = shape; }
+    pub fn set_coefficients(&mut self, coefficients: Vec<f64>) { self.coefficients = coefficients; }
+    pub fn set_std_errors(&mut self, std_errors: Vec<f64>) { self.std_errors = std_errors; }
+    pub fn set_robust_std_errors(&mut self, robust_std_errors: Vec<f64>) { self.robust_std_errors = robust_std_errors; }
+    pub fn set_residuals(&mut self, residuals: Vec<f64>) { self.residuals = residuals; }
+}
+
+// Internal helpers (not exposed to WASM)
+impl GARCH {
+    /// Model specification from the configured variance model, distribution and mean equation
+    pub fn spec(&self) -> GarchSpec {
+        GarchSpec {
+            variance_model: self.variance_model.clone(),
+            distribution: self.distribution.clone(),
+            p: self.p,
+            q: self.q,
+            ar: self.ar_order,
+            ma: self.ma_order,
+            in_mean: self.garch_in_mean,
+        }
+    }
 }
\ No newline at end of file
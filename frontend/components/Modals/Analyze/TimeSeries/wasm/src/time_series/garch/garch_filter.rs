@@ -0,0 +1,157 @@
+use crate::time_series::garch::innovation::{innovation_log_density, n_shape_params};
+
+/// Mean and variance equations of an ARMA-GARCH(-M) model
+#[derive(Clone)]
+pub struct GarchSpec {
+    pub variance_model: String, // "garch", "egarch" or "tgarch"
+    pub distribution: String,   // "normal", "t", "skew_t" or "ged"
+    pub p: usize,               // GARCH order
+    pub q: usize,               // ARCH order
+    pub ar: usize,              // AR order of the mean equation
+    pub ma: usize,              // MA order of the mean equation
+    pub in_mean: bool,          // GARCH-in-mean term λσ_t
+}
+
+/// Parameters in the layout [μ, φ, θ, λ, ω, α, γ, β, shape] (λ only with GARCH-M,
+/// γ only for EGARCH and TGARCH)
+#[derive(Clone, Debug)]
+pub struct GarchParams {
+    pub mu: f64,
+    pub ar: Vec<f64>,
+    pub ma: Vec<f64>,
+    pub lambda: f64,
+    pub omega: f64,
+    pub alpha: Vec<f64>,
+    pub gamma: Vec<f64>,
+    pub beta: Vec<f64>,
+    pub shape: Vec<f64>,
+}
+
+/// Residuals ε_t, conditional variances σ²_t and log-likelihood contributions
+pub struct GarchPath {
+    pub residuals: Vec<f64>,
+    pub variance: Vec<f64>,
+    pub loglik_terms: Vec<f64>, // From observation `start` on
+    pub start: usize,
+}
+
+impl GarchSpec {
+    pub fn has_gamma(&self) -> bool {
+        self.variance_model == "egarch" || self.variance_model == "tgarch"
+    }
+
+    pub fn n_params(&self) -> usize {
+        1 + self.ar + self.ma + usize::from(self.in_mean) + 1 + self.q * (1 + usize::from(self.has_gamma())) + self.p
+            + n_shape_params(&self.distribution)
+    }
+
+    /// Index of ω in the parameter vector
+    pub fn omega_index(&self) -> usize {
+        1 + self.ar + self.ma + usize::from(self.in_mean)
+    }
+
+    /// Index of β_1 in the parameter vector
+    pub fn beta_index(&self) -> usize {
+        self.omega_index() + 1 + self.q * (1 + usize::from(self.has_gamma()))
+    }
+
+    pub fn unpack(&self, theta: &[f64]) -> GarchParams {
+        let mut idx = 0;
+        let mut take = |len: usize| {
+            idx += len;
+            theta[idx - len..idx].to_vec()
+        };
+        let mu = take(1)[0];
+        let ar = take(self.ar);
+        let ma = take(self.ma);
+        let lambda = if self.in_mean { take(1)[0] } else { 0.0 };
+        let omega = take(1)[0];
+        let alpha = take(self.q);
+        let gamma = if self.has_gamma() { take(self.q) } else { Vec::new() };
+        let beta = take(self.p);
+        let shape = take(n_shape_params(&self.distribution));
+        GarchParams { mu, ar, ma, lambda, omega, alpha, gamma, beta, shape }
+    }
+
+    pub fn pack(&self, params: &GarchParams) -> Vec<f64> {
+        let mut theta = vec![params.mu];
+        theta.extend_from_slice(&params.ar);
+        theta.extend_from_slice(&params.ma);
+        if self.in_mean {
+            theta.push(params.lambda);
+        }
+        theta.push(params.omega);
+        theta.extend_from_slice(&params.alpha);
+        theta.extend_from_slice(&params.gamma);
+        theta.extend_from_slice(&params.beta);
+        theta.extend_from_slice(&params.shape);
+        theta
+    }
+}
+
+/// Conditional variance at t from the past residuals and variances. Values before the
+/// first modelled observation are replaced by the presample variance.
+pub fn next_variance(spec: &GarchSpec, params: &GarchParams, residuals: &[f64], variance: &[f64], t: usize, start: usize, presample: f64) -> f64 {
+    let eps = |s: usize| if s >= start { residuals[s] } else { 0.0 };
+    let eps2 = |s: usize| if s >= start { residuals[s] * residuals[s] } else { presample };
+    let var = |s: usize| if s >= start { variance[s] } else { presample };
+    let back = |lag: usize| t.checked_sub(lag);
+    match spec.variance_model.as_str() {
+        "garch" | "tgarch" => {
+            let mut value = params.omega;
+            for (i, &a) in params.alpha.iter().enumerate() {
+                let (e, e2) = back(i + 1).map_or((0.0, presample), |s| (eps(s), eps2(s)));
+                let leverage = if spec.has_gamma() && e < 0.0 { params.gamma[i] } else { 0.0 };
+                value += (a + leverage) * e2;
+            }
+            for (j, &b) in params.beta.iter().enumerate() {
+                value += b * back(j + 1).map_or(presample, var);
+            }
+            value
+        },
+        "egarch" => {
+            let mut value = params.omega;
+            for i in 0..params.alpha.len() {
+                if let Some(s) = back(i + 1) {
+                    let z = eps(s) / var(s).sqrt();
+                    value += params.alpha[i] * z.abs() + params.gamma[i] * z;
+                }
+            }
+            for (j, &b) in params.beta.iter().enumerate() {
+                value += b * back(j + 1).map_or(presample, var).ln();
+            }
+            value.exp()
+        },
+        _ => panic!("Unknown variance model: {}", spec.variance_model),
+    }
+}
+
+/// Filter the ARMA-GARCH(-M) recursions through y:
+/// y_t = μ + Σφ_i·y_{t-i} + Σθ_j·ε_{t-j} + λσ_t + ε_t, ε_t = σ_t·z_t,
+/// conditional on the first `ar` observations (presample residuals are zero)
+pub fn garch_filter(spec: &GarchSpec, params: &GarchParams, y: &[f64]) -> GarchPath {
+    let n = y.len();
+    let start = spec.ar;
+    let mean_y = y.iter().sum::<f64>() / n as f64;
+    let presample = y.iter().map(|v| (v - mean_y).powi(2)).sum::<f64>() / n as f64;
+
+    let mut residuals = vec![0.0; n];
+    let mut variance = vec![presample; n];
+    let mut loglik_terms = Vec::with_capacity(n - start);
+    for t in start..n {
+        variance[t] = next_variance(spec, params, &residuals, &variance, t, start, presample);
+        let mut mean = params.mu + params.lambda * variance[t].sqrt();
+        for (i, &phi) in params.ar.iter().enumerate() {
+            mean += phi * y[t - 1 - i];
+        }
+        for (j, &theta) in params.ma.iter().enumerate() {
+            if t > j && t - 1 - j >= start {
+                mean += theta * residuals[t - 1 - j];
+            }
+        }
+        residuals[t] = y[t] - mean;
+        let z = residuals[t] / variance[t].sqrt();
+        loglik_terms.push(innovation_log_density(z, &spec.distribution, &params.shape) - 0.5 * variance[t].ln());
+    }
+    GarchPath { residuals, variance, loglik_terms, start }
+}
@@ -0,0 +1,116 @@
+use wasm_bindgen::prelude::*;
+use crate::GARCH;
+use crate::time_series::arima::arima_fore_ahead::psi_weights;
+use crate::time_series::garch::garch_filter::{next_variance, GarchParams};
+use crate::time_series::garch::innovation::prob_negative;
+use crate::time_series::helper_structs::ForecastResult;
+use crate::time_series::smoothing::smoothing_forecast::normal_forecast_result;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+// Simulated paths behind the EGARCH variance forecasts (fixed seed for reproducible output)
+const EGARCH_PATHS: usize = 2000;
+const EGARCH_SEED: u64 = 42;
+
+// Internal helpers (not exposed to WASM)
+impl GARCH {
+    /// Fitted parameters, or an error when the coefficients do not match the specification
+    fn fitted_params(&self) -> Result<GarchParams, JsValue> {
+        let spec = self.spec();
+        let coefficients = self.get_coefficients();
+        if coefficients.is_empty() {
+            return Err(JsValue::from_str("Model not estimated"));
+        }
+        if coefficients.len() != spec.n_params() {
+            return Err(JsValue::from_str(&format!(
+                "Expected {} coefficients for the model specification, got {}",
+                spec.n_params(), coefficients.len()
+            )));
+        }
+        Ok(spec.unpack(&coefficients))
+    }
+}
+
+#[wasm_bindgen]
+impl GARCH {
+    /// Conditional variance forecasts σ²_{n+1..n+h} of the fitted model. GARCH and TGARCH use
+    /// the recursion with E[ε²] = σ² and E[1(ε < 0)ε²] = P(z < 0)σ² for future shocks;
+    /// EGARCH averages simulated paths driven by resampled standardized residuals.
+    pub fn forecast_variance(&self, h: usize) -> Result<Vec<f64>, JsValue> {
+        let spec = self.spec();
+        let params = self.fitted_params()?;
+        let residuals = self.get_residuals();
+        let variance = self.get_variance();
+        let n = residuals.len();
+
+        if spec.variance_model == "egarch" {
+            let pool: Vec<f64> = self.get_standardized_residuals()[spec.ar..].to_vec();
+            let mut rng = StdRng::seed_from_u64(EGARCH_SEED);
+            let mut total = vec![0.0; h];
+            for _ in 0..EGARCH_PATHS {
+                let mut eps = residuals.clone();
+                let mut var = variance.clone();
+                for (j, sum) in total.iter_mut().enumerate() {
+                    let sigma2 = next_variance(&spec, &params, &eps, &var, n + j, spec.ar, variance[0]);
+                    var.push(sigma2);
+                    eps.push(sigma2.sqrt() * pool[rng.gen_range(0..pool.len())]);
+                    *sum += sigma2;
+                }
+            }
+            return Ok(total.iter().map(|s| s / EGARCH_PATHS as f64).collect());
+        }
+
+        let kappa = prob_negative(&spec.distribution, &params.shape);
+        let mut var = variance.clone();
+        for t in n..n + h {
+            let mut value = params.omega;
+            for (i, &a) in params.alpha.iter().enumerate() {
+                let s = t - 1 - i;
+                let g = if spec.has_gamma() { params.gamma[i] } else { 0.0 };
+                value += if s < n {
+                    (a + if residuals[s] < 0.0 { g } else { 0.0 }) * residuals[s].powi(2)
+                } else {
+                    (a + kappa * g) * var[s]
+                };
+            }
+            for (j, &b) in params.beta.iter().enumerate() {
+                value += b * var[t - 1 - j];
+            }
+            var.push(value);
+        }
+        Ok(var[n..].to_vec())
+    }
+
+    /// h-step forecasts of the mean equation with normal intervals, where the forecast
+    /// error variance Σ_{j<h} ψ_j²σ²_{n+h-j} combines the ARMA ψ-weights with the
+    /// conditional variance forecasts
+    pub fn forecast_ahead(&self, h: usize, level: f64) -> Result<ForecastResult, JsValue> {
+        let params = self.fitted_params()?;
+        let data = self.get_data();
+        let residuals = self.get_residuals();
+        let n = data.len();
+        let variance = self.forecast_variance(h)?;
+
+        let mut y = data.clone();
+        for (j, sigma2) in variance.iter().enumerate() {
+            let t = n + j;
+            let mut mean = params.mu + params.lambda * sigma2.sqrt();
+            for (i, &phi) in params.ar.iter().enumerate() {
+                mean += phi * y[t - 1 - i];
+            }
+            for (i, &theta) in params.ma.iter().enumerate() {
+                if t - 1 - i < n {
+                    mean += theta * residuals[t - 1 - i];
+                }
+            }
+            y.push(mean);
+        }
+
+        let negated_ma: Vec<f64> = params.ma.iter().map(|m| -m).collect();
+        let psi = psi_weights(&params.ar, &negated_ma, h);
+        let se = (1..=h)
+            .map(|step| (0..step).map(|j| psi[j].powi(2) * variance[step - 1 - j]).sum::<f64>().sqrt())
+            .collect();
+        Ok(normal_forecast_result(y[n..].to_vec(), se, level))
+    }
+}
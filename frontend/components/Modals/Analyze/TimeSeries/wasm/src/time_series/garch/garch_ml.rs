@@ -0,0 +1,235 @@
+use wasm_bindgen::prelude::*;
+use crate::{invert_matrix, numerical_hessian, GARCH};
+use crate::time_series::garch::garch_filter::{garch_filter, GarchParams, GarchSpec};
+use crate::time_series::garch::innovation::n_shape_params;
+use finitediff::FiniteDiff;
+use liblbfgs::lbfgs;
+use std::cell::RefCell;
+
+// Lower bounds of the shape parameters: ν > 2 keeps the t variance finite
+const T_DF_MIN: f64 = 2.05;
+const GED_SHAPE_MIN: f64 = 0.1;
+
+// Map unconstrained parameters to the natural ones: positive ω, α, β for GARCH and
+// TGARCH (γ free), everything free for EGARCH, ν and ξ above their bounds
+fn to_natural(spec: &GarchSpec, raw: &[f64]) -> Vec<f64> {
+    let mut theta = raw.to_vec();
+    let positive_from = spec.omega_index();
+    if spec.variance_model != "egarch" {
+        for (i, value) in theta.iter_mut().enumerate().take(spec.beta_index() + spec.p).skip(positive_from) {
+            let is_gamma = spec.has_gamma() && i > positive_from + spec.q && i <= positive_from + 2 * spec.q;
+            if !is_gamma {
+                *value = raw[i].exp();
+            }
+        }
+    }
+    let shape_index = spec.n_params() - n_shape_params(&spec.distribution);
+    match spec.distribution.as_str() {
+        "t" => theta[shape_index] = T_DF_MIN + raw[shape_index].exp(),
+        "skew_t" => {
+            theta[shape_index] = T_DF_MIN + raw[shape_index].exp();
+            theta[shape_index + 1] = raw[shape_index + 1].exp();
+        },
+        "ged" => theta[shape_index] = GED_SHAPE_MIN + raw[shape_index].exp(),
+        _ => {},
+    }
+    theta
+}
+
+fn to_raw(spec: &GarchSpec, theta: &[f64]) -> Vec<f64> {
+    let mut raw = theta.to_vec();
+    let positive_from = spec.omega_index();
+    if spec.variance_model != "egarch" {
+        for (i, value) in raw.iter_mut().enumerate().take(spec.beta_index() + spec.p).skip(positive_from) {
+            let is_gamma = spec.has_gamma() && i > positive_from + spec.q && i <= positive_from + 2 * spec.q;
+            if !is_gamma {
+                *value = theta[i].ln();
+            }
+        }
+    }
+    let shape_index = spec.n_params() - n_shape_params(&spec.distribution);
+    match spec.distribution.as_str() {
+        "t" => raw[shape_index] = (theta[shape_index] - T_DF_MIN).ln(),
+        "skew_t" => {
+            raw[shape_index] = (theta[shape_index] - T_DF_MIN).ln();
+            raw[shape_index + 1] = theta[shape_index + 1].ln();
+        },
+        "ged" => raw[shape_index] = (theta[shape_index] - GED_SHAPE_MIN).ln(),
+        _ => {},
+    }
+    raw
+}
+
+// Covariance stationarity: Σα + Σγ/2 + Σβ < 1 (GARCH, TGARCH with α_i + γ_i ≥ 0) or Σ|β| < 1 (EGARCH)
+fn is_admissible(spec: &GarchSpec, params: &GarchParams) -> bool {
+    let beta_sum: f64 = params.beta.iter().sum();
+    match spec.variance_model.as_str() {
+        "egarch" => params.beta.iter().map(|b| b.abs()).sum::<f64>() < 1.0,
+        "tgarch" => {
+            params.alpha.iter().zip(params.gamma.iter()).all(|(a, g)| a + g >= 0.0)
+                && params.alpha.iter().sum::<f64>() + 0.5 * params.gamma.iter().sum::<f64>() + beta_sum < 1.0
+        },
+        _ => params.alpha.iter().sum::<f64>() + beta_sum < 1.0,
+    }
+}
+
+// Starting values for data scaled to unit variance
+fn start_values(spec: &GarchSpec, mean: f64) -> GarchParams {
+    let q = spec.q.max(1) as f64;
+    let p = spec.p.max(1) as f64;
+    let (omega, alpha, gamma) = match spec.variance_model.as_str() {
+        "egarch" => (0.0, 0.1 / q, 0.0),
+        "tgarch" => (0.095, 0.03 / q, 0.05 / q),
+        _ => (0.1, 0.05 / q, 0.0),
+    };
+    let shape = match spec.distribution.as_str() {
+        "t" => vec![8.0],
+        "skew_t" => vec![8.0, 1.0],
+        "ged" => vec![1.5],
+        _ => Vec::new(),
+    };
+    GarchParams {
+        mu: mean,
+        ar: vec![0.0; spec.ar],
+        ma: vec![0.0; spec.ma],
+        lambda: 0.0,
+        omega,
+        alpha: vec![alpha; spec.q],
+        gamma: if spec.has_gamma() { vec![gamma; spec.q] } else { Vec::new() },
+        beta: vec![0.85 / p; spec.p],
+        shape,
+    }
+}
+
+#[wasm_bindgen]
+impl GARCH {
+    /// Maximum-likelihood fit of the ARMA(ar, ma) mean equation (with the optional
+    /// GARCH-in-mean term) and the GARCH, EGARCH or TGARCH variance with normal, Student-t,
+    /// skew-t or GED innovations. The data are scaled to unit variance for the optimizer and
+    /// the estimates transformed back. Standard errors come from the inverse Hessian and the
+    /// Bollerslev-Wooldridge sandwich H⁻¹(Σ g_t g_t')H⁻¹ of the per-observation scores.
+    pub fn fit(&mut self) -> Result<(), JsValue> {
+        let spec = self.spec();
+        let data = self.get_data();
+        let n = data.len();
+        if n <= spec.n_params() + spec.ar + 1 {
+            return Err(JsValue::from_str(&format!("Too few observations ({}) for {} parameters", n, spec.n_params())));
+        }
+        let mean = data.iter().sum::<f64>() / n as f64;
+        let sd = (data.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n as f64).sqrt();
+        if sd <= 0.0 {
+            return Err(JsValue::from_str("The series is constant"));
+        }
+        let y: Vec<f64> = data.iter().map(|v| v / sd).collect();
+
+        let negative_loglik = |theta: &[f64]| -> f64 {
+            let params = spec.unpack(theta);
+            if !is_admissible(&spec, &params) {
+                return f64::MAX;
+            }
+            let value = -garch_filter(&spec, &params, &y).loglik_terms.iter().sum::<f64>();
+            if value.is_finite() { value } else { f64::MAX }
+        };
+
+        let mut raw = to_raw(&spec, &spec.pack(&start_values(&spec, mean / sd)));
+        // The line search may stop on an inadmissible trial point, so the best point visited is kept
+        let best = RefCell::new((f64::INFINITY, raw.clone()));
+        let f = |raw: &Vec<f64>| {
+            let value = negative_loglik(&to_natural(&spec, raw));
+            let mut best = best.borrow_mut();
+            if value < best.0 {
+                *best = (value, raw.clone());
+            }
+            value
+        };
+        let g = |raw: &Vec<f64>| raw.central_diff(&f);
+        let eval = |x: &[f64], gx: &mut [f64]| {
+            let x_vec = x.to_vec();
+            let fx = f(&x_vec);
+            let gx_eval = g(&x_vec);
+            gx[..gx_eval.len()].copy_from_slice(&gx_eval[..]);
+            Ok(fx)
+        };
+        let fmin = lbfgs().with_max_iterations(500).with_linesearch_algorithm("Backtracking");
+        if let Err(e) = fmin.minimize(&mut raw, eval, |_prgr| false) {
+            tracing::warn!("Got error during GARCH fit: {}", e);
+        }
+        let theta = to_natural(&spec, &best.into_inner().1);
+        let params = spec.unpack(&theta);
+        let path = garch_filter(&spec, &params, &y);
+
+        // Observed information and per-observation scores on the scaled problem
+        let k = theta.len();
+        let objective = |x: &Vec<f64>| negative_loglik(x);
+        let hessian = numerical_hessian(&objective, &theta);
+        let h_inv = invert_matrix(&hessian).unwrap_or_else(|| vec![vec![f64::NAN; k]; k]);
+        let mut scores = vec![vec![0.0; path.loglik_terms.len()]; k];
+        for (i, score) in scores.iter_mut().enumerate() {
+            let step = 1e-4 * theta[i].abs().max(1.0);
+            let mut up = theta.clone();
+            let mut down = theta.clone();
+            up[i] += step;
+            down[i] -= step;
+            let terms_up = garch_filter(&spec, &spec.unpack(&up), &y).loglik_terms;
+            let terms_down = garch_filter(&spec, &spec.unpack(&down), &y).loglik_terms;
+            *score = terms_up.iter().zip(terms_down.iter()).map(|(a, b)| (a - b) / (2.0 * step)).collect();
+        }
+        let outer: Vec<Vec<f64>> = (0..k)
+            .map(|i| (0..k).map(|j| scores[i].iter().zip(scores[j].iter()).map(|(a, b)| a * b).sum()).collect())
+            .collect();
+        let sandwich: Vec<Vec<f64>> = (0..k)
+            .map(|i| {
+                (0..k)
+                    .map(|j| (0..k).map(|a| (0..k).map(|b| h_inv[i][a] * outer[a][b] * h_inv[b][j]).sum::<f64>()).sum())
+                    .collect()
+            })
+            .collect();
+
+        // Back to the original scale: μ·sd, ω·sd² (GARCH, TGARCH) or ω + 2 ln sd·(1 - Σβ) (EGARCH)
+        let mut jacobian = vec![vec![0.0; k]; k];
+        (0..k).for_each(|i| jacobian[i][i] = 1.0);
+        jacobian[0][0] = sd;
+        let omega_index = spec.omega_index();
+        let shift = 2.0 * sd.ln();
+        if spec.variance_model == "egarch" {
+            for j in 0..spec.p {
+                jacobian[omega_index][spec.beta_index() + j] = -shift;
+            }
+        } else {
+            jacobian[omega_index][omega_index] = sd * sd;
+        }
+        let mut coefficients: Vec<f64> = (0..k).map(|i| (0..k).map(|j| jacobian[i][j] * theta[j]).sum()).collect();
+        if spec.variance_model == "egarch" {
+            coefficients[omega_index] += shift;
+        }
+        let transformed_se = |cov: &Vec<Vec<f64>>| -> Vec<f64> {
+            (0..k)
+                .map(|i| (0..k).map(|a| (0..k).map(|b| jacobian[i][a] * cov[a][b] * jacobian[i][b]).sum::<f64>()).sum::<f64>().sqrt())
+                .collect()
+        };
+
+        let n_used = path.loglik_terms.len() as f64;
+        let log_lik = path.loglik_terms.iter().sum::<f64>() - n_used * sd.ln();
+        let original = spec.unpack(&coefficients);
+        self.set_std_errors(transformed_se(&h_inv));
+        self.set_robust_std_errors(transformed_se(&sandwich));
+        self.set_omega(original.omega);
+        self.set_alpha(original.alpha);
+        self.set_gamma(original.gamma);
+        self.set_beta(original.beta);
+        self.set_shape(original.shape);
+        self.set_coefficients(coefficients);
+        self.set_variance(path.variance.iter().map(|v| v * sd * sd).collect());
+        self.set_residuals(path.residuals.iter().map(|e| e * sd).collect());
+        self.set_log_likelihood(log_lik);
+        self.set_aic(-2.0 * log_lik + 2.0 * k as f64);
+        self.set_bic(-2.0 * log_lik + k as f64 * n_used.ln());
+        Ok(())
+    }
+
+    /// Standardized residuals ε_t/σ_t of the fitted model
+    pub fn get_standardized_residuals(&self) -> Vec<f64> {
+        let variance = self.get_variance();
+        self.get_residuals().iter().zip(variance.iter()).map(|(e, v)| e / v.sqrt()).collect()
+    }
+}
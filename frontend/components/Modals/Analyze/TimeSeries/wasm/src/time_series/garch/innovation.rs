@@ -0,0 +1,60 @@
+use statrs::function::gamma::ln_gamma;
+
+/// Shape parameters of the standardized innovation distribution:
+/// "normal" none, "t" ν, "skew_t" ν and ξ, "ged" ν
+pub fn n_shape_params(distribution: &str) -> usize {
+    match distribution {
+        "normal" => 0,
+        "t" | "ged" => 1,
+        "skew_t" => 2,
+        _ => panic!("Unknown distribution: {}", distribution),
+    }
+}
+
+/// Log density of the Student-t scaled to unit variance (ν > 2)
+fn std_t_log_density(z: f64, nu: f64) -> f64 {
+    ln_gamma((nu + 1.0) / 2.0) - ln_gamma(nu / 2.0) - 0.5 * (std::f64::consts::PI * (nu - 2.0)).ln()
+        - (nu + 1.0) / 2.0 * (1.0 + z * z / (nu - 2.0)).ln()
+}
+
+/// Log density at z of the zero-mean, unit-variance innovation distribution:
+/// Student-t, Fernández-Steel skew-t (ξ = 1 is symmetric) or generalized error distribution
+pub fn innovation_log_density(z: f64, distribution: &str, shape: &[f64]) -> f64 {
+    match distribution {
+        "normal" => -0.5 * (2.0 * std::f64::consts::PI).ln() - 0.5 * z * z,
+        "t" => std_t_log_density(z, shape[0]),
+        "skew_t" => {
+            let (nu, xi) = (shape[0], shape[1]);
+            // Mean and standard deviation of the skewed (unstandardized) variable
+            let m = (ln_gamma((nu - 1.0) / 2.0) - ln_gamma(nu / 2.0)).exp() * (nu - 2.0).sqrt()
+                / std::f64::consts::PI.sqrt() * (xi - 1.0 / xi);
+            let s = (xi * xi + 1.0 / (xi * xi) - 1.0 - m * m).sqrt();
+            let x = s * z + m;
+            let scaled = if x >= 0.0 { x / xi } else { x * xi };
+            (2.0 / (xi + 1.0 / xi)).ln() + s.ln() + std_t_log_density(scaled, nu)
+        },
+        "ged" => {
+            let nu = shape[0];
+            let lambda = (2f64.powf(-2.0 / nu) * (ln_gamma(1.0 / nu) - ln_gamma(3.0 / nu)).exp()).sqrt();
+            nu.ln() - 0.5 * (z / lambda).abs().powf(nu) - (1.0 + 1.0 / nu) * 2f64.ln() - ln_gamma(1.0 / nu) - lambda.ln()
+        },
+        _ => panic!("Unknown distribution: {}", distribution),
+    }
+}
+
+/// P(z < 0), which scales the expected leverage term of TGARCH forecasts;
+/// 0.5 for the symmetric distributions, numerical integration for the skew-t
+pub fn prob_negative(distribution: &str, shape: &[f64]) -> f64 {
+    if distribution != "skew_t" {
+        return 0.5;
+    }
+    let (steps, lower) = (4000, -40.0);
+    let width = -lower / steps as f64;
+    let density = |z: f64| innovation_log_density(z, distribution, shape).exp();
+    (0..steps)
+        .map(|i| {
+            let a = lower + i as f64 * width;
+            0.5 * width * (density(a) + density(a + width))
+        })
+        .sum()
+}
@@ -4,4 +4,8 @@ pub mod likelihood;
 pub mod diagnostics;
 pub mod arch_lm_test;
 pub mod egarch;
-pub mod tgarch;
\ No newline at end of file
+pub mod tgarch;
+pub mod innovation;
+pub mod garch_filter;
+pub mod garch_ml;
+pub mod garch_forecast;
\ No newline at end of file
@@ -28,3 +28,99 @@ pub struct CriticalValues {
     pub i1_bound: f64,
 }
 
+
+// Out-of-sample forecast with prediction intervals
+// NOTE: Vec<f64> fields can't be public in WASM structs - use getters
+#[wasm_bindgen]
+pub struct ForecastResult {
+    forecast: Vec<f64>,
+    se: Vec<f64>,
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+    paths: Vec<f64>,  // Flattened simulated paths: [path0[0..h], path1[0..h], ...]
+    pub level: f64,
+    pub n_paths: usize,
+}
+
+#[wasm_bindgen]
+impl ForecastResult {
+    pub fn get_forecast(&self) -> Vec<f64> { self.forecast.clone() }
+    pub fn get_se(&self) -> Vec<f64> { self.se.clone() }
+    pub fn get_lower(&self) -> Vec<f64> { self.lower.clone() }
+    pub fn get_upper(&self) -> Vec<f64> { self.upper.clone() }
+    pub fn get_paths(&self) -> Vec<f64> { self.paths.clone() }
+}
+
+// Internal constructors (not exposed to WASM)
+impl ForecastResult {
+    pub fn new(forecast: Vec<f64>, se: Vec<f64>, lower: Vec<f64>, upper: Vec<f64>, level: f64) -> ForecastResult {
+        ForecastResult { forecast, se, lower, upper, paths: Vec::new(), level, n_paths: 0 }
+    }
+
+    /// Build a result whose intervals come from the empirical quantiles of simulated paths
+    /// (NaN se and bounds with fewer than two paths)
+    pub fn from_paths(forecast: Vec<f64>, paths: Vec<Vec<f64>>, level: f64) -> ForecastResult {
+        let h = forecast.len();
+        let n_paths = paths.len();
+        if n_paths < 2 {
+            let nan = vec![f64::NAN; h];
+            return ForecastResult::new(forecast, nan.clone(), nan.clone(), nan, level);
+        }
+        let mut se = Vec::with_capacity(h);
+        let mut lower = Vec::with_capacity(h);
+        let mut upper = Vec::with_capacity(h);
+        for k in 0..h {
+            let mut values: Vec<f64> = paths.iter().map(|path| path[k]).collect();
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            let mean = values.iter().sum::<f64>() / n_paths as f64;
+            let var = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n_paths as f64 - 1.0);
+            se.push(var.sqrt());
+            lower.push(empirical_quantile(&values, (1.0 - level) / 2.0));
+            upper.push(empirical_quantile(&values, 1.0 - (1.0 - level) / 2.0));
+        }
+        ForecastResult {
+            forecast,
+            se,
+            lower,
+            upper,
+            paths: paths.into_iter().flatten().collect(),
+            level,
+            n_paths,
+        }
+    }
+}
+
+/// Linear-interpolated quantile of already sorted values
+pub fn empirical_quantile(sorted: &[f64], prob: f64) -> f64 {
+    let pos = prob * (sorted.len() as f64 - 1.0);
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+    sorted[lo] + (pos - lo as f64) * (sorted[hi] - sorted[lo])
+}
+
+// Impulse responses with bootstrap bands
+// Flattened by horizon, then responding variable, then shock: [(h·k + i)·k + j]
+#[wasm_bindgen]
+pub struct ImpulseResponseResult {
+    irf: Vec<f64>,
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+    pub horizon: usize,
+    pub n_vars: usize,
+    pub level: f64,
+    pub n_boot: usize,
+}
+
+#[wasm_bindgen]
+impl ImpulseResponseResult {
+    pub fn get_irf(&self) -> Vec<f64> { self.irf.clone() }
+    pub fn get_lower(&self) -> Vec<f64> { self.lower.clone() }
+    pub fn get_upper(&self) -> Vec<f64> { self.upper.clone() }
+}
+
+// Internal constructor (not exposed to WASM)
+impl ImpulseResponseResult {
+    pub fn new(irf: Vec<f64>, lower: Vec<f64>, upper: Vec<f64>, horizon: usize, n_vars: usize, level: f64, n_boot: usize) -> ImpulseResponseResult {
+        ImpulseResponseResult { irf, lower, upper, horizon, n_vars, level, n_boot }
+    }
+}
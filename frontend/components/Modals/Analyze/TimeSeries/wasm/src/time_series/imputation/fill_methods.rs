@@ -0,0 +1,197 @@
+use wasm_bindgen::prelude::*;
+use crate::Imputation;
+use crate::time_series::imputation::local_level::{estimate_signal_noise_ratio, local_level_smooth};
+
+/// Natural cubic spline through (x_i, y_i), x increasing, evaluated at `at`;
+/// points outside [x_0, x_n] take the nearest end value
+pub fn natural_spline(x: &[f64], y: &[f64], at: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    if n < 3 {
+        // A line (or constant) through fewer points
+        return at
+            .iter()
+            .map(|&t| {
+                if n == 1 || t <= x[0] {
+                    y[0]
+                } else if t >= x[n - 1] {
+                    y[n - 1]
+                } else {
+                    y[0] + (y[1] - y[0]) * (t - x[0]) / (x[1] - x[0])
+                }
+            })
+            .collect();
+    }
+
+    // Second derivatives M_i with M_0 = M_{n-1} = 0 (tridiagonal system, Thomas algorithm)
+    let h: Vec<f64> = (0..n - 1).map(|i| x[i + 1] - x[i]).collect();
+    let mut diag = vec![0.0; n - 2];
+    let mut rhs = vec![0.0; n - 2];
+    for i in 1..n - 1 {
+        diag[i - 1] = 2.0 * (h[i - 1] + h[i]);
+        rhs[i - 1] = 6.0 * ((y[i + 1] - y[i]) / h[i] - (y[i] - y[i - 1]) / h[i - 1]);
+    }
+    for i in 1..n - 2 {
+        let w = h[i] / diag[i - 1];
+        diag[i] -= w * h[i];
+        rhs[i] -= w * rhs[i - 1];
+    }
+    let mut m = vec![0.0; n];
+    for i in (1..n - 1).rev() {
+        let next = if i + 1 < n - 1 { h[i] * m[i + 1] } else { 0.0 };
+        m[i] = (rhs[i - 1] - next) / diag[i - 1];
+    }
+
+    at.iter()
+        .map(|&t| {
+            if t <= x[0] {
+                return y[0];
+            }
+            if t >= x[n - 1] {
+                return y[n - 1];
+            }
+            let i = x.partition_point(|&xi| xi <= t).clamp(1, n - 1) - 1;
+            let (a, b) = (x[i + 1] - t, t - x[i]);
+            m[i] * a.powi(3) / (6.0 * h[i])
+                + m[i + 1] * b.powi(3) / (6.0 * h[i])
+                + (y[i] / h[i] - m[i] * h[i] / 6.0) * a
+                + (y[i + 1] / h[i] - m[i + 1] * h[i] / 6.0) * b
+        })
+        .collect()
+}
+
+// Internal helpers (not exposed to WASM)
+impl Imputation {
+    /// Indices and values of the observed points, or an error when there are none
+    fn observed(&self) -> Result<(Vec<usize>, Vec<f64>), JsValue> {
+        let index: Vec<usize> = (0..self.data.len()).filter(|&i| !self.data[i].is_nan()).collect();
+        if index.is_empty() {
+            return Err(JsValue::from_str("No observed values to impute from"));
+        }
+        let values = index.iter().map(|&i| self.data[i]).collect();
+        Ok((index, values))
+    }
+
+    /// Store the filled series and the positions that were missing
+    fn finish(&mut self, filled: Vec<f64>) -> Vec<f64> {
+        self.imputed_index = self.get_missing_index();
+        self.filled = filled.clone();
+        filled
+    }
+}
+
+#[wasm_bindgen]
+impl Imputation {
+    /// Linear interpolation between the neighbouring observations; leading and trailing
+    /// gaps take the first and last observed value
+    pub fn fill_linear(&mut self) -> Result<Vec<f64>, JsValue> {
+        let (index, values) = self.observed()?;
+        let mut filled = self.data.clone();
+        for (i, v) in filled.iter_mut().enumerate() {
+            if v.is_nan() {
+                let next = index.partition_point(|&j| j < i);
+                *v = if next == 0 {
+                    values[0]
+                } else if next == index.len() {
+                    values[index.len() - 1]
+                } else {
+                    let (i0, i1) = (index[next - 1], index[next]);
+                    let w = (i - i0) as f64 / (i1 - i0) as f64;
+                    values[next - 1] + w * (values[next] - values[next - 1])
+                };
+            }
+        }
+        Ok(self.finish(filled))
+    }
+
+    /// Natural cubic spline interpolation; leading and trailing gaps take the first and
+    /// last observed value
+    pub fn fill_spline(&mut self) -> Result<Vec<f64>, JsValue> {
+        let (index, values) = self.observed()?;
+        let x: Vec<f64> = index.iter().map(|&i| i as f64).collect();
+        let missing = self.get_missing_index();
+        let at: Vec<f64> = missing.iter().map(|&i| i as f64).collect();
+        let mut filled = self.data.clone();
+        for (&i, v) in missing.iter().zip(natural_spline(&x, &values, &at)) {
+            filled[i] = v;
+        }
+        Ok(self.finish(filled))
+    }
+
+    /// Last observation carried forward; a leading gap takes the first observation
+    pub fn fill_locf(&mut self) -> Result<Vec<f64>, JsValue> {
+        let (_, values) = self.observed()?;
+        let mut last = values[0];
+        let mut filled = self.data.clone();
+        for v in filled.iter_mut() {
+            if v.is_nan() {
+                *v = last;
+            } else {
+                last = *v;
+            }
+        }
+        Ok(self.finish(filled))
+    }
+
+    /// Mean of the k nearest observations before and the k nearest after each gap
+    /// (fewer at the ends of the series)
+    pub fn fill_nearby_mean(&mut self, k: usize) -> Result<Vec<f64>, JsValue> {
+        let (index, values) = self.observed()?;
+        let k = k.max(1);
+        let mut filled = self.data.clone();
+        for (i, v) in filled.iter_mut().enumerate() {
+            if v.is_nan() {
+                let next = index.partition_point(|&j| j < i);
+                let neighbours = &values[next.saturating_sub(k)..(next + k).min(values.len())];
+                *v = neighbours.iter().sum::<f64>() / neighbours.len() as f64;
+            }
+        }
+        Ok(self.finish(filled))
+    }
+
+    /// Seasonally adjusted Kalman interpolation: seasonal indices from the observed
+    /// deviations around a centred moving average are removed, the adjusted series is
+    /// fitted by a local level model (signal-to-noise ratio by maximum likelihood), and
+    /// the smoothed level plus the seasonal index fills each gap. Without a period
+    /// (period < 2) only the local level model is used.
+    pub fn fill_seasonal_kalman(&mut self) -> Result<Vec<f64>, JsValue> {
+        let (index, _) = self.observed()?;
+        if index.len() < 3 {
+            return self.fill_linear();
+        }
+        let n = self.data.len();
+        let period = self.period;
+        let mut seasonal = vec![0.0; n];
+        if period >= 2 && index.len() >= 2 * period {
+            // Trend of the linearly filled series by a centred (2 x m) moving average
+            let linear = self.fill_linear()?;
+            let half = period / 2;
+            let mut sums = vec![0.0; period];
+            let mut counts = vec![0usize; period];
+            for t in half..n.saturating_sub(half) {
+                let trend = if period.is_multiple_of(2) {
+                    (0.5 * linear[t - half] + linear[t - half + 1..t + half].iter().sum::<f64>() + 0.5 * linear[t + half]) / period as f64
+                } else {
+                    linear[t - half..=t + half].iter().sum::<f64>() / period as f64
+                };
+                if !self.data[t].is_nan() {
+                    sums[t % period] += self.data[t] - trend;
+                    counts[t % period] += 1;
+                }
+            }
+            let mut indices: Vec<f64> = (0..period).map(|s| if counts[s] > 0 { sums[s] / counts[s] as f64 } else { 0.0 }).collect();
+            let mean = indices.iter().sum::<f64>() / period as f64;
+            indices.iter_mut().for_each(|s| *s -= mean);
+            for (t, s) in seasonal.iter_mut().enumerate() {
+                *s = indices[t % period];
+            }
+        }
+
+        let adjusted: Vec<f64> = (0..n).map(|t| self.data[t] - seasonal[t]).collect();
+        let ratio = estimate_signal_noise_ratio(&adjusted);
+        let level = local_level_smooth(&adjusted, ratio);
+        let filled = (0..n)
+            .map(|t| if self.data[t].is_nan() { level[t] + seasonal[t] } else { self.data[t] })
+            .collect();
+        Ok(self.finish(filled))
+    }
+}
@@ -0,0 +1,58 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct Imputation {
+    pub(crate) data: Vec<f64>,    // Missing observations are NaN
+    pub(crate) period: usize,     // Seasonal period for the seasonal Kalman fill (0 or 1: none)
+    pub(crate) timestamps: Vec<f64>, // Period start times (ms since epoch) after regularize
+    pub(crate) filled: Vec<f64>,
+    pub(crate) imputed_index: Vec<usize>,
+}
+
+#[wasm_bindgen]
+impl Imputation {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, period: usize) -> Imputation {
+        Imputation {
+            data,
+            period,
+            timestamps: Vec::new(),
+            filled: Vec::new(),
+            imputed_index: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_period(&self) -> usize {
+        self.period
+    }
+    pub fn get_timestamps(&self) -> Vec<f64> {
+        self.timestamps.clone()
+    }
+    pub fn get_filled(&self) -> Vec<f64> {
+        self.filled.clone()
+    }
+    /// Positions (0-based) of the points filled by the last fill method
+    pub fn get_imputed_index(&self) -> Vec<usize> {
+        self.imputed_index.clone()
+    }
+    /// Values filled in at get_imputed_index
+    pub fn get_imputed_values(&self) -> Vec<f64> {
+        self.imputed_index.iter().map(|&i| self.filled[i]).collect()
+    }
+    /// Positions (0-based) of the missing observations
+    pub fn get_missing_index(&self) -> Vec<usize> {
+        (0..self.data.len()).filter(|&i| self.data[i].is_nan()).collect()
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>) {
+        self.data = data;
+    }
+    pub fn set_period(&mut self, period: usize) {
+        self.period = period;
+    }
+}
@@ -0,0 +1,80 @@
+use std::f64::consts::PI;
+use crate::time_series::optimization::golden_section_minimize;
+
+// Diffuse prior variance of the initial level, relative to the data scale
+const DIFFUSE_SCALE: f64 = 1e7;
+
+/// Filtered quantities of the local level model y_t = μ_t + ε_t, μ_{t+1} = μ_t + η_t
+/// with q = σ²_η/σ²_ε and σ²_ε = 1 (missing observations are NaN)
+pub struct LocalLevelFilter {
+    pub predicted: Vec<f64>,     // a_t = E(μ_t | y_1..y_{t-1})
+    pub predicted_var: Vec<f64>, // P_t
+    pub filtered: Vec<f64>,      // E(μ_t | y_1..y_t)
+    pub filtered_var: Vec<f64>,
+    pub log_likelihood: f64,     // With σ²_ε concentrated out, first observation diffuse
+}
+
+pub fn local_level_filter(data: &[f64], q: f64) -> LocalLevelFilter {
+    let n = data.len();
+    let observed: Vec<f64> = data.iter().filter(|v| !v.is_nan()).cloned().collect();
+    let mean = observed.iter().sum::<f64>() / observed.len().max(1) as f64;
+    let scale = observed.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / observed.len().max(1) as f64;
+
+    let mut a = 0.0;
+    let mut p = DIFFUSE_SCALE * scale.max(1.0);
+    let mut out = LocalLevelFilter {
+        predicted: Vec::with_capacity(n),
+        predicted_var: Vec::with_capacity(n),
+        filtered: Vec::with_capacity(n),
+        filtered_var: Vec::with_capacity(n),
+        log_likelihood: 0.0,
+    };
+    let (mut sum_sq, mut sum_log_f, mut n_used, mut first) = (0.0, 0.0, 0usize, true);
+    for &y in data.iter() {
+        out.predicted.push(a);
+        out.predicted_var.push(p);
+        if !y.is_nan() {
+            let f = p + 1.0;
+            let v = y - a;
+            if !first {
+                sum_sq += v * v / f;
+                sum_log_f += f.ln();
+                n_used += 1;
+            }
+            first = false;
+            a += p / f * v;
+            p -= p * p / f;
+        }
+        out.filtered.push(a);
+        out.filtered_var.push(p);
+        p += q;
+    }
+    let m = n_used as f64;
+    out.log_likelihood = if n_used > 0 {
+        -0.5 * (m * ((2.0 * PI * sum_sq / m).ln() + 1.0) + sum_log_f)
+    } else {
+        f64::NAN
+    };
+    out
+}
+
+/// Smoothed level E(μ_t | y_1..y_n) by the Rauch-Tung-Striebel recursion
+pub fn local_level_smooth(data: &[f64], q: f64) -> Vec<f64> {
+    let filter = local_level_filter(data, q);
+    let n = data.len();
+    let mut smoothed = filter.filtered.clone();
+    for t in (0..n.saturating_sub(1)).rev() {
+        let gain = filter.filtered_var[t] / filter.predicted_var[t + 1];
+        smoothed[t] = filter.filtered[t] + gain * (smoothed[t + 1] - filter.predicted[t + 1]);
+    }
+    smoothed
+}
+
+/// Maximum-likelihood q = σ²_η/σ²_ε by golden-section search over log q in [-12, 8]
+pub fn estimate_signal_noise_ratio(data: &[f64]) -> f64 {
+    let objective = |log_q: f64| {
+        let ll = local_level_filter(data, log_q.exp()).log_likelihood;
+        if ll.is_finite() { -ll } else { f64::MAX }
+    };
+    golden_section_minimize(objective, -12.0, 8.0).exp()
+}
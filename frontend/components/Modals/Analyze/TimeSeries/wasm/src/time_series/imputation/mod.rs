@@ -0,0 +1,4 @@
+pub mod imputation;
+pub mod fill_methods;
+pub mod local_level;
+pub mod regularize;
\ No newline at end of file
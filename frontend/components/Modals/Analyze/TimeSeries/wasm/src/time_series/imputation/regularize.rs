@@ -0,0 +1,112 @@
+use wasm_bindgen::prelude::*;
+use crate::Imputation;
+
+const MS_PER_DAY: f64 = 86_400_000.0;
+
+/// (year, month 1-12, day) of a day count since 1970-01-01 (proleptic Gregorian)
+pub fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Day count since 1970-01-01 of a civil date
+pub fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Sequential number of the "day", "week" (starting Monday), "month", "quarter" or
+/// "year" containing the timestamp (ms since epoch, UTC)
+pub fn period_number(timestamp: f64, frequency: &str) -> i64 {
+    let days = (timestamp / MS_PER_DAY).floor() as i64;
+    let (year, month, _) = civil_from_days(days);
+    match frequency {
+        "day" => days,
+        // 1970-01-01 was a Thursday
+        "week" => (days + 3).div_euclid(7),
+        "month" => year * 12 + month - 1,
+        "quarter" => year * 4 + (month - 1) / 3,
+        "year" => year,
+        _ => panic!("Unknown frequency: {}", frequency),
+    }
+}
+
+/// Start (ms since epoch, UTC) of a period numbered by period_number
+pub fn period_start(number: i64, frequency: &str) -> f64 {
+    let days = match frequency {
+        "day" => number,
+        "week" => number * 7 - 3,
+        "month" => days_from_civil(number.div_euclid(12), number.rem_euclid(12) + 1, 1),
+        "quarter" => days_from_civil(number.div_euclid(4), 3 * number.rem_euclid(4) + 1, 1),
+        "year" => days_from_civil(number, 1, 1),
+        _ => panic!("Unknown frequency: {}", frequency),
+    };
+    days as f64 * MS_PER_DAY
+}
+
+#[wasm_bindgen]
+impl Imputation {
+    /// Put observations made at irregular `timestamps` (ms since epoch, UTC) on a regular
+    /// "day", "week", "month", "quarter" or "year" grid from the first to the last period.
+    /// Several observations in one period are combined by "mean", "sum", "first" or
+    /// "last"; periods without observations become missing. Replaces the data, stores
+    /// the period start times in get_timestamps and returns the regular series.
+    pub fn regularize(&mut self, timestamps: Vec<f64>, values: Vec<f64>, frequency: String, aggregate: String) -> Result<Vec<f64>, JsValue> {
+        if timestamps.len() != values.len() {
+            return Err(JsValue::from_str(&format!(
+                "Length mismatch: {} timestamps for {} values", timestamps.len(), values.len()
+            )));
+        }
+        let mut points: Vec<(i64, f64, f64)> = timestamps
+            .iter()
+            .zip(values.iter())
+            .filter(|(t, v)| t.is_finite() && !v.is_nan())
+            .map(|(&t, &v)| (period_number(t, &frequency), t, v))
+            .collect();
+        if points.is_empty() {
+            return Err(JsValue::from_str("No observed values to regularize"));
+        }
+        // Order by period, then timestamp within the period for "first" and "last"
+        points.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+        let first = points[0].0;
+        let n = (points[points.len() - 1].0 - first + 1) as usize;
+
+        let mut groups: Vec<Vec<f64>> = vec![Vec::new(); n];
+        for (number, _, v) in points {
+            groups[(number - first) as usize].push(v);
+        }
+        let data: Vec<f64> = groups
+            .iter()
+            .map(|g| {
+                if g.is_empty() {
+                    return f64::NAN;
+                }
+                match aggregate.as_str() {
+                    "mean" => g.iter().sum::<f64>() / g.len() as f64,
+                    "sum" => g.iter().sum::<f64>(),
+                    "first" => g[0],
+                    "last" => g[g.len() - 1],
+                    _ => panic!("Unknown aggregate: {}", aggregate),
+                }
+            })
+            .collect();
+        self.timestamps = (0..n as i64).map(|i| period_start(first + i, &frequency)).collect();
+        self.data = data.clone();
+        self.filled = Vec::new();
+        self.imputed_index = Vec::new();
+        Ok(data)
+    }
+}
@@ -8,4 +8,13 @@ pub mod arima;
 pub mod garch;
 pub mod ecm;
 pub mod ardl;
+pub mod arimax;
+pub mod vecm;
+pub mod var;
+pub mod spectral;
+pub mod imputation;
+pub mod box_cox;
+pub mod state_space;
+pub mod filters;
+pub mod optimization;
 pub mod helper_structs;  // Helper structs for WASM returns
\ No newline at end of file
@@ -0,0 +1,26 @@
+// Shared numerical optimizers (internal, no JS conversion needed)
+
+/// Minimum of a unimodal objective on [lo, hi] by golden-section search
+pub fn golden_section_minimize<F: Fn(f64) -> f64>(objective: F, lo: f64, hi: f64) -> f64 {
+    let ratio = (5.0f64.sqrt() - 1.0) / 2.0;
+    let (mut lo, mut hi) = (lo, hi);
+    let mut x1 = hi - ratio * (hi - lo);
+    let mut x2 = lo + ratio * (hi - lo);
+    let (mut f1, mut f2) = (objective(x1), objective(x2));
+    for _ in 0..60 {
+        if f1 <= f2 {
+            hi = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = hi - ratio * (hi - lo);
+            f1 = objective(x1);
+        } else {
+            lo = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = lo + ratio * (hi - lo);
+            f2 = objective(x2);
+        }
+    }
+    0.5 * (lo + hi)
+}
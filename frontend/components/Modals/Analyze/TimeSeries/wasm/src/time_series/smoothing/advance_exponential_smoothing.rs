@@ -1,10 +1,30 @@
 use wasm_bindgen::prelude::*;
 use crate::Smoothing;
 
+// Smoothed states of Holt's and Winter's methods (internal)
+pub struct SmoothingStates {
+    pub level: Vec<f64>,
+    pub trend: Vec<f64>,
+    pub seasonal: Vec<f64>, // Empty for Holt's method
+    pub fitted: Vec<f64>,
+}
+
 #[wasm_bindgen]
 impl Smoothing{
     // Holt's Method
     pub fn calculate_holt(&self, alpha:f64, beta:f64) -> Vec<f64> {
+        self.holt_states(alpha, beta).fitted
+    }
+
+    // Winter's Method
+    pub fn calculate_winter(&self, alpha:f64, beta:f64, gamma:f64, period:usize) -> Vec<f64> {
+        self.winter_states(alpha, beta, gamma, period).fitted
+    }
+}
+
+// Internal helpers (not exposed to WASM)
+impl Smoothing{
+    pub fn holt_states(&self, alpha:f64, beta:f64) -> SmoothingStates {
         let mut level: Vec<f64> = Vec::new();
         let mut trend: Vec<f64> = Vec::new();
         let mut holt_values: Vec<f64> = Vec::new();
@@ -19,11 +39,10 @@ impl Smoothing{
                 holt_values.push(level[i-1] + trend[i-1]);
             }
         }
-        holt_values
+        SmoothingStates { level, trend, seasonal: Vec::new(), fitted: holt_values }
     }
 
-    // Winter's Method
-    pub fn calculate_winter(&self, alpha:f64, beta:f64, gamma:f64, period:usize) -> Vec<f64> {
+    pub fn winter_states(&self, alpha:f64, beta:f64, gamma:f64, period:usize) -> SmoothingStates {
         let mut level: Vec<f64> = Vec::new();
         let mut trend: Vec<f64> = Vec::new();
         let mut seasonal: Vec<f64> = Vec::new();
@@ -41,7 +60,7 @@ impl Smoothing{
                 trend.push(beta * (level[i] - level[i-1]) + (1.0 - beta) * trend[i-1]);
                 seasonal.push(gamma * (data[i] / level[i]) + (1.0 - gamma) * seasonal[0]);
                 winter_values.push(data[0]);
-            } 
+            }
             else{
                 level.push(alpha * (data[i] / seasonal[i-period]) + (1.0 - alpha) * (level[i-1] + trend[i-1]));
                 trend.push(beta * (level[i] - level[i-1]) + (1.0 - beta) * trend[i-1]);
@@ -49,6 +68,6 @@ impl Smoothing{
                 winter_values.push((level[i-1] + trend[i-1]) * seasonal[i-period]);
             }
         }
-        winter_values
+        SmoothingStates { level, trend, seasonal, fitted: winter_values }
     }
 }
\ No newline at end of file
@@ -0,0 +1,91 @@
+use wasm_bindgen::prelude::*;
+use serde::Serialize;
+use crate::EtsFit;
+
+// One model fitted during ETS selection
+#[derive(Serialize, Clone)]
+pub struct EtsCandidate {
+    pub model: String,
+    pub log_likelihood: f64,
+    pub aic: f64,
+    pub aicc: f64,
+    pub bic: f64,
+}
+
+/// Exponential smoothing state-space model ETS(error, trend, season).
+/// Components are "N", "A", "Ad", "M", "Md" or "Z" (chosen by AICc).
+#[wasm_bindgen]
+pub struct Ets {
+    data: Vec<f64>,
+    period: usize,           // Seasonal period (0 or 1 for non-seasonal data)
+    error_type: String,      // "A", "M" or "Z"
+    trend_type: String,      // "N", "A", "Ad", "M", "Md" or "Z"
+    season_type: String,     // "N", "A", "M" or "Z"
+    allow_multiplicative_trend: bool,
+    fit: Option<EtsFit>,
+    candidates: Vec<EtsCandidate>,
+}
+
+#[wasm_bindgen]
+impl Ets {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, period: usize) -> Ets {
+        Ets {
+            data,
+            period,
+            error_type: "Z".to_string(),
+            trend_type: "Z".to_string(),
+            season_type: "Z".to_string(),
+            allow_multiplicative_trend: false,
+            fit: None,
+            candidates: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> { self.data.clone() }
+    pub fn get_period(&self) -> usize { self.period }
+    pub fn get_error_type(&self) -> String { self.error_type.clone() }
+    pub fn get_trend_type(&self) -> String { self.trend_type.clone() }
+    pub fn get_season_type(&self) -> String { self.season_type.clone() }
+    pub fn get_allow_multiplicative_trend(&self) -> bool { self.allow_multiplicative_trend }
+
+    // Setters
+    pub fn set_model(&mut self, error_type: String, trend_type: String, season_type: String) {
+        self.error_type = error_type;
+        self.trend_type = trend_type;
+        self.season_type = season_type;
+    }
+    pub fn set_allow_multiplicative_trend(&mut self, allow: bool) { self.allow_multiplicative_trend = allow; }
+
+    // Estimation results (NaN or empty before fit)
+    pub fn get_model(&self) -> String { self.fit.as_ref().map_or(String::new(), |f| f.spec.name()) }
+    pub fn get_alpha(&self) -> f64 { self.fit.as_ref().map_or(f64::NAN, |f| f.params.alpha) }
+    pub fn get_beta(&self) -> f64 { self.fit.as_ref().map_or(f64::NAN, |f| f.params.beta) }
+    pub fn get_gamma(&self) -> f64 { self.fit.as_ref().map_or(f64::NAN, |f| f.params.gamma) }
+    pub fn get_phi(&self) -> f64 { self.fit.as_ref().map_or(f64::NAN, |f| f.params.phi) }
+    pub fn get_initial_states(&self) -> Vec<f64> { self.fit.as_ref().map_or(Vec::new(), |f| f.init_states.clone()) }
+    pub fn get_fitted(&self) -> Vec<f64> { self.fit.as_ref().map_or(Vec::new(), |f| f.run.fitted.clone()) }
+    pub fn get_residuals(&self) -> Vec<f64> { self.fit.as_ref().map_or(Vec::new(), |f| f.run.residuals.clone()) }
+    pub fn get_level(&self) -> Vec<f64> { self.fit.as_ref().map_or(Vec::new(), |f| f.run.level.clone()) }
+    pub fn get_slope(&self) -> Vec<f64> { self.fit.as_ref().map_or(Vec::new(), |f| f.run.trend.clone()) }
+    pub fn get_season(&self) -> Vec<f64> { self.fit.as_ref().map_or(Vec::new(), |f| f.run.season.clone()) }
+    pub fn get_log_likelihood(&self) -> f64 { self.fit.as_ref().map_or(f64::NAN, |f| f.log_likelihood) }
+    pub fn get_aic(&self) -> f64 { self.fit.as_ref().map_or(f64::NAN, |f| f.aic()) }
+    pub fn get_aicc(&self) -> f64 { self.fit.as_ref().map_or(f64::NAN, |f| f.aicc()) }
+    pub fn get_bic(&self) -> f64 { self.fit.as_ref().map_or(f64::NAN, |f| f.bic()) }
+    pub fn get_sigma2(&self) -> f64 { self.fit.as_ref().map_or(f64::NAN, |f| f.sigma2()) }
+
+    // Ranked table of every model fitted (best AICc first)
+    pub fn get_candidates(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.candidates).unwrap()
+    }
+}
+
+// Internal accessors (not exposed to WASM)
+impl Ets {
+    pub fn fitted_model(&self) -> Option<&EtsFit> { self.fit.as_ref() }
+    pub fn candidates(&self) -> &[EtsCandidate] { &self.candidates }
+    pub fn set_fit(&mut self, fit: EtsFit) { self.fit = Some(fit); }
+    pub fn set_candidates(&mut self, candidates: Vec<EtsCandidate>) { self.candidates = candidates; }
+}
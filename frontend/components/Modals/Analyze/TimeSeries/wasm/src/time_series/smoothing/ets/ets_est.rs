@@ -0,0 +1,240 @@
+use crate::{ets_filter, EtsComponent, EtsParams, EtsRun, EtsSpec};
+use finitediff::FiniteDiff;
+use liblbfgs::lbfgs;
+use std::cell::RefCell;
+
+/// ETS model estimated by maximum likelihood
+pub struct EtsFit {
+    pub spec: EtsSpec,
+    pub params: EtsParams,
+    pub init_states: Vec<f64>, // [l_0, b_0, s_0, .., s_{-m+1}]
+    pub run: EtsRun,
+    pub log_likelihood: f64,
+    pub n_params: usize, // Smoothing parameters, free initial states and σ²
+}
+
+impl EtsFit {
+    pub fn aic(&self) -> f64 {
+        -2.0 * self.log_likelihood + 2.0 * self.n_params as f64
+    }
+
+    pub fn aicc(&self) -> f64 {
+        let n = self.run.n_used as f64;
+        let k = self.n_params as f64;
+        if n - k - 1.0 > 0.0 {
+            self.aic() + 2.0 * k * (k + 1.0) / (n - k - 1.0)
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    pub fn bic(&self) -> f64 {
+        -2.0 * self.log_likelihood + self.n_params as f64 * (self.run.n_used as f64).ln()
+    }
+
+    /// Residual variance Σe²/(n - k)
+    pub fn sigma2(&self) -> f64 {
+        let residuals: Vec<f64> = self.run.residuals.iter().filter(|e| !e.is_nan()).cloned().collect();
+        let df = (residuals.len() as f64 - self.n_params as f64).max(1.0);
+        residuals.iter().map(|e| e * e).sum::<f64>() / df
+    }
+}
+
+fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+fn logit(p: f64) -> f64 {
+    (p / (1.0 - p)).ln()
+}
+
+/// Heuristic initial states from the first two seasons (or the first observations
+/// without seasonality): level and slope from the season means, seasonal
+/// deviations from the detrended first season
+pub fn initial_states(spec: &EtsSpec, data: &[f64]) -> Vec<f64> {
+    let observed: Vec<f64> = data.iter().filter(|v| !v.is_nan()).cloned().collect();
+    let m = if spec.has_season() { spec.period } else { 1 };
+    let span = if spec.has_season() { m } else { observed.len().clamp(1, 10) };
+    let mean = |x: &[f64]| x.iter().sum::<f64>() / x.len() as f64;
+    let first = mean(&observed[..span.min(observed.len())]);
+    let second = if observed.len() >= 2 * span { mean(&observed[span..2 * span]) } else { first };
+
+    let mut states = vec![first];
+    let slope = (second - first) / span as f64;
+    if spec.has_trend() {
+        if spec.trend.is_multiplicative() {
+            let ratio = if first > 0.0 && second > 0.0 { (second / first).powf(1.0 / span as f64) } else { 1.0 };
+            states.push(ratio);
+        } else {
+            states.push(slope);
+        }
+    }
+    if spec.has_season() {
+        // s_{-m+1+i} belongs to observation i of the first season
+        let mut season = vec![0.0; m];
+        for i in 0..m.min(observed.len()) {
+            let trend_i = first + slope * (i as f64 - (m as f64 - 1.0) / 2.0);
+            season[m - 1 - i] = if spec.season.is_multiplicative() { observed[i] / trend_i } else { observed[i] - trend_i };
+        }
+        let total = season.iter().sum::<f64>();
+        for s in season.iter_mut() {
+            if spec.season.is_multiplicative() {
+                *s *= m as f64 / total;
+            } else {
+                *s -= total / m as f64;
+            }
+        }
+        states.extend(season);
+    }
+    states
+}
+
+// Map unconstrained parameters to the admissible region
+// 0 < α < 1, 0 < β < α, 0 < γ < 1 - α, 0.8 < φ < 0.98 and to the initial states
+fn to_natural(spec: &EtsSpec, raw: &[f64], scale: f64) -> (EtsParams, Vec<f64>) {
+    let mut idx = 0;
+    let mut next = || {
+        idx += 1;
+        raw[idx - 1]
+    };
+    let alpha = 1e-4 + (1.0 - 2e-4) * logistic(next());
+    let beta = if spec.has_trend() { alpha * logistic(next()) } else { 0.0 };
+    let gamma = if spec.has_season() { (1.0 - alpha) * logistic(next()) } else { 0.0 };
+    let phi = if spec.trend.is_damped() { 0.8 + 0.18 * logistic(next()) } else { 1.0 };
+
+    let mut states = vec![next() * scale];
+    if spec.has_trend() {
+        let b = next();
+        states.push(if spec.trend.is_multiplicative() { b.exp() } else { b * scale });
+    }
+    if spec.has_season() {
+        let m = spec.period;
+        let mut season: Vec<f64> = (0..m - 1)
+            .map(|_| {
+                let s = next();
+                if spec.season.is_multiplicative() { s } else { s * scale }
+            })
+            .collect();
+        let total = season.iter().sum::<f64>();
+        season.push(if spec.season.is_multiplicative() { m as f64 - total } else { -total });
+        states.extend(season);
+    }
+    (EtsParams { alpha, beta, gamma, phi }, states)
+}
+
+fn to_raw(spec: &EtsSpec, params: &EtsParams, states: &[f64], scale: f64) -> Vec<f64> {
+    let mut raw = vec![logit((params.alpha - 1e-4) / (1.0 - 2e-4))];
+    if spec.has_trend() {
+        raw.push(logit(params.beta / params.alpha));
+    }
+    if spec.has_season() {
+        raw.push(logit(params.gamma / (1.0 - params.alpha)));
+    }
+    if spec.trend.is_damped() {
+        raw.push(logit((params.phi - 0.8) / 0.18));
+    }
+    raw.push(states[0] / scale);
+    let mut idx = 1;
+    if spec.has_trend() {
+        raw.push(if spec.trend.is_multiplicative() { states[1].ln() } else { states[1] / scale });
+        idx += 1;
+    }
+    if spec.has_season() {
+        for &s in states[idx..idx + spec.period - 1].iter() {
+            raw.push(if spec.season.is_multiplicative() { s } else { s / scale });
+        }
+    }
+    raw
+}
+
+/// Maximum-likelihood estimates of the smoothing parameters and initial states
+pub fn est_ets(spec: EtsSpec, data: &[f64]) -> EtsFit {
+    let observed: Vec<f64> = data.iter().filter(|v| !v.is_nan()).cloned().collect();
+    let scale = (observed.iter().map(|v| v.abs()).sum::<f64>() / observed.len() as f64).max(1e-8);
+    let start_params = EtsParams { alpha: 0.5, beta: 0.1, gamma: 0.1, phi: 0.9 };
+    let start_states = initial_states(&spec, data);
+    let mut raw = to_raw(&spec, &start_params, &start_states, scale);
+
+    // The line search may stop on an inadmissible trial point, so the best point
+    // visited is kept
+    let best = RefCell::new((f64::INFINITY, raw.clone()));
+    let f = |raw: &Vec<f64>| {
+        let (params, states) = to_natural(&spec, raw, scale);
+        if spec.has_season() && spec.season.is_multiplicative() && states.iter().skip(states.len() - spec.period).any(|s| *s <= 0.0) {
+            return f64::MAX;
+        }
+        let lik = ets_filter(&spec, &params, &states, data).lik;
+        if !lik.is_finite() {
+            return f64::MAX;
+        }
+        let mut best = best.borrow_mut();
+        if lik < best.0 {
+            *best = (lik, raw.clone());
+        }
+        lik
+    };
+    let g = |raw: &Vec<f64>| raw.central_diff(&f);
+    let eval = |x: &[f64], gx: &mut [f64]| {
+        let x_vec = x.to_vec();
+        let fx = f(&x_vec);
+        let gx_eval = g(&x_vec);
+        gx[..gx_eval.len()].copy_from_slice(&gx_eval[..]);
+        Ok(fx)
+    };
+    let fmin = lbfgs().with_max_iterations(300).with_linesearch_algorithm("Backtracking");
+    if let Err(e) = fmin.minimize(&mut raw, eval, |_prgr| false) {
+        tracing::warn!("Got error during ETS fit: {}", e);
+    }
+    let raw = best.into_inner().1;
+
+    let (params, init_states) = to_natural(&spec, &raw, scale);
+    let run = ets_filter(&spec, &params, &init_states, data);
+    let n = run.n_used as f64;
+    let log_likelihood = -0.5 * (run.lik + n * ((2.0 * std::f64::consts::PI).ln() + 1.0 - n.ln()));
+    let n_params = spec.n_smoothing() + spec.n_free_states() + 1;
+    EtsFit {
+        spec,
+        params,
+        init_states,
+        run,
+        log_likelihood,
+        n_params,
+    }
+}
+
+/// Every admissible ETS model for the data: multiplicative error and season need positive
+/// data, additive error is not combined with a multiplicative trend or season
+pub fn ets_candidates(data: &[f64], period: usize, allow_multiplicative_trend: bool) -> Vec<EtsSpec> {
+    let positive = data.iter().filter(|v| !v.is_nan()).all(|v| *v > 0.0);
+    let mut specs = Vec::new();
+    let errors = [EtsComponent::Additive, EtsComponent::Multiplicative];
+    let trends = [
+        EtsComponent::None,
+        EtsComponent::Additive,
+        EtsComponent::AdditiveDamped,
+        EtsComponent::Multiplicative,
+        EtsComponent::MultiplicativeDamped,
+    ];
+    let seasons = [EtsComponent::None, EtsComponent::Additive, EtsComponent::Multiplicative];
+    for &error in errors.iter() {
+        for &trend in trends.iter() {
+            for &season in seasons.iter() {
+                if season != EtsComponent::None && period < 2 {
+                    continue;
+                }
+                if trend.is_multiplicative() && !allow_multiplicative_trend {
+                    continue;
+                }
+                let multiplicative = error.is_multiplicative() || trend.is_multiplicative() || season.is_multiplicative();
+                if multiplicative && !positive {
+                    continue;
+                }
+                if !error.is_multiplicative() && (trend.is_multiplicative() || season.is_multiplicative()) {
+                    continue;
+                }
+                specs.push(EtsSpec { error, trend, season, period });
+            }
+        }
+    }
+    specs
+}
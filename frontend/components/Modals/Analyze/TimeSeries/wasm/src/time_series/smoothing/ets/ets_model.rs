@@ -0,0 +1,219 @@
+/// Error, trend or seasonal component of an ETS model
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EtsComponent {
+    None,
+    Additive,
+    AdditiveDamped,
+    Multiplicative,
+    MultiplicativeDamped,
+}
+
+impl EtsComponent {
+    /// Parse "N", "A", "Ad", "M" or "Md"
+    pub fn parse(code: &str) -> EtsComponent {
+        match code {
+            "N" => EtsComponent::None,
+            "A" => EtsComponent::Additive,
+            "Ad" => EtsComponent::AdditiveDamped,
+            "M" => EtsComponent::Multiplicative,
+            "Md" => EtsComponent::MultiplicativeDamped,
+            _ => panic!("Unknown ETS component: {}", code),
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            EtsComponent::None => "N",
+            EtsComponent::Additive => "A",
+            EtsComponent::AdditiveDamped => "Ad",
+            EtsComponent::Multiplicative => "M",
+            EtsComponent::MultiplicativeDamped => "Md",
+        }
+    }
+
+    pub fn is_damped(&self) -> bool {
+        matches!(self, EtsComponent::AdditiveDamped | EtsComponent::MultiplicativeDamped)
+    }
+
+    pub fn is_multiplicative(&self) -> bool {
+        matches!(self, EtsComponent::Multiplicative | EtsComponent::MultiplicativeDamped)
+    }
+}
+
+/// ETS(error, trend, season) model with seasonal period m.
+/// The initial state vector is laid out as [l_0, b_0, s_0, s_{-1}, .., s_{-m+1}].
+#[derive(Clone, Copy, Debug)]
+pub struct EtsSpec {
+    pub error: EtsComponent,
+    pub trend: EtsComponent,
+    pub season: EtsComponent,
+    pub period: usize,
+}
+
+impl EtsSpec {
+    pub fn new(error: &str, trend: &str, season: &str, period: usize) -> EtsSpec {
+        EtsSpec {
+            error: EtsComponent::parse(error),
+            trend: EtsComponent::parse(trend),
+            season: EtsComponent::parse(season),
+            period,
+        }
+    }
+
+    /// Name such as "ETS(M,Ad,M)"
+    pub fn name(&self) -> String {
+        format!("ETS({},{},{})", self.error.code(), self.trend.code(), self.season.code())
+    }
+
+    pub fn has_trend(&self) -> bool {
+        self.trend != EtsComponent::None
+    }
+
+    pub fn has_season(&self) -> bool {
+        self.season != EtsComponent::None && self.period > 1
+    }
+
+    /// Number of smoothing parameters (α, β, γ, φ as present)
+    pub fn n_smoothing(&self) -> usize {
+        1 + self.has_trend() as usize + self.has_season() as usize + self.trend.is_damped() as usize
+    }
+
+    /// Length of the initial state vector
+    pub fn n_states(&self) -> usize {
+        1 + self.has_trend() as usize + if self.has_season() { self.period } else { 0 }
+    }
+
+    /// Free initial states (the seasonal states are normalized to sum to 0 or m)
+    pub fn n_free_states(&self) -> usize {
+        self.n_states() - self.has_season() as usize
+    }
+}
+
+/// Smoothing parameters; β ≤ α and γ ≤ 1 - α, φ = 1 for undamped trends
+#[derive(Clone, Copy, Debug)]
+pub struct EtsParams {
+    pub alpha: f64,
+    pub beta: f64,
+    pub gamma: f64,
+    pub phi: f64,
+}
+
+/// Output of the ETS recursions over the sample
+pub struct EtsRun {
+    pub fitted: Vec<f64>,    // One-step-ahead forecasts ŷ_t
+    pub residuals: Vec<f64>, // e_t = y_t - ŷ_t (additive error) or (y_t - ŷ_t)/ŷ_t (multiplicative error)
+    pub level: Vec<f64>,     // l_0..l_n
+    pub trend: Vec<f64>,     // b_0..b_n (empty without trend)
+    pub season: Vec<f64>,    // s_0..s_n (empty without season)
+    pub states: Vec<f64>,    // Final state vector, same layout as the initial one
+    pub lik: f64,            // n·ln(Σe²) + 2Σln|ŷ_t| (multiplicative error), i.e. -2 log L up to constants
+    pub n_used: usize,       // Observations that entered the likelihood
+}
+
+/// Point forecast of the state for one step: (ŷ, q, φ-weighted trend) where q is the
+/// level-plus-trend term before the season is applied
+fn one_step(spec: &EtsSpec, phi: f64, level: f64, trend: f64, season: f64) -> (f64, f64, f64) {
+    let (q, phib) = match spec.trend {
+        EtsComponent::None => (level, 0.0),
+        EtsComponent::Additive | EtsComponent::AdditiveDamped => (level + phi * trend, phi * trend),
+        EtsComponent::Multiplicative | EtsComponent::MultiplicativeDamped => {
+            let phib = trend.powf(phi);
+            (level * phib, phib)
+        },
+    };
+    let fitted = if !spec.has_season() {
+        q
+    } else if spec.season.is_multiplicative() {
+        q * season
+    } else {
+        q + season
+    };
+    (fitted, q, phib)
+}
+
+/// Run the ETS recursions (Hyndman et al., 2008) from the initial states.
+/// Missing observations (NaN) are replaced by their one-step forecast and skipped in the likelihood.
+pub fn ets_filter(spec: &EtsSpec, params: &EtsParams, init: &[f64], data: &[f64]) -> EtsRun {
+    let m = spec.period;
+    let phi = if spec.trend.is_damped() { params.phi } else { 1.0 };
+    let mut level = init[0];
+    let mut trend = if spec.has_trend() { init[1] } else { 0.0 };
+    let mut season: Vec<f64> = if spec.has_season() { init[init.len() - m..].to_vec() } else { Vec::new() };
+
+    let mut run = EtsRun {
+        fitted: Vec::with_capacity(data.len()),
+        residuals: Vec::with_capacity(data.len()),
+        level: vec![level],
+        trend: if spec.has_trend() { vec![trend] } else { Vec::new() },
+        season: if spec.has_season() { vec![season[0]] } else { Vec::new() },
+        states: Vec::new(),
+        lik: f64::INFINITY,
+        n_used: 0,
+    };
+    let mut sum_sq = 0.0;
+    let mut sum_log = 0.0;
+
+    for &y in data.iter() {
+        let s_old = if spec.has_season() { season[m - 1] } else { 0.0 };
+        let (fitted, q, phib) = one_step(spec, phi, level, trend, s_old);
+        if !fitted.is_finite() || (spec.error.is_multiplicative() && fitted <= 0.0) {
+            return run;
+        }
+        let y = if y.is_nan() {
+            run.residuals.push(f64::NAN);
+            fitted
+        } else {
+            let e = if spec.error.is_multiplicative() { (y - fitted) / fitted } else { y - fitted };
+            sum_sq += e * e;
+            if spec.error.is_multiplicative() {
+                sum_log += fitted.abs().ln();
+            }
+            run.n_used += 1;
+            run.residuals.push(e);
+            y
+        };
+        run.fitted.push(fitted);
+
+        // Level: l_t = q + α(p - q) with p the seasonally adjusted observation
+        let p = if !spec.has_season() {
+            y
+        } else if spec.season.is_multiplicative() {
+            y / s_old
+        } else {
+            y - s_old
+        };
+        let old_level = level;
+        level = q + params.alpha * (p - q);
+
+        // Growth: b_t = φb + (β/α)(r - φb) with r the realised change in level
+        if spec.has_trend() {
+            let r = if spec.trend.is_multiplicative() { level / old_level } else { level - old_level };
+            trend = phib + params.beta / params.alpha * (r - phib);
+        }
+
+        // Season: s_t = s_{t-m} + γ(t - s_{t-m})
+        if spec.has_season() {
+            let detrended = if spec.season.is_multiplicative() { y / q } else { y - q };
+            season.pop();
+            season.insert(0, s_old + params.gamma * (detrended - s_old));
+        }
+
+        run.level.push(level);
+        if spec.has_trend() {
+            run.trend.push(trend);
+        }
+        if spec.has_season() {
+            run.season.push(season[0]);
+        }
+    }
+
+    run.states = vec![level];
+    if spec.has_trend() {
+        run.states.push(trend);
+    }
+    run.states.extend_from_slice(&season);
+    if run.n_used > 0 && sum_sq > 0.0 {
+        run.lik = run.n_used as f64 * sum_sq.ln() + 2.0 * sum_log;
+    }
+    run
+}
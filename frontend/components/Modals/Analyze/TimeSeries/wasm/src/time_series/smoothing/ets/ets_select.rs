@@ -0,0 +1,52 @@
+use wasm_bindgen::prelude::*;
+use crate::{est_ets, ets_candidates, Ets, EtsSpec};
+use crate::time_series::smoothing::ets::ets::EtsCandidate;
+
+// Internal helpers (not exposed to WASM)
+impl Ets {
+    /// Models matching the requested components; "Z" ranges over the admissible choices
+    pub fn matching_specs(&self) -> Vec<EtsSpec> {
+        let requested = [self.get_error_type(), self.get_trend_type(), self.get_season_type()];
+        if requested.iter().all(|c| c != "Z") {
+            return vec![EtsSpec::new(&requested[0], &requested[1], &requested[2], self.get_period())];
+        }
+        ets_candidates(&self.get_data(), self.get_period(), self.get_allow_multiplicative_trend())
+            .into_iter()
+            .filter(|spec| {
+                let codes = [spec.error.code(), spec.trend.code(), spec.season.code()];
+                requested.iter().zip(codes.iter()).all(|(r, c)| r == "Z" || r == c)
+            })
+            .collect()
+    }
+}
+
+#[wasm_bindgen]
+impl Ets {
+    /// Estimate every matching model by maximum likelihood and keep the one with the
+    /// smallest AICc; returns its name, e.g. "ETS(M,Ad,M)", or an error when no model
+    /// is admissible (e.g. multiplicative components on non-positive data)
+    pub fn fit(&mut self) -> Result<String, JsValue> {
+        let data = self.get_data();
+        let mut fits: Vec<_> = self.matching_specs().into_iter().map(|spec| est_ets(spec, &data)).collect();
+        if fits.is_empty() {
+            return Err(JsValue::from_str("No admissible ETS model for the requested components"));
+        }
+        let key = |aicc: f64| if aicc.is_finite() { aicc } else { f64::INFINITY };
+        fits.sort_by(|a, b| key(a.aicc()).partial_cmp(&key(b.aicc())).unwrap_or(std::cmp::Ordering::Equal));
+        let candidates = fits
+            .iter()
+            .map(|f| EtsCandidate {
+                model: f.spec.name(),
+                log_likelihood: f.log_likelihood,
+                aic: f.aic(),
+                aicc: f.aicc(),
+                bic: f.bic(),
+            })
+            .collect();
+        self.set_candidates(candidates);
+        let best = fits.remove(0);
+        let name = best.spec.name();
+        self.set_fit(best);
+        Ok(name)
+    }
+}
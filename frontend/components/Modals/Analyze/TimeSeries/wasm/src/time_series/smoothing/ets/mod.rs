@@ -0,0 +1,4 @@
+pub mod ets;
+pub mod ets_model;
+pub mod ets_est;
+pub mod ets_select;
\ No newline at end of file
@@ -3,5 +3,7 @@ pub mod moving_average;
 pub mod exponential_smoothing;
 pub mod advance_exponential_smoothing;
 pub mod smoothing_evaluation;
+pub mod smoothing_forecast;
+pub mod ets;
 
 pub use smoothing::Smoothing;
\ No newline at end of file
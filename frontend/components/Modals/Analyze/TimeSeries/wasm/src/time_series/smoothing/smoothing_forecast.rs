@@ -0,0 +1,130 @@
+use wasm_bindgen::prelude::*;
+use crate::Smoothing;
+use crate::time_series::helper_structs::ForecastResult;
+use statrs::distribution::{ContinuousCDF, Normal};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+// Simulated paths behind the Winter's method intervals (fixed seed for reproducible output)
+const WINTER_PATHS: usize = 2000;
+const WINTER_SEED: u64 = 42;
+
+// Internal helpers (not exposed to WASM)
+impl Smoothing {
+    /// One-step errors y_t - ŷ_t from index `start` on
+    fn one_step_errors(&self, fitted: &[f64], start: usize) -> Vec<f64> {
+        let data = self.get_data();
+        (start..data.len()).map(|t| data[t] - fitted[t]).collect()
+    }
+
+    /// Mean squared one-step error σ²
+    fn one_step_variance(&self, fitted: &[f64], start: usize) -> f64 {
+        let errors = self.one_step_errors(fitted, start);
+        errors.iter().map(|e| e * e).sum::<f64>() / errors.len() as f64
+    }
+}
+
+/// Normal prediction intervals forecast ± z·se at the given level
+pub fn normal_forecast_result(forecast: Vec<f64>, se: Vec<f64>, level: f64) -> ForecastResult {
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let z = normal.inverse_cdf(1.0 - (1.0 - level) / 2.0);
+    let lower = forecast.iter().zip(se.iter()).map(|(f, s)| f - z * s).collect();
+    let upper = forecast.iter().zip(se.iter()).map(|(f, s)| f + z * s).collect();
+    ForecastResult::new(forecast, se, lower, upper, level)
+}
+
+#[wasm_bindgen]
+impl Smoothing {
+    /// Simple moving average forecast: the mean of the last `distance` observations for
+    /// every horizon, with the one-step error variance σ²; needs 1 ≤ distance < n
+    pub fn forecast_sma(&self, distance: usize, h: usize, level: f64) -> Result<ForecastResult, JsValue> {
+        let data = self.get_data();
+        let n = data.len();
+        if distance == 0 || distance >= n {
+            return Err(JsValue::from_str(&format!(
+                "Distance must be between 1 and {}, got {}", n.saturating_sub(1), distance
+            )));
+        }
+        let sma = self.calculate_sma(distance);
+        // ŷ_t = SMA_{t-1}
+        let mut fitted = vec![0.0; n];
+        fitted[distance..n].copy_from_slice(&sma[distance - 1..n - 1]);
+        let sigma2 = self.one_step_variance(&fitted, distance);
+        Ok(normal_forecast_result(vec![sma[n - 1]; h], vec![sigma2.sqrt(); h], level))
+    }
+
+    /// Single exponential smoothing forecast, flat at the last smoothed value.
+    /// Var(e_{n+h}) = σ²[1 + α²(h - 1)] as in ETS(A,N,N).
+    pub fn forecast_ses(&self, alpha: f64, h: usize, level: f64) -> ForecastResult {
+        let data = self.get_data();
+        let n = data.len();
+        let ses = self.calculate_ses(alpha);
+        let next = alpha * data[n - 1] + (1.0 - alpha) * ses[n - 1];
+        let sigma2 = self.one_step_variance(&ses, 1.min(n - 1));
+        let se = (1..=h).map(|j| (sigma2 * (1.0 + alpha.powi(2) * (j as f64 - 1.0))).sqrt()).collect();
+        normal_forecast_result(vec![next; h], se, level)
+    }
+
+    /// Holt's linear trend forecast l_n + h·b_n. With β* = αβ,
+    /// Var(e_{n+h}) = σ²[1 + (h - 1){α² + αβ*h + β*²h(2h - 1)/6}] as in ETS(A,A,N).
+    pub fn forecast_holt(&self, alpha: f64, beta: f64, h: usize, level: f64) -> ForecastResult {
+        let states = self.holt_states(alpha, beta);
+        let n = states.level.len();
+        let (last_level, last_trend) = (states.level[n - 1], states.trend[n - 1]);
+        let sigma2 = self.one_step_variance(&states.fitted, 1.min(n - 1));
+        let beta_star = alpha * beta;
+        let mut forecast = Vec::with_capacity(h);
+        let mut se = Vec::with_capacity(h);
+        for j in 1..=h {
+            let j = j as f64;
+            forecast.push(last_level + j * last_trend);
+            let factor = alpha.powi(2) + alpha * beta_star * j + beta_star.powi(2) * j * (2.0 * j - 1.0) / 6.0;
+            se.push((sigma2 * (1.0 + (j - 1.0) * factor)).sqrt());
+        }
+        normal_forecast_result(forecast, se, level)
+    }
+
+    /// Winter's multiplicative forecast (l_n + h·b_n)·s_{n+h-m}. The intervals come from
+    /// simulated paths that feed resampled one-step errors through the smoothing equations.
+    /// Needs 1 ≤ period ≤ n.
+    pub fn forecast_winter(&self, alpha: f64, beta: f64, gamma: f64, period: usize, h: usize, level: f64) -> Result<ForecastResult, JsValue> {
+        let n = self.get_data().len();
+        if period == 0 || period > n {
+            return Err(JsValue::from_str(&format!(
+                "Period must be between 1 and {}, got {}", n, period
+            )));
+        }
+        let states = self.winter_states(alpha, beta, gamma, period);
+        let season_tail = states.seasonal[n - period..].to_vec();
+
+        // Continue the recursions with the given future errors
+        let simulate = |errors: &[f64]| -> Vec<f64> {
+            let mut lvl = states.level[n - 1];
+            let mut trd = states.trend[n - 1];
+            let mut season = season_tail.clone();
+            let mut path = Vec::with_capacity(errors.len());
+            for (j, &e) in errors.iter().enumerate() {
+                let s_old = season[j];
+                let y = (lvl + trd) * s_old + e;
+                let new_level = alpha * (y / s_old) + (1.0 - alpha) * (lvl + trd);
+                trd = beta * (new_level - lvl) + (1.0 - beta) * trd;
+                lvl = new_level;
+                season.push(gamma * (y / lvl) + (1.0 - gamma) * s_old);
+                path.push(y);
+            }
+            path
+        };
+        let forecast = simulate(&vec![0.0; h]);
+
+        let errors = self.one_step_errors(&states.fitted, period.min(n - 1));
+        let mean = errors.iter().sum::<f64>() / errors.len() as f64;
+        let pool: Vec<f64> = errors.iter().map(|e| e - mean).collect();
+        let mut rng = StdRng::seed_from_u64(WINTER_SEED);
+        let mut paths = Vec::with_capacity(WINTER_PATHS);
+        for _ in 0..WINTER_PATHS {
+            let shocks: Vec<f64> = (0..h).map(|_| pool[rng.gen_range(0..pool.len())]).collect();
+            paths.push(simulate(&shocks));
+        }
+        Ok(ForecastResult::from_paths(forecast, paths, level))
+    }
+}
@@ -0,0 +1,63 @@
+use wasm_bindgen::prelude::*;
+use nalgebra::Complex;
+use crate::Spectral;
+use crate::time_series::spectral::spectral_density::daniell_smooth;
+
+#[wasm_bindgen]
+impl Spectral {
+    /// Cross-spectrum of the series with `other` at f_j = j/n, j = 1..⌊n/2⌋, both detrended
+    /// and tapered alike and smoothed by a Daniell window of half-width `span`.
+    /// f_xy = c - i·q from the cross-periodogram X_j·conj(Y_j); stores the co- and
+    /// quadrature spectra, squared coherency |f_xy|²/(f_xx·f_yy) and phase arg(f_xy),
+    /// and returns the squared coherency.
+    pub fn calculate_cross_spectrum(&mut self, other: Vec<f64>, span: usize) -> Result<Vec<f64>, JsValue> {
+        let data = self.get_data();
+        let n = data.len();
+        if n < 3 {
+            return Err(JsValue::from_str("The cross-spectrum needs at least 3 observations"));
+        }
+        if other.len() != n {
+            return Err(JsValue::from_str(&format!(
+                "Series length mismatch: expected {}, got {}", n, other.len()
+            )));
+        }
+        let (x, prepared) = self.fourier_transform(&data);
+        let (y, _) = self.fourier_transform(&other);
+        let scale = n as f64 * prepared.u2;
+        let cross: Vec<Complex<f64>> = x.iter().zip(y.iter()).map(|(a, b)| a * b.conj() / scale).collect();
+
+        // The zero frequency is replaced by the mean of its neighbours, as for the periodogram
+        let fill_zero = |mut v: Vec<f64>| {
+            if n > 2 {
+                v[0] = 0.5 * (v[1] + v[n - 1]);
+            }
+            v
+        };
+        let f_xx = daniell_smooth(&fill_zero(x.iter().map(|v| v.norm_sqr() / scale).collect()), span);
+        let f_yy = daniell_smooth(&fill_zero(y.iter().map(|v| v.norm_sqr() / scale).collect()), span);
+        let co = daniell_smooth(&fill_zero(cross.iter().map(|v| v.re).collect()), span);
+        let quad = daniell_smooth(&fill_zero(cross.iter().map(|v| -v.im).collect()), span);
+
+        let range = 1..=n / 2;
+        self.frequencies = range.clone().map(|j| j as f64 / n as f64).collect();
+        self.co_spectrum = co[range.clone()].to_vec();
+        self.quad_spectrum = quad[range.clone()].to_vec();
+        self.coherency = range
+            .clone()
+            .map(|j| ((co[j].powi(2) + quad[j].powi(2)) / (f_xx[j] * f_yy[j])).min(1.0))
+            .collect();
+        self.phase = range.map(|j| (-quad[j]).atan2(co[j])).collect();
+        self.df = 2.0 * (2 * span + 1) as f64 / (prepared.u4 / prepared.u2.powi(2));
+        self.bandwidth = (2 * span + 1) as f64 / n as f64;
+        Ok(self.coherency.clone())
+    }
+
+    /// Squared coherency exceeded with probability 1 - level under zero coherency:
+    /// 1 - (1 - level)^{1/(ν/2 - 1)} for the df ν of the last cross-spectrum (NaN for ν <= 2)
+    pub fn get_coherency_threshold(&self, level: f64) -> f64 {
+        if self.df.is_nan() || self.df <= 2.0 {
+            return f64::NAN;
+        }
+        1.0 - (1.0 - level).powf(1.0 / (self.df / 2.0 - 1.0))
+    }
+}
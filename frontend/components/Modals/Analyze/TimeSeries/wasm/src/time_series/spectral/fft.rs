@@ -0,0 +1,83 @@
+use nalgebra::Complex;
+use std::f64::consts::PI;
+
+/// Discrete Fourier transform X_j = Σ_t x_t·exp(-2πi·jt/n), radix-2 for powers of two
+/// and Bluestein's chirp-z algorithm for any other length
+pub fn fft(input: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let n = input.len();
+    if n <= 1 || n.is_power_of_two() {
+        let mut data = input.to_vec();
+        radix2(&mut data, false);
+        return data;
+    }
+
+    // X_j = conj(w_j)·Σ_t (x_t·conj(w_t))·w_{j-t} with the chirp w_t = exp(iπt²/n),
+    // evaluated as a circular convolution of power-of-two length
+    let m = (2 * n - 1).next_power_of_two();
+    let chirp: Vec<Complex<f64>> = (0..n)
+        .map(|t| {
+            // t² mod 2n keeps the angle accurate for long series
+            let angle = PI * ((t * t) % (2 * n)) as f64 / n as f64;
+            Complex::new(angle.cos(), angle.sin())
+        })
+        .collect();
+    let mut a = vec![Complex::new(0.0, 0.0); m];
+    for t in 0..n {
+        a[t] = input[t] * chirp[t].conj();
+    }
+    let mut b = vec![Complex::new(0.0, 0.0); m];
+    b[0] = chirp[0];
+    for t in 1..n {
+        b[t] = chirp[t];
+        b[m - t] = chirp[t];
+    }
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    for (a_j, b_j) in a.iter_mut().zip(b.iter()) {
+        *a_j *= b_j;
+    }
+    radix2(&mut a, true);
+    (0..n).map(|j| a[j] * chirp[j].conj()).collect()
+}
+
+// In-place iterative Cooley-Tukey transform; the inverse is scaled by 1/n
+fn radix2(data: &mut [Complex<f64>], inverse: bool) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        let angle = sign * 2.0 * PI / len as f64;
+        let step = Complex::new(angle.cos(), angle.sin());
+        for start in (0..n).step_by(len) {
+            let mut w = Complex::new(1.0, 0.0);
+            for k in 0..len / 2 {
+                let u = data[start + k];
+                let v = data[start + k + len / 2] * w;
+                data[start + k] = u + v;
+                data[start + k + len / 2] = u - v;
+                w *= step;
+            }
+        }
+        len <<= 1;
+    }
+    if inverse {
+        for value in data.iter_mut() {
+            *value /= n as f64;
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use wasm_bindgen::prelude::*;
+use crate::Spectral;
+
+// Above this size the alternating terms of the exact p-value cancel catastrophically
+const FISHER_TERM_LIMIT: f64 = 1e12;
+
+/// P-value of Fisher's g for m periodogram ordinates:
+/// P(G > g) = Σ_{k=1}^{⌊1/g⌋} (-1)^{k-1}·C(m, k)·(1 - kg)^{m-1}, falling back to the
+/// Bonferroni bound m(1 - g)^{m-1} when the exact sum is numerically unreliable
+pub fn fisher_g_pvalue(g: f64, m: usize) -> f64 {
+    let bound = (m as f64 * (1.0 - g).powi(m as i32 - 1)).min(1.0);
+    let mut sum = 0.0;
+    let mut log_binom = 0.0;
+    let upper = ((1.0 / g).floor() as usize).min(m);
+    for k in 1..=upper {
+        log_binom += ((m - k + 1) as f64).ln() - (k as f64).ln();
+        let term = (log_binom + (m as f64 - 1.0) * (1.0 - k as f64 * g).ln()).exp();
+        if term > FISHER_TERM_LIMIT {
+            return bound;
+        }
+        sum += if k % 2 == 1 { term } else { -term };
+    }
+    if (0.0..=1.0).contains(&sum) { sum } else { bound }
+}
+
+#[wasm_bindgen]
+impl Spectral {
+    /// Fisher's test for hidden periodicity, g = max I(f_j) / Σ I(f_j) over
+    /// j = 1..⌊(n-1)/2⌋ (the zero and Nyquist frequencies excluded).
+    /// Returns [g, p-value, frequency of the largest ordinate]; needs n >= 3. All three are
+    /// NaN when the detrended series is zero up to rounding (e.g. a constant series).
+    pub fn fisher_g_test(&self) -> Result<Vec<f64>, JsValue> {
+        let data = self.get_data();
+        let n = data.len();
+        if n < 3 {
+            return Err(JsValue::from_str("Fisher's test needs at least 3 observations"));
+        }
+        let m = (n - 1) / 2;
+        let (ordinates, prepared) = self.full_periodogram(&data);
+        let scale = data.iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+        let rounding = n as f64 * (n as f64 * f64::EPSILON * scale).powi(2);
+        if prepared.values.iter().map(|v| v * v).sum::<f64>() <= rounding {
+            return Ok(vec![f64::NAN; 3]);
+        }
+        let candidates = &ordinates[1..=m];
+        let total: f64 = candidates.iter().sum();
+        let (j_max, max) = candidates
+            .iter()
+            .enumerate()
+            .fold((0, f64::MIN), |best, (j, &v)| if v > best.1 { (j, v) } else { best });
+        let g = max / total;
+        Ok(vec![g, fisher_g_pvalue(g, m), (j_max + 1) as f64 / n as f64])
+    }
+}
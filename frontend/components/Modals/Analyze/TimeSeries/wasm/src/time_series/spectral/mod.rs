@@ -0,0 +1,6 @@
+pub mod spectral;
+pub mod fft;
+pub mod periodogram;
+pub mod spectral_density;
+pub mod fisher_test;
+pub mod cross_spectrum;
\ No newline at end of file
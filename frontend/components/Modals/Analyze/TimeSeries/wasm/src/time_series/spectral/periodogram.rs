@@ -0,0 +1,95 @@
+use wasm_bindgen::prelude::*;
+use nalgebra::Complex;
+use crate::Spectral;
+use crate::time_series::spectral::fft::fft;
+use std::f64::consts::PI;
+
+// Tapered, detrended series with the taper moments U2 = mean(h²) and U4 = mean(h⁴)
+pub struct PreparedSeries {
+    pub values: Vec<f64>,
+    pub u2: f64,
+    pub u4: f64,
+}
+
+// Internal helpers (not exposed to WASM)
+impl Spectral {
+    /// Remove the mean or a least-squares line, then apply a split cosine bell to
+    /// the `taper` proportion of observations at each end
+    pub fn prepare_series(&self, data: &[f64]) -> PreparedSeries {
+        let n = data.len();
+        let mut values = data.to_vec();
+        match self.get_detrend().as_str() {
+            "none" => {},
+            "mean" => {
+                let mean = values.iter().sum::<f64>() / n as f64;
+                values.iter_mut().for_each(|v| *v -= mean);
+            },
+            "linear" => {
+                let mean_t = (n as f64 - 1.0) / 2.0;
+                let mean = values.iter().sum::<f64>() / n as f64;
+                let sxx: f64 = (0..n).map(|t| (t as f64 - mean_t).powi(2)).sum();
+                let sxy: f64 = (0..n).map(|t| (t as f64 - mean_t) * (values[t] - mean)).sum();
+                let slope = if sxx > 0.0 { sxy / sxx } else { 0.0 };
+                for (t, v) in values.iter_mut().enumerate() {
+                    *v -= mean + slope * (t as f64 - mean_t);
+                }
+            },
+            other => panic!("Unknown detrend: {}", other),
+        }
+
+        let m = (self.get_taper().clamp(0.0, 0.5) * n as f64).floor() as usize;
+        let weights: Vec<f64> = (0..n)
+            .map(|t| {
+                let edge = t.min(n - 1 - t);
+                if edge < m {
+                    0.5 * (1.0 - (PI * (2.0 * edge as f64 + 1.0) / (2.0 * m as f64)).cos())
+                } else {
+                    1.0
+                }
+            })
+            .collect();
+        for (v, h) in values.iter_mut().zip(weights.iter()) {
+            *v *= h;
+        }
+        let u2 = weights.iter().map(|h| h.powi(2)).sum::<f64>() / n as f64;
+        let u4 = weights.iter().map(|h| h.powi(4)).sum::<f64>() / n as f64;
+        PreparedSeries { values, u2, u4 }
+    }
+
+    /// DFT of the prepared series at every Fourier frequency j/n, j = 0..n-1
+    pub fn fourier_transform(&self, data: &[f64]) -> (Vec<Complex<f64>>, PreparedSeries) {
+        let prepared = self.prepare_series(data);
+        let input: Vec<Complex<f64>> = prepared.values.iter().map(|&v| Complex::new(v, 0.0)).collect();
+        (fft(&input), prepared)
+    }
+
+    /// Raw periodogram |X_j|²/(n·U2) at j = 0..n-1; the zero frequency is replaced by
+    /// the mean of its neighbours so that smoothing is not pulled down by the removed mean
+    pub fn full_periodogram(&self, data: &[f64]) -> (Vec<f64>, PreparedSeries) {
+        let n = data.len();
+        let (transform, prepared) = self.fourier_transform(data);
+        let mut ordinates: Vec<f64> = transform.iter().map(|x| x.norm_sqr() / (n as f64 * prepared.u2)).collect();
+        if n > 2 {
+            ordinates[0] = 0.5 * (ordinates[1] + ordinates[n - 1]);
+        }
+        (ordinates, prepared)
+    }
+}
+
+#[wasm_bindgen]
+impl Spectral {
+    /// Periodogram I(f_j) = |Σ_t h_t·x_t·exp(-2πi·f_j·t)|² / (n·U2) at the Fourier
+    /// frequencies f_j = j/n, j = 1..⌊n/2⌋, of the detrended and tapered series; needs n >= 3
+    pub fn calculate_periodogram(&mut self) -> Result<Vec<f64>, JsValue> {
+        let data = self.get_data();
+        let n = data.len();
+        if n < 3 {
+            return Err(JsValue::from_str("The periodogram needs at least 3 observations"));
+        }
+        let (ordinates, _) = self.full_periodogram(&data);
+        let periodogram: Vec<f64> = ordinates[1..=n / 2].to_vec();
+        self.frequencies = (1..=n / 2).map(|j| j as f64 / n as f64).collect();
+        self.periodogram = periodogram.clone();
+        Ok(periodogram)
+    }
+}
@@ -0,0 +1,101 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct Spectral {
+    pub(crate) data: Vec<f64>,
+    pub(crate) taper: f64,      // Proportion split-cosine-bell tapered at each end (0 to 0.5)
+    pub(crate) detrend: String, // "none", "mean" or "linear"
+    pub(crate) frequencies: Vec<f64>, // Fourier frequencies j/n in cycles per observation, j = 1..n/2
+    pub(crate) periodogram: Vec<f64>,
+    pub(crate) density: Vec<f64>,
+    pub(crate) density_lower: Vec<f64>,
+    pub(crate) density_upper: Vec<f64>,
+    pub(crate) df: f64,        // Equivalent degrees of freedom of the smoothed density
+    pub(crate) bandwidth: f64, // In cycles per observation
+    pub(crate) co_spectrum: Vec<f64>,
+    pub(crate) quad_spectrum: Vec<f64>,
+    pub(crate) coherency: Vec<f64>, // Squared coherency
+    pub(crate) phase: Vec<f64>,     // In radians
+}
+
+#[wasm_bindgen]
+impl Spectral {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, taper: f64, detrend: String) -> Spectral {
+        Spectral {
+            data,
+            taper,
+            detrend,
+            frequencies: Vec::new(),
+            periodogram: Vec::new(),
+            density: Vec::new(),
+            density_lower: Vec::new(),
+            density_upper: Vec::new(),
+            df: 0.0,
+            bandwidth: 0.0,
+            co_spectrum: Vec::new(),
+            quad_spectrum: Vec::new(),
+            coherency: Vec::new(),
+            phase: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_taper(&self) -> f64 {
+        self.taper
+    }
+    pub fn get_detrend(&self) -> String {
+        self.detrend.clone()
+    }
+    pub fn get_frequencies(&self) -> Vec<f64> {
+        self.frequencies.clone()
+    }
+    /// Periods 1/f matching get_frequencies
+    pub fn get_periods(&self) -> Vec<f64> {
+        self.frequencies.iter().map(|f| 1.0 / f).collect()
+    }
+    pub fn get_periodogram(&self) -> Vec<f64> {
+        self.periodogram.clone()
+    }
+    pub fn get_density(&self) -> Vec<f64> {
+        self.density.clone()
+    }
+    pub fn get_density_lower(&self) -> Vec<f64> {
+        self.density_lower.clone()
+    }
+    pub fn get_density_upper(&self) -> Vec<f64> {
+        self.density_upper.clone()
+    }
+    pub fn get_df(&self) -> f64 {
+        self.df
+    }
+    pub fn get_bandwidth(&self) -> f64 {
+        self.bandwidth
+    }
+    pub fn get_co_spectrum(&self) -> Vec<f64> {
+        self.co_spectrum.clone()
+    }
+    pub fn get_quad_spectrum(&self) -> Vec<f64> {
+        self.quad_spectrum.clone()
+    }
+    pub fn get_coherency(&self) -> Vec<f64> {
+        self.coherency.clone()
+    }
+    pub fn get_phase(&self) -> Vec<f64> {
+        self.phase.clone()
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>) {
+        self.data = data;
+    }
+    pub fn set_taper(&mut self, taper: f64) {
+        self.taper = taper;
+    }
+    pub fn set_detrend(&mut self, detrend: String) {
+        self.detrend = detrend;
+    }
+}
@@ -0,0 +1,85 @@
+use wasm_bindgen::prelude::*;
+use crate::Spectral;
+use statrs::distribution::{ChiSquared, ContinuousCDF};
+use std::f64::consts::PI;
+
+/// Lag window w(u), |u| ≤ 1, of the "tukey_hamming" or "parzen" estimator
+pub fn lag_window(window: &str, u: f64) -> f64 {
+    let u = u.abs();
+    if u > 1.0 {
+        return 0.0;
+    }
+    match window {
+        "tukey_hamming" => 0.54 + 0.46 * (PI * u).cos(),
+        "parzen" => {
+            if u <= 0.5 {
+                1.0 - 6.0 * u * u + 6.0 * u.powi(3)
+            } else {
+                2.0 * (1.0 - u).powi(3)
+            }
+        },
+        _ => panic!("Unknown window: {}", window),
+    }
+}
+
+/// Circular moving average of half-width m over periodogram ordinates j = 0..n-1
+pub fn daniell_smooth(ordinates: &[f64], m: usize) -> Vec<f64> {
+    let n = ordinates.len() as isize;
+    let width = (2 * m + 1) as f64;
+    (0..n)
+        .map(|j| (-(m as isize)..=m as isize).map(|k| ordinates[(j + k).rem_euclid(n) as usize]).sum::<f64>() / width)
+        .collect()
+}
+
+#[wasm_bindgen]
+impl Spectral {
+    /// Smoothed spectral density at f_j = j/n, j = 1..⌊n/2⌋, with χ² confidence bands.
+    /// "daniell" averages 2·span + 1 neighbouring periodogram ordinates; "tukey_hamming"
+    /// and "parzen" weight the autocovariances up to lag M = span by the lag window.
+    /// Equivalent df ν = 2n·B / (U4/U2²) with bandwidth B = 1/(n·Σw_j²) (Daniell) or
+    /// 1/Σ_{|k|≤M} w(k/M)² (lag windows); the band is [ν·f/χ²_{ν,1-α/2}, ν·f/χ²_{ν,α/2}].
+    pub fn calculate_density(&mut self, window: String, span: usize, level: f64) -> Result<Vec<f64>, JsValue> {
+        let data = self.get_data();
+        let n = data.len();
+        if n < 3 {
+            return Err(JsValue::from_str("The spectral density needs at least 3 observations"));
+        }
+        let (density, bandwidth, prepared) = match window.as_str() {
+            "daniell" => {
+                let (ordinates, prepared) = self.full_periodogram(&data);
+                let smoothed = daniell_smooth(&ordinates, span);
+                (smoothed[1..=n / 2].to_vec(), (2 * span + 1) as f64 / n as f64, prepared)
+            },
+            "tukey_hamming" | "parzen" => {
+                let prepared = self.prepare_series(&data);
+                let x = &prepared.values;
+                let max_lag = span.clamp(1, n - 1);
+                let autocov: Vec<f64> = (0..=max_lag)
+                    .map(|k| (0..n - k).map(|t| x[t] * x[t + k]).sum::<f64>() / (n as f64 * prepared.u2))
+                    .collect();
+                let weights: Vec<f64> = (0..=max_lag).map(|k| lag_window(&window, k as f64 / max_lag as f64)).collect();
+                let density = (1..=n / 2)
+                    .map(|j| {
+                        let f = j as f64 / n as f64;
+                        autocov[0] + 2.0 * (1..=max_lag).map(|k| weights[k] * autocov[k] * (2.0 * PI * f * k as f64).cos()).sum::<f64>()
+                    })
+                    .collect();
+                let sum_w2 = 1.0 + 2.0 * weights[1..].iter().map(|w| w * w).sum::<f64>();
+                (density, 1.0 / sum_w2, prepared)
+            },
+            _ => panic!("Unknown window: {}", window),
+        };
+
+        let df = 2.0 * n as f64 * bandwidth / (prepared.u4 / prepared.u2.powi(2));
+        let chi2 = ChiSquared::new(df).unwrap();
+        let alpha = 1.0 - level;
+        let (chi_upper, chi_lower) = (chi2.inverse_cdf(1.0 - alpha / 2.0), chi2.inverse_cdf(alpha / 2.0));
+        self.frequencies = (1..=n / 2).map(|j| j as f64 / n as f64).collect();
+        self.density_lower = density.iter().map(|f| df * f / chi_upper).collect();
+        self.density_upper = density.iter().map(|f| df * f / chi_lower).collect();
+        self.df = df;
+        self.bandwidth = bandwidth;
+        self.density = density.clone();
+        Ok(density)
+    }
+}
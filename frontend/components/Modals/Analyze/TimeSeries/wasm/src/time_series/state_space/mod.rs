@@ -0,0 +1,4 @@
+pub mod structural_model;
+pub mod state_space_kalman;
+pub mod structural_estimate;
+pub mod structural_forecast;
\ No newline at end of file
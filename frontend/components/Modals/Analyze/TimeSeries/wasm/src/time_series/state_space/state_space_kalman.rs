@@ -0,0 +1,134 @@
+use nalgebra::{DMatrix, DVector};
+use std::f64::consts::PI;
+
+/// Univariate linear Gaussian state-space model
+/// y_t = z'α_t + ε_t, ε_t ~ N(0, h);  α_{t+1} = T·α_t + η_t, η_t ~ N(0, Q)
+pub struct StateSpaceModel {
+    pub z: DVector<f64>,
+    pub t_mat: DMatrix<f64>,
+    pub q_mat: DMatrix<f64>,
+    pub h: f64,
+}
+
+/// Output of the Kalman filter (innovations are NaN at missing observations)
+pub struct StateSpaceOutput {
+    pub predicted: Vec<DVector<f64>>,     // a_t = E(α_t | y_1..y_{t-1}), t = 1..n+1
+    pub predicted_var: Vec<DMatrix<f64>>, // P_t
+    pub filtered: Vec<DVector<f64>>,      // E(α_t | y_1..y_t)
+    pub filtered_var: Vec<DMatrix<f64>>,
+    pub innovations: Vec<f64>,            // v_t = y_t - z'a_t
+    pub innovation_var: Vec<f64>,         // F_t = z'P_t·z + h
+    pub log_likelihood: f64,              // Diffuse: the first m observations are conditioned on
+    pub n_used: usize,
+}
+
+/// Kalman recursions started from a_1 = 0, P_1 = diffuse·I, calling `step` with
+/// (a_t, P_t, filtered state, filtered variance, v_t, F_t) at every t and returning the
+/// log-likelihood with the number of terms in it. Missing observations (NaN) skip the
+/// update step, and the first m (state dimension) observations are left out of the
+/// log-likelihood as they only identify the diffuse initial state.
+fn kalman_recursions<F>(model: &StateSpaceModel, data: &[f64], diffuse: f64, mut step: F) -> (f64, usize)
+where
+    F: FnMut(&DVector<f64>, &DMatrix<f64>, &DVector<f64>, &DMatrix<f64>, f64, f64),
+{
+    let m = model.z.len();
+    let t_transpose = model.t_mat.transpose();
+    let mut a = DVector::<f64>::zeros(m);
+    let mut p_mat = DMatrix::<f64>::identity(m, m) * diffuse;
+    let mut a_f = a.clone();
+    let mut p_f = p_mat.clone();
+    let mut pz = DVector::<f64>::zeros(m);
+    let mut p_t = DMatrix::<f64>::zeros(m, m);
+    let (mut log_likelihood, mut n_used, mut n_observed) = (0.0, 0, 0);
+    for &y in data.iter() {
+        a_f.copy_from(&a);
+        p_f.copy_from(&p_mat);
+        let (v, f) = if y.is_nan() {
+            (f64::NAN, f64::NAN)
+        } else {
+            pz.gemv(1.0, &p_mat, &model.z, 0.0);
+            let f = model.z.dot(&pz) + model.h;
+            let v = y - model.z.dot(&a);
+            n_observed += 1;
+            if n_observed > m {
+                log_likelihood -= 0.5 * ((2.0 * PI).ln() + f.ln() + v * v / f);
+                n_used += 1;
+            }
+            a_f.axpy(v / f, &pz, 1.0);
+            p_f.ger(-1.0 / f, &pz, &pz, 1.0);
+            (v, f)
+        };
+        step(&a, &p_mat, &a_f, &p_f, v, f);
+        // a_{t+1} = T·a_{t|t}, P_{t+1} = T·P_{t|t}·T' + Q
+        a.gemv(1.0, &model.t_mat, &a_f, 0.0);
+        p_t.gemm(1.0, &p_f, &t_transpose, 0.0);
+        p_mat.gemm(1.0, &model.t_mat, &p_t, 0.0);
+        p_mat += &model.q_mat;
+    }
+    step(&a, &p_mat, &a_f, &p_f, f64::NAN, f64::NAN);
+    (log_likelihood, n_used)
+}
+
+/// Kalman filter started from a_1 = 0, P_1 = diffuse·I (see kalman_recursions)
+pub fn state_space_filter(model: &StateSpaceModel, data: &[f64], diffuse: f64) -> StateSpaceOutput {
+    let n = data.len();
+    let mut out = StateSpaceOutput {
+        predicted: Vec::with_capacity(n + 1),
+        predicted_var: Vec::with_capacity(n + 1),
+        filtered: Vec::with_capacity(n),
+        filtered_var: Vec::with_capacity(n),
+        innovations: Vec::with_capacity(n),
+        innovation_var: Vec::with_capacity(n),
+        log_likelihood: 0.0,
+        n_used: 0,
+    };
+    let (log_likelihood, n_used) = kalman_recursions(model, data, diffuse, |a, p_mat, a_f, p_f, v, f| {
+        out.predicted.push(a.clone());
+        out.predicted_var.push(p_mat.clone());
+        if out.filtered.len() < n {
+            out.filtered.push(a_f.clone());
+            out.filtered_var.push(p_f.clone());
+            out.innovations.push(v);
+            out.innovation_var.push(f);
+        }
+    });
+    out.log_likelihood = log_likelihood;
+    out.n_used = n_used;
+    out
+}
+
+/// Diffuse log-likelihood alone, without storing the states
+pub fn state_space_log_likelihood(model: &StateSpaceModel, data: &[f64], diffuse: f64) -> f64 {
+    kalman_recursions(model, data, diffuse, |_, _, _, _, _, _| {}).0
+}
+
+/// Smoothed states E(α_t | y_1..y_n) and their variances by the fixed-interval smoother
+/// of de Jong: r_{t-1} = z·v_t/F_t + L_t'r_t, N_{t-1} = z·z'/F_t + L_t'N_t·L_t with
+/// L_t = T - T·P_t·z·z'/F_t, then α̂_t = a_t + P_t·r_{t-1} and V_t = P_t - P_t·N_{t-1}·P_t.
+/// No matrix inversion is needed, so singular state variances are fine.
+pub fn state_space_smoother(model: &StateSpaceModel, filter: &StateSpaceOutput) -> (Vec<DVector<f64>>, Vec<DMatrix<f64>>) {
+    let m = model.z.len();
+    let n = filter.innovations.len();
+    let mut r = DVector::<f64>::zeros(m);
+    let mut n_mat = DMatrix::<f64>::zeros(m, m);
+    let mut smoothed = vec![DVector::<f64>::zeros(m); n];
+    let mut smoothed_var = vec![DMatrix::<f64>::zeros(m, m); n];
+    let zz = &model.z * model.z.transpose();
+    for t in (0..n).rev() {
+        let p_mat = &filter.predicted_var[t];
+        let v = filter.innovations[t];
+        if v.is_nan() {
+            r = model.t_mat.transpose() * &r;
+            n_mat = model.t_mat.transpose() * &n_mat * &model.t_mat;
+        } else {
+            let f = filter.innovation_var[t];
+            let gain = &model.t_mat * p_mat * &model.z / f;
+            let l_mat = &model.t_mat - &gain * model.z.transpose();
+            r = &model.z * (v / f) + l_mat.transpose() * &r;
+            n_mat = &zz / f + l_mat.transpose() * &n_mat * &l_mat;
+        }
+        smoothed[t] = &filter.predicted[t] + p_mat * &r;
+        smoothed_var[t] = p_mat - p_mat * &n_mat * p_mat;
+    }
+    (smoothed, smoothed_var)
+}
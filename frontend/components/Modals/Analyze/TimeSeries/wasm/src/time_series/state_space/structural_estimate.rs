@@ -0,0 +1,110 @@
+use wasm_bindgen::prelude::*;
+use crate::StructuralModel;
+use crate::time_series::state_space::state_space_kalman::{state_space_filter, state_space_log_likelihood, state_space_smoother, StateSpaceOutput};
+use liblbfgs::lbfgs;
+
+// Diffuse prior variance of the initial states, relative to the data variance
+const DIFFUSE_SCALE: f64 = 1e6;
+
+// Internal helpers (not exposed to WASM)
+impl StructuralModel {
+    /// Kalman filter of `data` (the model's data, rescaled or extended) for the given variances,
+    /// with the diffuse prior scaled to the variance of `data`
+    pub fn kalman(&self, data: &[f64], variances: &[f64]) -> StateSpaceOutput {
+        state_space_filter(&self.system(variances), data, Self::diffuse_variance(data))
+    }
+
+    /// Prior variance of the diffuse initial states for `data`
+    fn diffuse_variance(data: &[f64]) -> f64 {
+        let observed: Vec<f64> = data.iter().filter(|v| !v.is_nan()).cloned().collect();
+        let mean = observed.iter().sum::<f64>() / observed.len() as f64;
+        let var = observed.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / observed.len() as f64;
+        DIFFUSE_SCALE * var.max(1e-12)
+    }
+}
+
+#[wasm_bindgen]
+impl StructuralModel {
+    /// Maximum-likelihood variances [irregular, level, slope, seasonal] as squares of free parameters,
+    /// on the data scaled to unit variance; the states are then filtered and smoothed.
+    /// Returns the log-likelihood.
+    pub fn fit(&mut self) -> Result<f64, JsValue> {
+        let observed: Vec<f64> = self.data.iter().filter(|v| !v.is_nan()).cloned().collect();
+        let mean = observed.iter().sum::<f64>() / observed.len() as f64;
+        let var = (observed.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / observed.len() as f64).max(1e-12);
+        let scaled: Vec<f64> = self.data.iter().map(|v| (v - mean) / var.sqrt()).collect();
+        let k = self.n_variances();
+        let to_variances = |raw: &[f64]| -> Vec<f64> { raw.iter().map(|r| r * r).collect() };
+
+        // Start from the variance of the first differences shared out over the components
+        let observed: Vec<f64> = scaled.iter().filter(|v| !v.is_nan()).cloned().collect();
+        let diff_var = observed.windows(2).map(|w| (w[1] - w[0]).powi(2)).sum::<f64>() / (observed.len() - 1) as f64;
+        let mut raw = vec![(diff_var.max(1e-4) / k as f64).sqrt(); k];
+
+        let diffuse = Self::diffuse_variance(&scaled);
+        let f = |raw: &Vec<f64>| {
+            let ll = state_space_log_likelihood(&self.system(&to_variances(raw)), &scaled, diffuse);
+            if ll.is_finite() { -ll } else { f64::MAX }
+        };
+        // Central differences with a step above the rounding noise of the diffuse start
+        let g = |raw: &Vec<f64>| -> Vec<f64> {
+            (0..raw.len())
+                .map(|i| {
+                    let h = 1e-6 * raw[i].abs().max(1.0);
+                    let (mut up, mut down) = (raw.clone(), raw.clone());
+                    up[i] += h;
+                    down[i] -= h;
+                    (f(&up) - f(&down)) / (2.0 * h)
+                })
+                .collect()
+        };
+        let eval = |x: &[f64], gx: &mut [f64]| {
+            let x_vec = x.to_vec();
+            let fx = f(&x_vec);
+            let gx_eval = g(&x_vec);
+            gx[..gx_eval.len()].copy_from_slice(&gx_eval[..]);
+            Ok(fx)
+        };
+        // The likelihood is flat near the optimum, so stop once three iterations gain
+        // less than 1e-6 in relative terms
+        let mut history: Vec<f64> = Vec::new();
+        let stalled = |fx: f64, history: &mut Vec<f64>| {
+            history.push(fx);
+            let k = history.len();
+            k > 3 && history[k - 4] - fx < 1e-6 * (1.0 + fx.abs())
+        };
+        let fmin = lbfgs().with_max_iterations(300).with_linesearch_algorithm("Backtracking");
+        if let Err(e) = fmin.minimize(&mut raw, eval, |prgr| stalled(prgr.fx, &mut history)) {
+            tracing::warn!("Got error during structural model fit: {}", e);
+        }
+
+        self.variances = to_variances(&raw).iter().map(|v| v * var).collect();
+        self.run_filter()
+    }
+
+    /// Filter and smooth the states with the current variances; returns the log-likelihood
+    pub fn run_filter(&mut self) -> Result<f64, JsValue> {
+        if self.variances.len() != self.n_variances() {
+            return Err(JsValue::from_str("Fit the model or set its variances first"));
+        }
+        let system = self.system(&self.variances);
+        let filter = self.kalman(&self.data, &self.variances);
+        let (smoothed, smoothed_var) = state_space_smoother(&system, &filter);
+        self.filtered = filter.filtered.iter().flat_map(|a| a.iter().cloned()).collect();
+        self.filtered_var = filter.filtered_var.iter().flat_map(|p| p.diagonal().iter().cloned().collect::<Vec<f64>>()).collect();
+        self.smoothed = smoothed.iter().flat_map(|a| a.iter().cloned()).collect();
+        self.smoothed_var = smoothed_var.iter().flat_map(|p| p.diagonal().iter().cloned().collect::<Vec<f64>>()).collect();
+        self.log_likelihood = filter.log_likelihood;
+        Ok(self.log_likelihood)
+    }
+
+    /// AIC = -2 log L + 2(k + m) with k variances and m diffuse initial states
+    pub fn calculate_aic(&self) -> f64 {
+        -2.0 * self.log_likelihood + 2.0 * (self.n_variances() + self.get_n_states()) as f64
+    }
+
+    pub fn calculate_bic(&self) -> f64 {
+        let n = self.data.iter().filter(|v| !v.is_nan()).count() as f64;
+        -2.0 * self.log_likelihood + (self.n_variances() + self.get_n_states()) as f64 * n.ln()
+    }
+}
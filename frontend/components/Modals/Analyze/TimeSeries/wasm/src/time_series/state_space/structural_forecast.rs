@@ -0,0 +1,27 @@
+use wasm_bindgen::prelude::*;
+use crate::StructuralModel;
+use crate::time_series::helper_structs::ForecastResult;
+use crate::time_series::smoothing::smoothing_forecast::normal_forecast_result;
+
+#[wasm_bindgen]
+impl StructuralModel {
+    /// h-step forecasts z'a_{n+j} with variance z'P_{n+j}·z + σ²_ε, continuing the state
+    /// prediction past the sample with the current variances
+    pub fn forecast_ahead(&self, h: usize, level: f64) -> Result<ForecastResult, JsValue> {
+        if self.variances.len() != self.n_variances() {
+            return Err(JsValue::from_str("Fit the model or set its variances first"));
+        }
+        let mut extended = self.data.clone();
+        extended.extend(std::iter::repeat_n(f64::NAN, h));
+        let system = self.system(&self.variances);
+        let filter = self.kalman(&extended, &self.variances);
+        let n = self.data.len();
+        let mut forecast = Vec::with_capacity(h);
+        let mut se = Vec::with_capacity(h);
+        for t in n..n + h {
+            forecast.push(system.z.dot(&filter.predicted[t]));
+            se.push((system.z.dot(&(&filter.predicted_var[t] * &system.z)) + system.h).sqrt());
+        }
+        Ok(normal_forecast_result(forecast, se, level))
+    }
+}
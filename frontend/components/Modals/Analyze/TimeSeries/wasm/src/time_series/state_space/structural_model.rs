@@ -0,0 +1,201 @@
+use wasm_bindgen::prelude::*;
+use nalgebra::{DMatrix, DVector};
+use crate::time_series::state_space::state_space_kalman::StateSpaceModel;
+
+/// Structural time-series model y_t = μ_t + γ_t + ε_t with
+/// "level":  μ_{t+1} = μ_t + η_t (local level)
+/// "trend":  μ_{t+1} = μ_t + ν_t + η_t, ν_{t+1} = ν_t + ζ_t (local linear trend)
+/// "bsm":    local linear trend plus a dummy seasonal γ_{t+1} = -Σ_{j<s-1} γ_{t-j} + ω_t
+#[wasm_bindgen]
+pub struct StructuralModel {
+    pub(crate) data: Vec<f64>,      // Missing observations are NaN
+    pub(crate) model: String,       // "level", "trend" or "bsm"
+    pub(crate) period: usize,       // Seasonal period (bsm only)
+    pub(crate) variances: Vec<f64>, // [irregular, level, slope, seasonal] for the model's components
+    pub(crate) log_likelihood: f64,
+    pub(crate) filtered: Vec<f64>,  // States flattened as [t][state]
+    pub(crate) filtered_var: Vec<f64>, // Diagonal of the state variances, same layout
+    pub(crate) smoothed: Vec<f64>,
+    pub(crate) smoothed_var: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl StructuralModel {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, model: String, period: usize) -> Result<StructuralModel, JsValue> {
+        match model.as_str() {
+            "level" | "trend" => {},
+            "bsm" => {
+                if period < 2 {
+                    return Err(JsValue::from_str("The basic structural model needs a period of at least 2"));
+                }
+            },
+            _ => panic!("Unknown model: {}", model),
+        }
+        let n_observed = data.iter().filter(|v| !v.is_nan()).count();
+        let n_states = Self::state_dimension(&model, period);
+        if n_observed <= n_states + 1 {
+            return Err(JsValue::from_str("Not enough observations for the model"));
+        }
+        Ok(StructuralModel {
+            data,
+            model,
+            period,
+            variances: Vec::new(),
+            log_likelihood: f64::NAN,
+            filtered: Vec::new(),
+            filtered_var: Vec::new(),
+            smoothed: Vec::new(),
+            smoothed_var: Vec::new(),
+        })
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_model(&self) -> String {
+        self.model.clone()
+    }
+    pub fn get_period(&self) -> usize {
+        self.period
+    }
+    pub fn get_variances(&self) -> Vec<f64> {
+        self.variances.clone()
+    }
+    pub fn get_log_likelihood(&self) -> f64 {
+        self.log_likelihood
+    }
+    pub fn get_n_states(&self) -> usize {
+        Self::state_dimension(&self.model, self.period)
+    }
+    pub fn get_filtered(&self) -> Vec<f64> {
+        self.filtered.clone()
+    }
+    pub fn get_filtered_var(&self) -> Vec<f64> {
+        self.filtered_var.clone()
+    }
+    pub fn get_smoothed(&self) -> Vec<f64> {
+        self.smoothed.clone()
+    }
+    pub fn get_smoothed_var(&self) -> Vec<f64> {
+        self.smoothed_var.clone()
+    }
+
+    /// One component ("level", "slope" or "seasonal") of the smoothed or filtered states
+    /// (empty before fit or run_filter)
+    pub fn get_component(&self, name: String, smoothed: bool) -> Vec<f64> {
+        let states = if smoothed { &self.smoothed } else { &self.filtered };
+        self.component_of(states, &name)
+    }
+
+    /// Variance of a component of the smoothed or filtered states
+    pub fn get_component_var(&self, name: String, smoothed: bool) -> Vec<f64> {
+        let vars = if smoothed { &self.smoothed_var } else { &self.filtered_var };
+        self.component_of(vars, &name)
+    }
+
+    /// y_t - μ̂_t - γ̂_t from the smoothed states (NaN at missing observations);
+    /// empty until fit or run_filter has smoothed the current data
+    pub fn get_irregular(&self) -> Vec<f64> {
+        let m = self.get_n_states();
+        if self.smoothed.len() != self.data.len() * m {
+            return Vec::new();
+        }
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(t, y)| {
+                let seasonal = if self.model == "bsm" { self.smoothed[t * m + 2] } else { 0.0 };
+                y - self.smoothed[t * m] - seasonal
+            })
+            .collect()
+    }
+
+    // Setters
+    /// New series (same observation-count check as the constructor); drops the states
+    /// and log-likelihood of the previous data
+    pub fn set_data(&mut self, data: Vec<f64>) -> Result<(), JsValue> {
+        let n_observed = data.iter().filter(|v| !v.is_nan()).count();
+        if n_observed <= self.get_n_states() + 1 {
+            return Err(JsValue::from_str("Not enough observations for the model"));
+        }
+        self.data = data;
+        self.log_likelihood = f64::NAN;
+        self.filtered = Vec::new();
+        self.filtered_var = Vec::new();
+        self.smoothed = Vec::new();
+        self.smoothed_var = Vec::new();
+        Ok(())
+    }
+    /// Fixed variances [irregular, level, slope, seasonal] (as many as the model has);
+    /// run_filter then gives the states without estimation
+    pub fn set_variances(&mut self, variances: Vec<f64>) -> Result<(), JsValue> {
+        if variances.len() != self.n_variances() || variances.iter().any(|v| v.is_nan() || *v < 0.0) {
+            return Err(JsValue::from_str(&format!(
+                "Expected {} non-negative variances", self.n_variances()
+            )));
+        }
+        self.variances = variances;
+        Ok(())
+    }
+}
+
+// Internal helpers (not exposed to WASM)
+impl StructuralModel {
+    pub fn state_dimension(model: &str, period: usize) -> usize {
+        match model {
+            "level" => 1,
+            "trend" => 2,
+            _ => 1 + period,
+        }
+    }
+
+    pub fn n_variances(&self) -> usize {
+        match self.model.as_str() {
+            "level" => 2,
+            "trend" => 3,
+            _ => 4,
+        }
+    }
+
+    /// Column `name` of a [t][state] flattened matrix
+    fn component_of(&self, values: &[f64], name: &str) -> Vec<f64> {
+        let m = self.get_n_states();
+        let index = match (name, self.model.as_str()) {
+            ("level", _) => 0,
+            ("slope", "trend") | ("slope", "bsm") => 1,
+            ("seasonal", "bsm") => 2,
+            ("slope", _) | ("seasonal", _) => return vec![0.0; values.len() / m],
+            _ => panic!("Unknown component: {}", name),
+        };
+        values.iter().skip(index).step_by(m).cloned().collect()
+    }
+
+    /// System matrices for the variances [irregular, level, slope, seasonal]
+    pub fn system(&self, variances: &[f64]) -> StateSpaceModel {
+        let m = self.get_n_states();
+        let mut z = DVector::<f64>::zeros(m);
+        let mut t_mat = DMatrix::<f64>::zeros(m, m);
+        let mut q_mat = DMatrix::<f64>::zeros(m, m);
+        z[0] = 1.0;
+        t_mat[(0, 0)] = 1.0;
+        q_mat[(0, 0)] = variances[1];
+        if m > 1 {
+            t_mat[(0, 1)] = 1.0;
+            t_mat[(1, 1)] = 1.0;
+            q_mat[(1, 1)] = variances[2];
+        }
+        if self.model == "bsm" {
+            z[2] = 1.0;
+            for j in 2..m {
+                t_mat[(2, j)] = -1.0;
+            }
+            for j in 3..m {
+                t_mat[(j, j - 1)] = 1.0;
+            }
+            q_mat[(2, 2)] = variances[3];
+        }
+        StateSpaceModel { z, t_mat, q_mat, h: variances[0] }
+    }
+}
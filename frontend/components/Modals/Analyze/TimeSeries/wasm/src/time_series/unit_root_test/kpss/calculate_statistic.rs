@@ -0,0 +1,58 @@
+use wasm_bindgen::prelude::*;
+use crate::Kpss;
+use crate::time_series::ecm::ols_helper::ols;
+use crate::time_series::unit_root_test::unit_root_helper::{bartlett_long_run_variance, deterministic_columns, interpolate_pvalue, newey_west_bandwidth, prepare_level};
+use crate::time_series::unit_root_test::unit_root_helper::{KPSS_LEVEL_CRITICAL, KPSS_PROBS, KPSS_TREND_CRITICAL};
+
+// Internal helpers (not exposed to WASM)
+impl Kpss {
+    fn critical_table(&self) -> [f64; 4] {
+        match self.get_equation().as_str() {
+            "no_trend" => KPSS_LEVEL_CRITICAL,
+            "with_trend" => KPSS_TREND_CRITICAL,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Kpss {
+    /// p-value interpolated in the KPSS table, so it is truncated to [0.01, 0.10]
+    pub fn calculate_pvalue(&self) -> f64 {
+        interpolate_pvalue(self.get_test_stat(), &self.critical_table(), &KPSS_PROBS)
+    }
+
+    /// Critical values at 1%, 5% and 10%
+    pub fn calculate_critical_value(&self) -> Vec<f64> {
+        let table = self.critical_table();
+        vec![table[3], table[1], table[0]]
+    }
+
+    /// η = ΣS_t² / (n²·σ̂²_LR), with S_t the partial sums of the residuals from the
+    /// regression on a constant (and trend) and σ̂²_LR the Bartlett long-run variance
+    pub fn calculate_test_stat(&mut self) -> f64 {
+        let data = prepare_level(self.get_data(), &self.get_level());
+        let n = data.len();
+        let columns = deterministic_columns(n, &self.get_equation());
+        let residuals = match ols(&columns, &data) {
+            Some(fit) => fit.residuals,
+            None => return f64::NAN,
+        };
+
+        let lag = newey_west_bandwidth(&residuals, &self.get_bandwidth());
+        let long_run_variance = bartlett_long_run_variance(&residuals, lag);
+        let mut partial_sum = 0.0;
+        let mut sum_sq = 0.0;
+        for e in residuals.iter() {
+            partial_sum += e;
+            sum_sq += partial_sum * partial_sum;
+        }
+        let test_stat = sum_sq / ((n * n) as f64 * long_run_variance);
+
+        self.set_lag(lag);
+        self.set_residuals(residuals);
+        self.set_long_run_variance(long_run_variance);
+        self.set_test_stat(test_stat);
+        test_stat
+    }
+}
@@ -0,0 +1,94 @@
+use wasm_bindgen::prelude::*;
+
+// The KPSS tables cover level ("no_trend") and trend ("with_trend") stationarity only
+fn check_equation(equation: &str) -> Result<(), JsValue> {
+    match equation {
+        "no_trend" | "with_trend" => Ok(()),
+        _ => Err(JsValue::from_str(&format!("Unknown equation: {}", equation))),
+    }
+}
+
+/// KPSS test of the null of level or trend stationarity
+#[wasm_bindgen]
+pub struct Kpss {
+    data: Vec<f64>,
+    equation: String,  // "no_trend" (level stationarity) or "with_trend"
+    level: String,
+    bandwidth: String, // "short", "long" or "auto" (Newey-West)
+    lag: usize,
+    test_stat: f64,
+    residuals: Vec<f64>,
+    long_run_variance: f64,
+}
+
+#[wasm_bindgen]
+impl Kpss {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, equation: String, level: String, bandwidth: String) -> Result<Kpss, JsValue> {
+        check_equation(&equation)?;
+        Ok(Kpss {
+            data,
+            equation,
+            level,
+            bandwidth,
+            lag: 0,
+            test_stat: 0.0,
+            residuals: Vec::new(),
+            long_run_variance: 0.0,
+        })
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_equation(&self) -> String {
+        self.equation.clone()
+    }
+    pub fn get_level(&self) -> String {
+        self.level.clone()
+    }
+    pub fn get_bandwidth(&self) -> String {
+        self.bandwidth.clone()
+    }
+    pub fn get_lag(&self) -> usize {
+        self.lag
+    }
+    pub fn get_test_stat(&self) -> f64 {
+        self.test_stat
+    }
+    pub fn get_residuals(&self) -> Vec<f64> {
+        self.residuals.clone()
+    }
+    pub fn get_long_run_variance(&self) -> f64 {
+        self.long_run_variance
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>) {
+        self.data = data;
+    }
+    pub fn set_equation(&mut self, equation: String) -> Result<(), JsValue> {
+        check_equation(&equation)?;
+        self.equation = equation;
+        Ok(())
+    }
+    pub fn set_level(&mut self, level: String) {
+        self.level = level;
+    }
+    pub fn set_bandwidth(&mut self, bandwidth: String) {
+        self.bandwidth = bandwidth;
+    }
+    pub fn set_lag(&mut self, lag: usize) {
+        self.lag = lag;
+    }
+    pub fn set_test_stat(&mut self, test_stat: f64) {
+        self.test_stat = test_stat;
+    }
+    pub fn set_residuals(&mut self, residuals: Vec<f64>) {
+        self.residuals = residuals;
+    }
+    pub fn set_long_run_variance(&mut self, long_run_variance: f64) {
+        self.long_run_variance = long_run_variance;
+    }
+}
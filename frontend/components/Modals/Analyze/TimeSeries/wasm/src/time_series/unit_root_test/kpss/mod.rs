@@ -0,0 +1,2 @@
+pub mod kpss;
+pub mod calculate_statistic;
\ No newline at end of file
@@ -5,4 +5,8 @@ pub mod calculate_pvalue;
 pub mod mackinnon_critical_values;
 pub mod mackinnon_pvalue;
 pub mod read_critical_values;
-pub mod read_pvalue;
\ No newline at end of file
+pub mod read_pvalue;
+pub mod kpss;
+pub mod phillips_perron;
+pub mod zivot_andrews;
+pub mod unit_root_helper;
\ No newline at end of file
@@ -0,0 +1,74 @@
+use wasm_bindgen::prelude::*;
+use crate::PhillipsPerron;
+use crate::{calculate_p_value, calculate_critical_values};
+use crate::time_series::ecm::ols_helper::ols;
+use crate::time_series::unit_root_test::unit_root_helper::{autocovariance_at, bartlett_long_run_variance, deterministic_columns, newey_west_bandwidth, prepare_level};
+
+// Asymptotic critical values of n(ρ̂ - 1) at 1%, 5% and 10% (Fuller, 1996, Table 10.A.1)
+const Z_ALPHA_NO_CONSTANT: [f64; 3] = [-13.8, -8.1, -5.7];
+const Z_ALPHA_NO_TREND: [f64; 3] = [-20.7, -14.1, -11.3];
+const Z_ALPHA_WITH_TREND: [f64; 3] = [-29.5, -21.8, -18.3];
+
+#[wasm_bindgen]
+impl PhillipsPerron {
+    /// MacKinnon p-value of Z(t), which shares the Dickey-Fuller τ distribution
+    pub fn calculate_pvalue(&self) -> f64 {
+        calculate_p_value(self.get_test_stat(), 1, &self.get_equation())
+    }
+
+    /// MacKinnon critical values of Z(t) at 1%, 5% and 10%
+    pub fn calculate_critical_value(&self) -> Vec<f64> {
+        let n = prepare_level(self.get_data(), &self.get_level()).len() - 1;
+        ["1%", "5%", "10%"]
+            .iter()
+            .map(|level| calculate_critical_values(n.min(255) as u8, &self.get_equation(), level))
+            .collect()
+    }
+
+    /// Asymptotic critical values of Z(α) at 1%, 5% and 10%
+    pub fn calculate_z_alpha_critical_value(&self) -> Vec<f64> {
+        match self.get_equation().as_str() {
+            "no_constant" => Z_ALPHA_NO_CONSTANT.to_vec(),
+            "no_trend" => Z_ALPHA_NO_TREND.to_vec(),
+            "with_trend" => Z_ALPHA_WITH_TREND.to_vec(),
+            _ => panic!("Unknown equation: {}", self.get_equation()),
+        }
+    }
+
+    /// Regress y_t on y_{t-1} (plus constant and trend) and correct the OLS statistics
+    /// for serial correlation with the long-run variance λ² of the residuals:
+    /// Z(α) = T(ρ̂ - 1) - ½(T²·se²/s²)(λ² - γ₀)
+    /// Z(t) = √(γ₀/λ²)·t - ½(λ² - γ₀)/λ·(T·se/s)
+    /// Returns Z(t); Z(α) is stored alongside.
+    pub fn calculate_test_stat(&mut self) -> f64 {
+        let data = prepare_level(self.get_data(), &self.get_level());
+        let t_len = data.len() - 1;
+        let mut columns = deterministic_columns(t_len, &self.get_equation());
+        columns.push(data[..t_len].to_vec());
+        let fit = match ols(&columns, &data[1..]) {
+            Some(fit) => fit,
+            None => return f64::NAN,
+        };
+
+        let k = columns.len() - 1;
+        let rho = fit.beta[k];
+        let se = fit.se[k];
+        let s = fit.sigma2.sqrt();
+        let t = t_len as f64;
+        let lag = newey_west_bandwidth(&fit.residuals, &self.get_bandwidth());
+        let gamma0 = autocovariance_at(&fit.residuals, 0);
+        let lambda2 = bartlett_long_run_variance(&fit.residuals, lag);
+
+        let t_stat = (rho - 1.0) / se;
+        let z_alpha = t * (rho - 1.0) - 0.5 * (t * t * se * se / fit.sigma2) * (lambda2 - gamma0);
+        let z_t = (gamma0 / lambda2).sqrt() * t_stat - 0.5 * (lambda2 - gamma0) / lambda2.sqrt() * (t * se / s);
+
+        self.set_lag(lag);
+        self.set_rho(rho);
+        self.set_z_alpha(z_alpha);
+        self.set_test_stat(z_t);
+        self.set_b_vec(fit.beta);
+        self.set_se_vec(fit.se);
+        z_t
+    }
+}
@@ -0,0 +1,2 @@
+pub mod phillips_perron;
+pub mod calculate_statistic;
\ No newline at end of file
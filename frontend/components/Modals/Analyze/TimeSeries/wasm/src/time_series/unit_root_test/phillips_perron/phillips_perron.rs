@@ -0,0 +1,99 @@
+use wasm_bindgen::prelude::*;
+
+/// Phillips-Perron unit-root test with the Z(α) and Z(t) statistics
+#[wasm_bindgen]
+pub struct PhillipsPerron {
+    data: Vec<f64>,
+    equation: String,  // "no_constant", "no_trend" or "with_trend"
+    level: String,
+    bandwidth: String, // "short", "long" or "auto" (Newey-West)
+    lag: usize,
+    rho: f64,
+    test_stat: f64, // Z(t)
+    z_alpha: f64,
+    b_vec: Vec<f64>,
+    se_vec: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl PhillipsPerron {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, equation: String, level: String, bandwidth: String) -> PhillipsPerron {
+        PhillipsPerron {
+            data,
+            equation,
+            level,
+            bandwidth,
+            lag: 0,
+            rho: 0.0,
+            test_stat: 0.0,
+            z_alpha: 0.0,
+            b_vec: Vec::new(),
+            se_vec: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_equation(&self) -> String {
+        self.equation.clone()
+    }
+    pub fn get_level(&self) -> String {
+        self.level.clone()
+    }
+    pub fn get_bandwidth(&self) -> String {
+        self.bandwidth.clone()
+    }
+    pub fn get_lag(&self) -> usize {
+        self.lag
+    }
+    pub fn get_rho(&self) -> f64 {
+        self.rho
+    }
+    pub fn get_test_stat(&self) -> f64 {
+        self.test_stat
+    }
+    pub fn get_z_alpha(&self) -> f64 {
+        self.z_alpha
+    }
+    pub fn get_b_vec(&self) -> Vec<f64> {
+        self.b_vec.clone()
+    }
+    pub fn get_se_vec(&self) -> Vec<f64> {
+        self.se_vec.clone()
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>) {
+        self.data = data;
+    }
+    pub fn set_equation(&mut self, equation: String) {
+        self.equation = equation;
+    }
+    pub fn set_level(&mut self, level: String) {
+        self.level = level;
+    }
+    pub fn set_bandwidth(&mut self, bandwidth: String) {
+        self.bandwidth = bandwidth;
+    }
+    pub fn set_lag(&mut self, lag: usize) {
+        self.lag = lag;
+    }
+    pub fn set_rho(&mut self, rho: f64) {
+        self.rho = rho;
+    }
+    pub fn set_test_stat(&mut self, test_stat: f64) {
+        self.test_stat = test_stat;
+    }
+    pub fn set_z_alpha(&mut self, z_alpha: f64) {
+        self.z_alpha = z_alpha;
+    }
+    pub fn set_b_vec(&mut self, b_vec: Vec<f64>) {
+        self.b_vec = b_vec;
+    }
+    pub fn set_se_vec(&mut self, se_vec: Vec<f64>) {
+        self.se_vec = se_vec;
+    }
+}
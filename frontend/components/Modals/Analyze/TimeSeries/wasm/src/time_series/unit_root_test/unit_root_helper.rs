@@ -0,0 +1,79 @@
+use crate::{first_difference, second_difference};
+
+// Kwiatkowski et al. (1992), Table 1: upper-tail critical values at 10%, 5%, 2.5% and 1%
+pub const KPSS_PROBS: [f64; 4] = [0.10, 0.05, 0.025, 0.01];
+pub const KPSS_LEVEL_CRITICAL: [f64; 4] = [0.347, 0.463, 0.574, 0.739];
+pub const KPSS_TREND_CRITICAL: [f64; 4] = [0.119, 0.146, 0.176, 0.216];
+
+/// Series at the requested level: "level", "first-difference" or "second-difference"
+pub fn prepare_level(data: Vec<f64>, level: &str) -> Vec<f64> {
+    match level {
+        "first-difference" => first_difference(data),
+        "second-difference" => second_difference(data),
+        _ => data,
+    }
+}
+
+/// Deterministic regressors of the test equation for observations t = 1..n:
+/// "no_constant" → none, "no_trend" → constant, "with_trend" → constant and trend
+pub fn deterministic_columns(n: usize, equation: &str) -> Vec<Vec<f64>> {
+    match equation {
+        "no_constant" => Vec::new(),
+        "no_trend" => vec![vec![1.0; n]],
+        "with_trend" => vec![vec![1.0; n], (1..=n).map(|t| t as f64).collect()],
+        _ => panic!("Unknown equation: {}", equation),
+    }
+}
+
+/// Sample autocovariance γ_j = Σ u_t·u_{t-j} / n (residuals have mean zero)
+pub fn autocovariance_at(u: &[f64], j: usize) -> f64 {
+    let n = u.len();
+    (j..n).map(|t| u[t] * u[t - j]).sum::<f64>() / n as f64
+}
+
+/// Newey-West long-run variance γ_0 + 2Σ_{j=1}^{l} (1 - j/(l+1))·γ_j (Bartlett kernel)
+pub fn bartlett_long_run_variance(u: &[f64], lag: usize) -> f64 {
+    let mut lrv = autocovariance_at(u, 0);
+    for j in 1..=lag.min(u.len() - 1) {
+        lrv += 2.0 * (1.0 - j as f64 / (lag as f64 + 1.0)) * autocovariance_at(u, j);
+    }
+    lrv
+}
+
+/// Truncation lag of the Bartlett kernel: "short" ⌊4(n/100)^{1/4}⌋ and "long" ⌊12(n/100)^{1/4}⌋
+/// (Schwert, 1989), or "auto" for the data-dependent Newey-West (1994) bandwidth
+pub fn newey_west_bandwidth(u: &[f64], method: &str) -> usize {
+    let n = u.len() as f64;
+    match method {
+        "short" => (4.0 * (n / 100.0).powf(0.25)).floor() as usize,
+        "long" => (12.0 * (n / 100.0).powf(0.25)).floor() as usize,
+        "auto" => {
+            let n_max = (4.0 * (n / 100.0).powf(2.0 / 9.0)).floor() as usize;
+            let mut s0 = autocovariance_at(u, 0);
+            let mut s1 = 0.0;
+            for j in 1..=n_max.min(u.len() - 1) {
+                let gamma_j = autocovariance_at(u, j);
+                s0 += 2.0 * gamma_j;
+                s1 += 2.0 * j as f64 * gamma_j;
+            }
+            let gamma_hat = 1.1447 * ((s1 / s0).powi(2)).cbrt();
+            ((gamma_hat * n.cbrt()).floor() as usize).min(u.len() - 1)
+        },
+        _ => panic!("Unknown bandwidth: {}", method),
+    }
+}
+
+/// Linear interpolation of p-values between tabulated critical values, clamped to the table
+/// (statistics ordered so that larger values mean stronger rejection)
+pub fn interpolate_pvalue(stat: f64, critical: &[f64], probs: &[f64]) -> f64 {
+    if stat <= critical[0] {
+        return probs[0];
+    }
+    for i in 1..critical.len() {
+        if stat <= critical[i] {
+            let w = (stat - critical[i - 1]) / (critical[i] - critical[i - 1]);
+            return probs[i - 1] + w * (probs[i] - probs[i - 1]);
+        }
+    }
+    probs[probs.len() - 1]
+}
@@ -0,0 +1,80 @@
+use wasm_bindgen::prelude::*;
+use crate::ZivotAndrews;
+use crate::time_series::ecm::ols_helper::ols;
+
+// Zivot and Andrews (1992), Tables 2-4: critical values at 1%, 5% and 10%
+const ZA_INTERCEPT_CRITICAL: [f64; 3] = [-5.34, -4.80, -4.58];
+const ZA_TREND_CRITICAL: [f64; 3] = [-4.93, -4.42, -4.11];
+const ZA_BOTH_CRITICAL: [f64; 3] = [-5.57, -5.08, -4.82];
+
+// Internal helpers (not exposed to WASM)
+impl ZivotAndrews {
+    /// Regressors for observations i = lag+1..n-1 with the break at `b`:
+    /// constant, trend, DU_i = 1(i ≥ b) and/or DT_i = (i - b + 1)·1(i ≥ b), y_{i-1}
+    /// and the lagged differences Δy_{i-1}, .., Δy_{i-lag}
+    fn break_regressors(&self, data: &[f64], b: usize) -> Vec<Vec<f64>> {
+        let n = data.len();
+        let lag = self.get_lag();
+        let rows = lag + 1..n;
+        let mut columns = vec![vec![1.0; n - lag - 1], rows.clone().map(|i| i as f64 + 1.0).collect()];
+        let model = self.get_model();
+        if model == "intercept" || model == "both" {
+            columns.push(rows.clone().map(|i| if i >= b { 1.0 } else { 0.0 }).collect());
+        }
+        if model == "trend" || model == "both" {
+            columns.push(rows.clone().map(|i| if i >= b { (i - b + 1) as f64 } else { 0.0 }).collect());
+        }
+        columns.push(rows.clone().map(|i| data[i - 1]).collect());
+        for j in 1..=lag {
+            columns.push(rows.clone().map(|i| data[i - j] - data[i - j - 1]).collect());
+        }
+        columns
+    }
+}
+
+#[wasm_bindgen]
+impl ZivotAndrews {
+    /// Critical values at 1%, 5% and 10%
+    pub fn calculate_critical_value(&self) -> Vec<f64> {
+        match self.get_model().as_str() {
+            "intercept" => ZA_INTERCEPT_CRITICAL.to_vec(),
+            "trend" => ZA_TREND_CRITICAL.to_vec(),
+            "both" => ZA_BOTH_CRITICAL.to_vec(),
+            _ => panic!("Unknown model: {}", self.get_model()),
+        }
+    }
+
+    /// For every break date within the trimmed sample, regress Δy_t on the deterministic
+    /// terms with the break, y_{t-1} and the lagged differences. The statistic is the
+    /// smallest t-ratio of y_{t-1} and its date is the estimated break.
+    pub fn calculate_test_stat(&mut self) -> f64 {
+        let data = self.get_data();
+        let n = data.len();
+        let lag = self.get_lag();
+        let y: Vec<f64> = (lag + 1..n).map(|i| data[i] - data[i - 1]).collect();
+        let first = ((self.get_trim() * n as f64).ceil() as usize).max(lag + 2);
+        let last = ((1.0 - self.get_trim()) * n as f64).floor() as usize;
+
+        let mut break_candidates = Vec::new();
+        let mut t_stats = Vec::new();
+        for b in first..=last.min(n - 1) {
+            let columns = self.break_regressors(&data, b);
+            let k = columns.len() - lag - 1;
+            if let Some(fit) = ols(&columns, &y) {
+                break_candidates.push(b as f64);
+                t_stats.push(fit.beta[k] / fit.se[k]);
+            }
+        }
+        let (best, test_stat) = t_stats
+            .iter()
+            .enumerate()
+            .fold((0, f64::INFINITY), |acc, (i, &t)| if t < acc.1 { (i, t) } else { acc });
+        if let Some(&b) = break_candidates.get(best) {
+            self.set_break_index(b as usize);
+        }
+        self.set_test_stat(test_stat);
+        self.set_break_candidates(break_candidates);
+        self.set_t_stats(t_stats);
+        test_stat
+    }
+}
@@ -0,0 +1,2 @@
+pub mod zivot_andrews;
+pub mod calculate_statistic;
\ No newline at end of file
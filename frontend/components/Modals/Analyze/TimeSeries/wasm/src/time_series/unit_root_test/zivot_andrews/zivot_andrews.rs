@@ -0,0 +1,83 @@
+use wasm_bindgen::prelude::*;
+
+/// Zivot-Andrews unit-root test against trend stationarity with one endogenous break
+#[wasm_bindgen]
+pub struct ZivotAndrews {
+    data: Vec<f64>,
+    model: String, // "intercept", "trend" or "both" (break in the intercept and/or the trend)
+    lag: usize,    // Lagged differences in the test regression
+    trim: f64,     // Fraction of the sample excluded at each end when searching for the break
+    test_stat: f64,
+    break_index: usize, // 0-based index of the first observation after the break
+    break_candidates: Vec<f64>,
+    t_stats: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl ZivotAndrews {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, model: String, lag: usize) -> ZivotAndrews {
+        ZivotAndrews {
+            data,
+            model,
+            lag,
+            trim: 0.15,
+            test_stat: 0.0,
+            break_index: 0,
+            break_candidates: Vec::new(),
+            t_stats: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_model(&self) -> String {
+        self.model.clone()
+    }
+    pub fn get_lag(&self) -> usize {
+        self.lag
+    }
+    pub fn get_trim(&self) -> f64 {
+        self.trim
+    }
+    pub fn get_test_stat(&self) -> f64 {
+        self.test_stat
+    }
+    pub fn get_break_index(&self) -> usize {
+        self.break_index
+    }
+    pub fn get_break_candidates(&self) -> Vec<f64> {
+        self.break_candidates.clone()
+    }
+    pub fn get_t_stats(&self) -> Vec<f64> {
+        self.t_stats.clone()
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>) {
+        self.data = data;
+    }
+    pub fn set_model(&mut self, model: String) {
+        self.model = model;
+    }
+    pub fn set_lag(&mut self, lag: usize) {
+        self.lag = lag;
+    }
+    pub fn set_trim(&mut self, trim: f64) {
+        self.trim = trim;
+    }
+    pub fn set_test_stat(&mut self, test_stat: f64) {
+        self.test_stat = test_stat;
+    }
+    pub fn set_break_index(&mut self, break_index: usize) {
+        self.break_index = break_index;
+    }
+    pub fn set_break_candidates(&mut self, break_candidates: Vec<f64>) {
+        self.break_candidates = break_candidates;
+    }
+    pub fn set_t_stats(&mut self, t_stats: Vec<f64>) {
+        self.t_stats = t_stats;
+    }
+}
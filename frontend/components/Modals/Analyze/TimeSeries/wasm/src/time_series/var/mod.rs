@@ -0,0 +1,6 @@
+pub mod var;
+pub mod var_model;
+pub mod var_estimate;
+pub mod var_granger;
+pub mod var_irf;
+pub mod var_forecast;
\ No newline at end of file
@@ -0,0 +1,79 @@
+use wasm_bindgen::prelude::*;
+use crate::time_series::var::var_model::VarFit;
+
+/// Vector autoregression y_t = ν (+ δt) + A_1y_{t-1} + .. + A_py_{t-p} + u_t for k series
+#[wasm_bindgen]
+pub struct Var {
+    pub(crate) data_flat: Vec<f64>,    // Flattened 2D array: [y1[0], y1[1], ..., y2[0], y2[1], ...]
+    pub(crate) n_vars: usize,          // Number of series k
+    pub(crate) n_obs: usize,           // Number of observations per series
+    pub(crate) lag: usize,             // VAR order p
+    pub(crate) deterministic: String,  // "none", "const" or "trend" (constant and trend)
+    pub(crate) fit: Option<VarFit>,
+    pub(crate) lag_criteria: Vec<f64>, // [AIC, BIC, HQ, FPE] for p = 1..max_lag
+}
+
+#[wasm_bindgen]
+impl Var {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        data_flat: Vec<f64>,   // Flattened series
+        n_vars: usize,         // Number of series
+        lag: usize,            // VAR order
+        deterministic: String, // Deterministic terms
+    ) -> Result<Var, JsValue> {
+        if n_vars < 1 || !data_flat.len().is_multiple_of(n_vars) {
+            return Err(JsValue::from_str(&format!(
+                "Data dimensions mismatch: {} values cannot hold {} series",
+                data_flat.len(), n_vars
+            )));
+        }
+        if lag < 1 {
+            return Err(JsValue::from_str("VAR order must be at least 1"));
+        }
+        Ok(Var {
+            n_obs: data_flat.len() / n_vars,
+            data_flat,
+            n_vars,
+            lag,
+            deterministic,
+            fit: None,
+            lag_criteria: Vec::new(),
+        })
+    }
+
+    // Getters
+    pub fn get_n_vars(&self) -> usize { self.n_vars }
+    pub fn get_n_obs(&self) -> usize { self.n_obs }
+    pub fn get_lag(&self) -> usize { self.lag }
+    pub fn get_deterministic(&self) -> String { self.deterministic.clone() }
+    pub fn get_lag_criteria(&self) -> Vec<f64> { self.lag_criteria.clone() }
+
+    // Setters
+    pub fn set_lag(&mut self, lag: usize) -> Result<(), JsValue> {
+        if lag < 1 {
+            return Err(JsValue::from_str("VAR order must be at least 1"));
+        }
+        self.lag = lag;
+        self.fit = None;
+        Ok(())
+    }
+}
+
+// Internal helpers (not exposed to WASM)
+impl Var {
+    /// Series i (0-based)
+    pub fn series(&self, i: usize) -> Vec<f64> {
+        self.data_flat[i * self.n_obs..(i + 1) * self.n_obs].to_vec()
+    }
+
+    /// All series, one vector per variable
+    pub fn all_series(&self) -> Vec<Vec<f64>> {
+        (0..self.n_vars).map(|i| self.series(i)).collect()
+    }
+
+    /// The estimated model, or an error before `estimate`
+    pub fn fitted_model(&self) -> Result<&VarFit, JsValue> {
+        self.fit.as_ref().ok_or_else(|| JsValue::from_str("VAR is not estimated yet, call estimate first"))
+    }
+}
@@ -0,0 +1,123 @@
+use wasm_bindgen::prelude::*;
+use crate::Var;
+use crate::time_series::var::var_model::{fit_var, n_deterministic};
+
+#[wasm_bindgen]
+impl Var {
+    /// Equation-by-equation OLS of the VAR(p)
+    pub fn estimate(&mut self) -> Result<(), JsValue> {
+        let n_det = n_deterministic(&self.deterministic);
+        if self.n_obs <= self.lag + n_det + self.n_vars * self.lag {
+            return Err(JsValue::from_str(&format!(
+                "Too few observations ({}) for {} series with VAR order {}",
+                self.n_obs, self.n_vars, self.lag
+            )));
+        }
+        let fit = fit_var(&self.all_series(), self.lag, n_det, self.lag)
+            .ok_or_else(|| JsValue::from_str("Singular regressor matrix"))?;
+        self.fit = Some(fit);
+        Ok(())
+    }
+
+    /// Information criteria for p = 1..max_lag on the common sample after max_lag
+    /// observations, with Σ̃ the ML residual covariance, m deterministic terms and T obs:
+    /// AIC = ln|Σ̃| + 2(pk² + km)/T, BIC = ln|Σ̃| + ln T·(pk² + km)/T,
+    /// HQ = ln|Σ̃| + 2 ln ln T·(pk² + km)/T, FPE = ((T + n*)/(T - n*))^k |Σ̃| with n* = pk + m.
+    /// Stores [AIC, BIC, HQ, FPE] per p and returns the order chosen by the criterion.
+    pub fn select_lag(&mut self, max_lag: usize, criterion: String) -> Result<usize, JsValue> {
+        let n_det = n_deterministic(&self.deterministic);
+        if max_lag == 0 {
+            return Err(JsValue::from_str("max_lag must be at least 1"));
+        }
+        // The largest model needs more observations than regressors on the common sample
+        if max_lag >= self.n_obs || max_lag * self.n_vars + n_det >= self.n_obs - max_lag {
+            return Err(JsValue::from_str(&format!(
+                "Too few observations ({}) for {} series with maximum VAR order {}",
+                self.n_obs, self.n_vars, max_lag
+            )));
+        }
+        let k = self.n_vars as f64;
+        let series = self.all_series();
+        let mut criteria = Vec::with_capacity(4 * max_lag);
+        for p in 1..=max_lag {
+            let fit = fit_var(&series, p, n_det, max_lag)
+                .ok_or_else(|| JsValue::from_str(&format!("Singular regressor matrix at order {}", p)))?;
+            let t = fit.n_used() as f64;
+            let log_det = fit.sigma_ml().determinant().ln();
+            let n_coef = p as f64 * k * k + k * n_det as f64;
+            let n_star = p as f64 * k + n_det as f64;
+            criteria.push(log_det + 2.0 * n_coef / t);
+            criteria.push(log_det + t.ln() * n_coef / t);
+            criteria.push(log_det + 2.0 * t.ln().ln() * n_coef / t);
+            criteria.push(((t + n_star) / (t - n_star)).powf(k) * log_det.exp());
+        }
+        let column = match criterion.as_str() {
+            "aic" => 0,
+            "bic" => 1,
+            "hq" => 2,
+            "fpe" => 3,
+            _ => panic!("Unknown criterion: {}", criterion),
+        };
+        let best = (0..max_lag)
+            .min_by(|&a, &b| criteria[4 * a + column].total_cmp(&criteria[4 * b + column]))
+            .map(|i| i + 1)
+            .unwrap_or(1);
+        self.lag_criteria = criteria;
+        Ok(best)
+    }
+
+    /// Coefficients per equation: [deterministic, A_1 row, .., A_p row]; empty before `estimate`
+    pub fn get_coefficients(&self) -> Vec<f64> {
+        self.fit.as_ref().map(|fit| fit.coef.concat()).unwrap_or_default()
+    }
+
+    /// Standard errors in the layout of `get_coefficients`
+    pub fn get_std_errors(&self) -> Vec<f64> {
+        self.fit.as_ref().map(|fit| fit.se.concat()).unwrap_or_default()
+    }
+
+    /// Residuals per equation (T = n - p each)
+    pub fn get_residuals(&self) -> Vec<f64> {
+        self.fit.as_ref().map(|fit| fit.residuals.concat()).unwrap_or_default()
+    }
+
+    /// Degrees-of-freedom adjusted residual covariance, row by row
+    pub fn get_sigma(&self) -> Vec<f64> {
+        self.fit.as_ref().map(|fit| fit.sigma().as_slice().to_vec()).unwrap_or_default()
+    }
+
+    /// Gaussian log-likelihood -T/2 [k(1 + ln 2π) + ln|Σ̃|]; NaN before `estimate`
+    pub fn calculate_log_likelihood(&self) -> f64 {
+        let fit = match self.fit.as_ref() {
+            Some(fit) => fit,
+            None => return f64::NAN,
+        };
+        let t = fit.n_used() as f64;
+        let k = self.n_vars as f64;
+        -0.5 * t * (k * (1.0 + (2.0 * std::f64::consts::PI).ln()) + fit.sigma_ml().determinant().ln())
+    }
+
+    /// Moduli of the companion-matrix eigenvalues; the VAR is stable when all are below one.
+    /// Empty before `estimate`.
+    pub fn calculate_roots(&self) -> Vec<f64> {
+        let fit = match self.fit.as_ref() {
+            Some(fit) => fit,
+            None => return Vec::new(),
+        };
+        let k = self.n_vars;
+        let kp = k * fit.lag;
+        let ar = fit.ar_matrices();
+        let companion = nalgebra::DMatrix::from_fn(kp, kp, |r, c| {
+            if r < k {
+                ar[c / k][(r, c % k)]
+            } else if r == c + k {
+                1.0
+            } else {
+                0.0
+            }
+        });
+        let mut moduli: Vec<f64> = companion.complex_eigenvalues().iter().map(|z| z.norm()).collect();
+        moduli.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        moduli
+    }
+}
@@ -0,0 +1,40 @@
+use wasm_bindgen::prelude::*;
+use nalgebra::DMatrix;
+use crate::Var;
+use crate::time_series::helper_structs::ForecastResult;
+use crate::time_series::smoothing::smoothing_forecast::normal_forecast_result;
+
+#[wasm_bindgen]
+impl Var {
+    /// h-step forecasts of every series by iterating the VAR, with normal intervals from the
+    /// forecast MSE Σ(h) = Σ_{s<h} Φ_s Σ Φ_s'. The result is flattened per series
+    /// ([y1 at 1..h, y2 at 1..h, ...]).
+    pub fn forecast_ahead(&self, h: usize, level: f64) -> Result<ForecastResult, JsValue> {
+        let fit = self.fitted_model()?;
+        let k = self.n_vars;
+        let mut history = self.all_series();
+        for offset in 0..h {
+            let next = fit.predict_at(&history, self.n_obs + offset);
+            for (i, s) in history.iter_mut().enumerate() {
+                s.push(next[i]);
+            }
+        }
+
+        let sigma = fit.sigma();
+        let phi = fit.ma_matrices(h);
+        let mut mse = DMatrix::<f64>::zeros(k, k);
+        let mut variances = Vec::with_capacity(h);
+        for phi_s in phi.iter().take(h) {
+            mse += phi_s * &sigma * phi_s.transpose();
+            variances.push(mse.diagonal());
+        }
+
+        let mut forecast = Vec::with_capacity(k * h);
+        let mut se = Vec::with_capacity(k * h);
+        for (i, s) in history.iter().enumerate() {
+            forecast.extend_from_slice(&s[self.n_obs..]);
+            se.extend(variances.iter().map(|v| v[i].sqrt()));
+        }
+        Ok(normal_forecast_result(forecast, se, level))
+    }
+}
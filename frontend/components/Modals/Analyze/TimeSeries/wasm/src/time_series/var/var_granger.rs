@@ -0,0 +1,41 @@
+use wasm_bindgen::prelude::*;
+use crate::Var;
+use crate::time_series::ecm::ols_helper::ols;
+use crate::time_series::var::var_model::var_regressors;
+use statrs::distribution::{ContinuousCDF, FisherSnedecor};
+
+#[wasm_bindgen]
+impl Var {
+    /// Granger-causality Wald test of H0: the lags of variable `cause` do not enter the
+    /// equation of variable `effect` (A_j[effect, cause] = 0 for j = 1..p), as the
+    /// F statistic ((SSR_r - SSR_u)/p) / (SSR_u/(T - kp - m)).
+    /// Returns [F, df1, df2, p-value].
+    pub fn granger_test(&self, cause: usize, effect: usize) -> Result<Vec<f64>, JsValue> {
+        if cause >= self.n_vars || effect >= self.n_vars || cause == effect {
+            return Err(JsValue::from_str(&format!(
+                "Invalid variables: cause {} and effect {} of {} series",
+                cause, effect, self.n_vars
+            )));
+        }
+        let fit = self.fitted_model()?;
+        let series = self.all_series();
+        let columns = var_regressors(&series, fit.lag, fit.n_det, fit.start);
+        let restricted: Vec<Vec<f64>> = columns
+            .iter()
+            .enumerate()
+            .filter(|(c, _)| *c < fit.n_det || (c - fit.n_det) % self.n_vars != cause)
+            .map(|(_, column)| column.clone())
+            .collect();
+        let y = &series[effect][fit.start..];
+        let ssr_u = fit.residuals[effect].iter().map(|e| e * e).sum::<f64>();
+        let ssr_r = ols(&restricted, y)
+            .ok_or_else(|| JsValue::from_str("Singular restricted regressor matrix"))?
+            .ssr;
+
+        let df1 = fit.lag as f64;
+        let df2 = fit.n_used() as f64 - fit.n_params() as f64;
+        let f_stat = ((ssr_r - ssr_u) / df1) / (ssr_u / df2);
+        let p_value = 1.0 - FisherSnedecor::new(df1, df2).unwrap().cdf(f_stat);
+        Ok(vec![f_stat, df1, df2, p_value])
+    }
+}
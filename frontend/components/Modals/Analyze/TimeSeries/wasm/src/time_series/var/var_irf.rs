@@ -0,0 +1,93 @@
+use wasm_bindgen::prelude::*;
+use nalgebra::DMatrix;
+use crate::Var;
+use crate::time_series::helper_structs::{empirical_quantile, ImpulseResponseResult};
+use crate::time_series::var::var_model::{fit_var, VarFit};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+// Fixed seed so the bootstrap bands are reproducible
+const IRF_SEED: u64 = 42;
+
+// Flatten responses by horizon, then responding variable, then shock
+fn flatten_responses(responses: &[DMatrix<f64>]) -> Vec<f64> {
+    responses.iter().flat_map(|m| m.transpose().as_slice().to_vec()).collect()
+}
+
+// Internal helpers (not exposed to WASM)
+impl Var {
+    /// Series generated by the fitted VAR from the first p observations with the given
+    /// residual rows (one per generated observation)
+    fn simulate_series(&self, fit: &VarFit, shocks: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        let mut series: Vec<Vec<f64>> = self.all_series().iter().map(|s| s[..fit.start].to_vec()).collect();
+        for (offset, shock) in shocks.iter().enumerate() {
+            let t = fit.start + offset;
+            let mean = fit.predict_at(&series, t);
+            for (i, s) in series.iter_mut().enumerate() {
+                s.push(mean[i] + shock[i]);
+            }
+        }
+        series
+    }
+}
+
+#[wasm_bindgen]
+impl Var {
+    /// "orthogonalized" (Cholesky) or "generalized" impulse responses for horizons 0..=horizon,
+    /// with percentile bands at `level` from `n_boot` residual-bootstrap replications
+    /// (no bands when n_boot is 0; singular replications are dropped)
+    pub fn calculate_irf(&self, kind: String, horizon: usize, n_boot: usize, level: f64) -> Result<ImpulseResponseResult, JsValue> {
+        let fit = self.fitted_model()?;
+        let k = self.n_vars;
+        let irf = flatten_responses(&fit.impulse_responses(&kind, horizon));
+
+        // Centered residual rows
+        let t_used = fit.n_used();
+        let means: Vec<f64> = fit.residuals.iter().map(|e| e.iter().sum::<f64>() / t_used as f64).collect();
+        let rows: Vec<Vec<f64>> = (0..t_used).map(|t| (0..k).map(|i| fit.residuals[i][t] - means[i]).collect()).collect();
+
+        let mut rng = StdRng::seed_from_u64(IRF_SEED);
+        let mut draws: Vec<Vec<f64>> = Vec::with_capacity(n_boot);
+        for _ in 0..n_boot {
+            let shocks: Vec<Vec<f64>> = (0..t_used).map(|_| rows[rng.gen_range(0..t_used)].clone()).collect();
+            let series = self.simulate_series(fit, &shocks);
+            if let Some(boot) = fit_var(&series, fit.lag, fit.n_det, fit.start) {
+                draws.push(flatten_responses(&boot.impulse_responses(&kind, horizon)));
+            }
+        }
+
+        let (mut lower, mut upper) = (Vec::new(), Vec::new());
+        if !draws.is_empty() {
+            for idx in 0..irf.len() {
+                let mut values: Vec<f64> = draws.iter().map(|d| d[idx]).collect();
+                values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+                lower.push(empirical_quantile(&values, (1.0 - level) / 2.0));
+                upper.push(empirical_quantile(&values, 1.0 - (1.0 - level) / 2.0));
+            }
+        }
+        Ok(ImpulseResponseResult::new(irf, lower, upper, horizon, k, level, draws.len()))
+    }
+
+    /// Forecast error variance decomposition from the orthogonalized responses: the share of
+    /// the h-step error variance of variable i due to shock j,
+    /// Σ_{s<h} Θ_s[i,j]² / Σ_j Σ_{s<h} Θ_s[i,j]², for h = 1..=horizon.
+    /// Flattened by horizon, then variable, then shock; empty before `estimate`.
+    pub fn calculate_fevd(&self, horizon: usize) -> Vec<f64> {
+        let fit = match self.fit.as_ref() {
+            Some(fit) => fit,
+            None => return Vec::new(),
+        };
+        let k = self.n_vars;
+        let theta = fit.impulse_responses("orthogonalized", horizon);
+        let mut cumulative = DMatrix::<f64>::zeros(k, k);
+        let mut shares = Vec::with_capacity(horizon * k * k);
+        for theta_s in theta.iter().take(horizon) {
+            cumulative += theta_s.component_mul(theta_s);
+            for i in 0..k {
+                let total: f64 = cumulative.row(i).sum();
+                shares.extend((0..k).map(|j| cumulative[(i, j)] / total));
+            }
+        }
+        shares
+    }
+}
@@ -0,0 +1,166 @@
+use nalgebra::DMatrix;
+use crate::time_series::ecm::ols_helper::ols;
+
+/// Least-squares VAR(p) fit; every equation shares the regressors
+/// [deterministic terms, y_{t-1} (k), .., y_{t-p} (k)]
+pub struct VarFit {
+    pub lag: usize,
+    pub n_det: usize,               // 0 "none", 1 "const", 2 "trend" (constant and trend)
+    pub coef: Vec<Vec<f64>>,        // Per equation
+    pub se: Vec<Vec<f64>>,          // Per equation
+    pub residuals: Vec<Vec<f64>>,   // Per equation
+    pub xtx_inv: Vec<Vec<f64>>,     // (X'X)⁻¹ of the shared regressors
+    pub start: usize,               // First observation of the estimation sample
+}
+
+/// Number of deterministic regressors for "none", "const" or "trend" (constant and trend)
+pub fn n_deterministic(deterministic: &str) -> usize {
+    match deterministic {
+        "none" => 0,
+        "const" => 1,
+        "trend" => 2,
+        _ => panic!("Unknown deterministic terms: {}", deterministic),
+    }
+}
+
+/// Regressor columns for t = start..n with start ≥ lag
+pub fn var_regressors(series: &[Vec<f64>], lag: usize, n_det: usize, start: usize) -> Vec<Vec<f64>> {
+    let n = series[0].len();
+    let mut columns = Vec::new();
+    if n_det >= 1 {
+        columns.push(vec![1.0; n - start]);
+    }
+    if n_det >= 2 {
+        columns.push((start..n).map(|t| t as f64 + 1.0).collect());
+    }
+    for j in 1..=lag {
+        for s in series.iter() {
+            columns.push((start..n).map(|t| s[t - j]).collect());
+        }
+    }
+    columns
+}
+
+/// Equation-by-equation OLS of a VAR(lag) on observations start..n.
+/// Returns None when the regressor matrix is singular.
+pub fn fit_var(series: &[Vec<f64>], lag: usize, n_det: usize, start: usize) -> Option<VarFit> {
+    let columns = var_regressors(series, lag, n_det, start);
+    let mut coef = Vec::with_capacity(series.len());
+    let mut se = Vec::with_capacity(series.len());
+    let mut residuals = Vec::with_capacity(series.len());
+    let mut xtx_inv = Vec::new();
+    for s in series.iter() {
+        let fit = ols(&columns, &s[start..])?;
+        coef.push(fit.beta);
+        se.push(fit.se);
+        residuals.push(fit.residuals);
+        xtx_inv = fit.xtx_inv;
+    }
+    Some(VarFit { lag, n_det, coef, se, residuals, xtx_inv, start })
+}
+
+impl VarFit {
+    pub fn n_vars(&self) -> usize {
+        self.coef.len()
+    }
+
+    pub fn n_used(&self) -> usize {
+        self.residuals[0].len()
+    }
+
+    /// Regressors per equation
+    pub fn n_params(&self) -> usize {
+        self.n_det + self.lag * self.n_vars()
+    }
+
+    /// Coefficient matrices A_1..A_p (row = equation)
+    pub fn ar_matrices(&self) -> Vec<DMatrix<f64>> {
+        let k = self.n_vars();
+        (0..self.lag)
+            .map(|j| DMatrix::from_fn(k, k, |eq, i| self.coef[eq][self.n_det + j * k + i]))
+            .collect()
+    }
+
+    /// Residual covariance E'E / divisor
+    fn residual_cov(&self, divisor: f64) -> DMatrix<f64> {
+        let k = self.n_vars();
+        DMatrix::from_fn(k, k, |i, j| {
+            self.residuals[i].iter().zip(self.residuals[j].iter()).map(|(a, b)| a * b).sum::<f64>() / divisor
+        })
+    }
+
+    /// Degrees-of-freedom adjusted residual covariance E'E / (T - kp - m)
+    pub fn sigma(&self) -> DMatrix<f64> {
+        self.residual_cov((self.n_used() as f64 - self.n_params() as f64).max(1.0))
+    }
+
+    /// Maximum-likelihood residual covariance E'E / T
+    pub fn sigma_ml(&self) -> DMatrix<f64> {
+        self.residual_cov(self.n_used() as f64)
+    }
+
+    /// MA coefficients Φ_0 = I, Φ_s = Σ_{j=1}^{min(s,p)} Φ_{s-j}A_j for s = 0..=horizon
+    pub fn ma_matrices(&self, horizon: usize) -> Vec<DMatrix<f64>> {
+        let k = self.n_vars();
+        let ar = self.ar_matrices();
+        let mut phi = vec![DMatrix::identity(k, k)];
+        for s in 1..=horizon {
+            let mut next = DMatrix::zeros(k, k);
+            for j in 1..=s.min(self.lag) {
+                next += &phi[s - j] * &ar[j - 1];
+            }
+            phi.push(next);
+        }
+        phi
+    }
+
+    /// Deterministic part of equation eq at observation t (0-based)
+    pub fn deterministic_at(&self, eq: usize, t: usize) -> f64 {
+        let mut value = 0.0;
+        if self.n_det >= 1 {
+            value += self.coef[eq][0];
+        }
+        if self.n_det >= 2 {
+            value += self.coef[eq][1] * (t as f64 + 1.0);
+        }
+        value
+    }
+
+    /// One-step prediction of every variable at observation t from the values before t
+    pub fn predict_at(&self, history: &[Vec<f64>], t: usize) -> Vec<f64> {
+        let k = self.n_vars();
+        (0..k)
+            .map(|eq| {
+                let mut value = self.deterministic_at(eq, t);
+                for j in 1..=self.lag {
+                    for (i, s) in history.iter().enumerate() {
+                        value += self.coef[eq][self.n_det + (j - 1) * k + i] * s[t - j];
+                    }
+                }
+                value
+            })
+            .collect()
+    }
+
+    /// Orthogonalized responses Θ_s = Φ_s P with Σ = PP' (Cholesky), or generalized
+    /// responses Φ_s Σ e_j / √σ_jj (Pesaran and Shin, 1998), for s = 0..=horizon
+    pub fn impulse_responses(&self, kind: &str, horizon: usize) -> Vec<DMatrix<f64>> {
+        let sigma = self.sigma();
+        let impact = match kind {
+            "orthogonalized" => match sigma.clone().cholesky() {
+                Some(chol) => chol.l(),
+                None => DMatrix::from_element(sigma.nrows(), sigma.ncols(), f64::NAN),
+            },
+            "generalized" => {
+                let mut impact = sigma.clone();
+                for j in 0..sigma.ncols() {
+                    let scale = sigma[(j, j)].sqrt();
+                    impact.column_mut(j).iter_mut().for_each(|v| *v /= scale);
+                }
+                impact
+            },
+            _ => panic!("Unknown impulse response: {}", kind),
+        };
+        self.ma_matrices(horizon).iter().map(|phi| phi * &impact).collect()
+    }
+}
@@ -0,0 +1,102 @@
+use nalgebra::{DMatrix, SymmetricEigen};
+
+/// Moment matrices and eigen-solution of Johansen's reduced-rank regression
+pub struct JohansenFit {
+    pub eigenvalues: Vec<f64>,     // λ_1 ≥ .. ≥ λ_k
+    pub eigenvectors: DMatrix<f64>, // Columns v_i normalized so that V'S11V = I
+    pub z0: DMatrix<f64>,          // Δx_t
+    pub z1: DMatrix<f64>,          // x_{t-1} (with the restricted deterministic term)
+    pub z2: DMatrix<f64>,          // Lagged differences and unrestricted deterministic terms
+    pub s00: DMatrix<f64>,
+    pub s01: DMatrix<f64>,
+    pub s11: DMatrix<f64>,
+}
+
+/// Whether the deterministic case (1-5) adds a term inside the cointegrating relations
+pub fn restricted_term(case: usize) -> bool {
+    case == 2 || case == 4
+}
+
+/// Number of unrestricted deterministic columns of Z2: constant for cases 3-5, trend for case 5
+pub fn n_unrestricted_terms(case: usize) -> usize {
+    match case {
+        1 | 2 => 0,
+        3 | 4 => 1,
+        5 => 2,
+        _ => panic!("Unknown deterministic case: {}", case),
+    }
+}
+
+// Residuals of the columns of y regressed on the columns of x
+fn residualize(y: &DMatrix<f64>, x: &DMatrix<f64>) -> Option<DMatrix<f64>> {
+    if x.ncols() == 0 {
+        return Some(y.clone());
+    }
+    let xtx_inv = (x.transpose() * x).try_inverse()?;
+    Some(y - x * (xtx_inv * x.transpose() * y))
+}
+
+/// Johansen's procedure for the VECM Δx_t = αβ'x_{t-1} + Σ Γ_i Δx_{t-i} + deterministic
+/// terms + ε_t, from a VAR of order `lag` in levels (`lag` - 1 lagged differences).
+/// The series are given one vector per variable. Returns None when a moment matrix is singular.
+pub fn johansen_procedure(series: &[Vec<f64>], lag: usize, case: usize) -> Option<JohansenFit> {
+    let k = series.len();
+    let n = series[0].len();
+    let rows = n - lag;
+    let diff = |i: usize, t: usize| series[i][t] - series[i][t - 1];
+
+    let z0 = DMatrix::from_fn(rows, k, |r, i| diff(i, r + lag));
+    let k1 = k + usize::from(restricted_term(case));
+    let z1 = DMatrix::from_fn(rows, k1, |r, i| {
+        let t = r + lag;
+        match i {
+            i if i < k => series[i][t - 1],
+            _ if case == 2 => 1.0,
+            _ => t as f64,
+        }
+    });
+    let n_det = n_unrestricted_terms(case);
+    let z2 = DMatrix::from_fn(rows, k * (lag - 1) + n_det, |r, c| {
+        let t = r + lag;
+        if c < k * (lag - 1) {
+            diff(c % k, t - 1 - c / k)
+        } else if c == k * (lag - 1) {
+            1.0
+        } else {
+            t as f64
+        }
+    });
+
+    let r0 = residualize(&z0, &z2)?;
+    let r1 = residualize(&z1, &z2)?;
+    let t_eff = rows as f64;
+    let s00 = r0.transpose() * &r0 / t_eff;
+    let s01 = r0.transpose() * &r1 / t_eff;
+    let s11 = r1.transpose() * &r1 / t_eff;
+
+    // |λS11 - S10 S00⁻¹ S01| = 0 as a symmetric problem through the Cholesky factor S11 = LL'
+    let l_inv = s11.clone().cholesky()?.l().try_inverse()?;
+    let s00_inv = s00.clone().try_inverse()?;
+    let m = &l_inv * s01.transpose() * s00_inv * &s01 * l_inv.transpose();
+    let eigen = SymmetricEigen::new((&m + m.transpose()) / 2.0);
+    let mut order: Vec<usize> = (0..k1).collect();
+    order.sort_by(|&a, &b| eigen.eigenvalues[b].partial_cmp(&eigen.eigenvalues[a]).unwrap());
+    let order = &order[..k];
+    let eigenvalues = order.iter().map(|&i| eigen.eigenvalues[i].clamp(0.0, 1.0 - 1e-12)).collect();
+    let vectors = DMatrix::from_fn(k1, k, |r, c| eigen.eigenvectors[(r, order[c])]);
+    let eigenvectors = l_inv.transpose() * vectors;
+
+    Some(JohansenFit { eigenvalues, eigenvectors, z0, z1, z2, s00, s01, s11 })
+}
+
+/// Trace statistics -T Σ_{i>r} ln(1 - λ_i) for r = 0..k-1
+pub fn trace_statistics(eigenvalues: &[f64], t_eff: usize) -> Vec<f64> {
+    (0..eigenvalues.len())
+        .map(|r| -(t_eff as f64) * eigenvalues[r..].iter().map(|l| (1.0 - l).ln()).sum::<f64>())
+        .collect()
+}
+
+/// Maximum-eigenvalue statistics -T ln(1 - λ_{r+1}) for r = 0..k-1
+pub fn max_eigen_statistics(eigenvalues: &[f64], t_eff: usize) -> Vec<f64> {
+    eigenvalues.iter().map(|l| -(t_eff as f64) * (1.0 - l).ln()).collect()
+}
@@ -0,0 +1,4 @@
+pub mod vecm;
+pub mod johansen;
+pub mod osterwald_lenum;
+pub mod vecm_estimate;
\ No newline at end of file
@@ -0,0 +1,109 @@
+use wasm_bindgen::prelude::*;
+
+/// Johansen cointegration tests and the vector error-correction model
+/// Δx_t = αβ'x_{t-1} + Γ_1Δx_{t-1} + .. + Γ_{p-1}Δx_{t-p+1} + deterministic terms + ε_t
+/// for k series. Deterministic cases: 1 none, 2 restricted constant, 3 unrestricted constant,
+/// 4 restricted trend, 5 unrestricted trend.
+#[wasm_bindgen]
+pub struct Vecm {
+    pub(crate) data_flat: Vec<f64>, // Flattened 2D array: [x1[0], x1[1], ..., x2[0], x2[1], ...]
+    pub(crate) n_vars: usize,       // Number of series k
+    pub(crate) n_obs: usize,        // Number of observations per series
+    pub(crate) lag: usize,          // VAR order p in levels
+    pub(crate) case: usize,         // Deterministic case 1-5
+
+    // Johansen tests
+    pub(crate) eigenvalues: Vec<f64>,
+    pub(crate) trace_stats: Vec<f64>,     // For r = 0..k-1
+    pub(crate) max_eigen_stats: Vec<f64>, // For r = 0..k-1
+
+    // VECM estimates for the chosen rank r
+    pub(crate) rank: usize,
+    pub(crate) beta: Vec<f64>,          // Cointegrating vectors, one after another (k or k+1 values each)
+    pub(crate) alpha: Vec<f64>,         // Adjustment coefficients, one k-vector per relation
+    pub(crate) gamma: Vec<f64>,         // Γ_1..Γ_{p-1}, each k×k row by row (row = equation)
+    pub(crate) deterministic: Vec<f64>, // Unrestricted constant (and trend) per equation
+    pub(crate) residuals: Vec<f64>,     // Flattened per equation
+    pub(crate) sigma: Vec<f64>,         // Residual covariance, row by row
+    pub(crate) log_likelihood: f64,
+}
+
+#[wasm_bindgen]
+impl Vecm {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        data_flat: Vec<f64>, // Flattened series
+        n_vars: usize,       // Number of series
+        lag: usize,          // VAR order in levels
+        case: usize,         // Deterministic case
+    ) -> Result<Vecm, JsValue> {
+        if n_vars < 2 || !data_flat.len().is_multiple_of(n_vars) {
+            return Err(JsValue::from_str(&format!(
+                "Data dimensions mismatch: {} values cannot hold {} series",
+                data_flat.len(), n_vars
+            )));
+        }
+        if lag < 1 {
+            return Err(JsValue::from_str("VAR order must be at least 1"));
+        }
+        if !(1..=5).contains(&case) {
+            return Err(JsValue::from_str(&format!("Unknown deterministic case: {}", case)));
+        }
+        let n_obs = data_flat.len() / n_vars;
+        if n_obs <= lag + n_vars * lag + 2 {
+            return Err(JsValue::from_str(&format!(
+                "Too few observations ({}) for {} series with VAR order {}",
+                n_obs, n_vars, lag
+            )));
+        }
+
+        Ok(Vecm {
+            data_flat,
+            n_vars,
+            n_obs,
+            lag,
+            case,
+            eigenvalues: Vec::new(),
+            trace_stats: Vec::new(),
+            max_eigen_stats: Vec::new(),
+            rank: 0,
+            beta: Vec::new(),
+            alpha: Vec::new(),
+            gamma: Vec::new(),
+            deterministic: Vec::new(),
+            residuals: Vec::new(),
+            sigma: Vec::new(),
+            log_likelihood: f64::NAN,
+        })
+    }
+
+    // Getters
+    pub fn get_n_vars(&self) -> usize { self.n_vars }
+    pub fn get_n_obs(&self) -> usize { self.n_obs }
+    pub fn get_lag(&self) -> usize { self.lag }
+    pub fn get_case(&self) -> usize { self.case }
+    pub fn get_eigenvalues(&self) -> Vec<f64> { self.eigenvalues.clone() }
+    pub fn get_trace_stats(&self) -> Vec<f64> { self.trace_stats.clone() }
+    pub fn get_max_eigen_stats(&self) -> Vec<f64> { self.max_eigen_stats.clone() }
+    pub fn get_rank(&self) -> usize { self.rank }
+    pub fn get_beta(&self) -> Vec<f64> { self.beta.clone() }
+    pub fn get_alpha(&self) -> Vec<f64> { self.alpha.clone() }
+    pub fn get_gamma(&self) -> Vec<f64> { self.gamma.clone() }
+    pub fn get_deterministic(&self) -> Vec<f64> { self.deterministic.clone() }
+    pub fn get_residuals(&self) -> Vec<f64> { self.residuals.clone() }
+    pub fn get_sigma(&self) -> Vec<f64> { self.sigma.clone() }
+    pub fn get_log_likelihood(&self) -> f64 { self.log_likelihood }
+}
+
+// Internal helpers (not exposed to WASM)
+impl Vecm {
+    /// Series i (0-based)
+    pub fn series(&self, i: usize) -> Vec<f64> {
+        self.data_flat[i * self.n_obs..(i + 1) * self.n_obs].to_vec()
+    }
+
+    /// All series, one vector per variable
+    pub fn all_series(&self) -> Vec<Vec<f64>> {
+        (0..self.n_vars).map(|i| self.series(i)).collect()
+    }
+}
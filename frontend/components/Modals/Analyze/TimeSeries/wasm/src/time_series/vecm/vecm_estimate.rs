@@ -0,0 +1,127 @@
+use wasm_bindgen::prelude::*;
+use nalgebra::DMatrix;
+use crate::Vecm;
+use crate::time_series::vecm::johansen::{johansen_procedure, max_eigen_statistics, n_unrestricted_terms, trace_statistics};
+use crate::time_series::vecm::osterwald_lenum::osterwald_lenum_critical_value;
+
+// Internal helpers (not exposed to WASM)
+impl Vecm {
+    /// Critical values for r = 0..k-1, each as [1%, 5%, 10%]
+    fn critical_values(&self, test: &str) -> Vec<f64> {
+        let mut values = Vec::with_capacity(3 * self.n_vars);
+        for r in 0..self.n_vars {
+            for level in ["1%", "5%", "10%"].iter() {
+                values.push(osterwald_lenum_critical_value(test, self.case, self.n_vars - r, level));
+            }
+        }
+        values
+    }
+}
+
+#[wasm_bindgen]
+impl Vecm {
+    /// Johansen trace and maximum-eigenvalue tests of H0: rank ≤ r for r = 0..k-1.
+    /// Returns the trace statistics; the eigenvalues and max-eigenvalue statistics are stored.
+    pub fn johansen_test(&mut self) -> Result<Vec<f64>, JsValue> {
+        let fit = johansen_procedure(&self.all_series(), self.lag, self.case)
+            .ok_or_else(|| JsValue::from_str("Singular moment matrix in the Johansen procedure"))?;
+        let t_eff = self.n_obs - self.lag;
+        self.trace_stats = trace_statistics(&fit.eigenvalues, t_eff);
+        self.max_eigen_stats = max_eigen_statistics(&fit.eigenvalues, t_eff);
+        self.eigenvalues = fit.eigenvalues;
+        Ok(self.trace_stats.clone())
+    }
+
+    /// Osterwald-Lenum critical values of the trace test, [1%, 5%, 10%] for each r = 0..k-1
+    pub fn calculate_trace_critical_values(&self) -> Vec<f64> {
+        self.critical_values("trace")
+    }
+
+    /// Osterwald-Lenum critical values of the max-eigenvalue test, [1%, 5%, 10%] for each r = 0..k-1
+    pub fn calculate_max_eigen_critical_values(&self) -> Vec<f64> {
+        self.critical_values("max_eigen")
+    }
+
+    /// Cointegrating rank from sequential testing: the first r whose "trace" or "max_eigen"
+    /// statistic does not exceed the critical value at "1%", "5%" or "10%"
+    pub fn select_rank(&self, test: String, level: String) -> usize {
+        let stats = match test.as_str() {
+            "trace" => &self.trace_stats,
+            "max_eigen" => &self.max_eigen_stats,
+            _ => panic!("Unknown test: {}", test),
+        };
+        (0..stats.len())
+            .find(|&r| stats[r] <= osterwald_lenum_critical_value(&test, self.case, self.n_vars - r, &level))
+            .unwrap_or(stats.len())
+    }
+
+    /// Maximum-likelihood VECM with cointegrating rank r: β from the first r eigenvectors,
+    /// normalized so its first r rows are the identity, α = S01β(β'S11β)⁻¹, and the short-run
+    /// matrices and unrestricted deterministic terms by OLS of Δx_t - αβ'x_{t-1} on Z2
+    pub fn estimate(&mut self, rank: usize) -> Result<(), JsValue> {
+        let k = self.n_vars;
+        if rank > k {
+            return Err(JsValue::from_str(&format!("Rank {} exceeds the number of series {}", rank, k)));
+        }
+        let fit = johansen_procedure(&self.all_series(), self.lag, self.case)
+            .ok_or_else(|| JsValue::from_str("Singular moment matrix in the Johansen procedure"))?;
+        let t_eff = fit.z0.nrows();
+
+        let mut beta = fit.eigenvectors.columns(0, rank).into_owned();
+        if rank > 0 {
+            if let Some(top_inv) = beta.rows(0, rank).into_owned().try_inverse() {
+                beta *= top_inv;
+            }
+        }
+        let alpha = if rank > 0 {
+            let btsb = (beta.transpose() * &fit.s11 * &beta)
+                .try_inverse()
+                .ok_or_else(|| JsValue::from_str("Singular β'S11β"))?;
+            &fit.s01 * &beta * btsb
+        } else {
+            DMatrix::zeros(k, 0)
+        };
+
+        // Short-run dynamics given αβ'
+        let target = &fit.z0 - &fit.z1 * &beta * alpha.transpose();
+        let n_z2 = fit.z2.ncols();
+        let psi = if n_z2 > 0 {
+            let ztz_inv = (fit.z2.transpose() * &fit.z2)
+                .try_inverse()
+                .ok_or_else(|| JsValue::from_str("Singular short-run regressor matrix"))?;
+            ztz_inv * fit.z2.transpose() * &target
+        } else {
+            DMatrix::zeros(0, k)
+        };
+        let residuals = &target - &fit.z2 * &psi;
+        let sigma = residuals.transpose() * &residuals / t_eff as f64;
+
+        let n_short = n_z2 - n_unrestricted_terms(self.case);
+        let mut gamma = Vec::with_capacity(n_short * k);
+        for j in 0..self.lag - 1 {
+            for eq in 0..k {
+                gamma.extend((0..k).map(|i| psi[(j * k + i, eq)]));
+            }
+        }
+        let mut deterministic = Vec::new();
+        for eq in 0..k {
+            deterministic.extend((n_short..n_z2).map(|c| psi[(c, eq)]));
+        }
+
+        // ln L = -T/2 [k(1 + ln 2π) + ln|S00| + Σ_{i≤r} ln(1 - λ_i)]
+        let log_det_s00 = fit.s00.determinant().ln();
+        let eigen_sum: f64 = fit.eigenvalues[..rank].iter().map(|l| (1.0 - l).ln()).sum();
+        let log_likelihood = -0.5 * t_eff as f64
+            * (k as f64 * (1.0 + (2.0 * std::f64::consts::PI).ln()) + log_det_s00 + eigen_sum);
+
+        self.rank = rank;
+        self.beta = beta.as_slice().to_vec();
+        self.alpha = alpha.as_slice().to_vec();
+        self.gamma = gamma;
+        self.deterministic = deterministic;
+        self.residuals = residuals.as_slice().to_vec();
+        self.sigma = sigma.transpose().as_slice().to_vec();
+        self.log_likelihood = log_likelihood;
+        Ok(())
+    }
+}